@@ -3,7 +3,7 @@ use std::time::Duration;
 
 use criterion::{criterion_group, criterion_main, Criterion};
 use eonn_accel::{Auto, Vector, X1024};
-use rann::rp_trees::make_forest;
+use rann::rp_trees::{make_forest, SplitStrategy};
 
 mod utils;
 
@@ -13,10 +13,14 @@ const N_VECTORS: usize = 1_000;
 fn benchmark_rp_forest(c: &mut Criterion) {
     fastrand::seed(2352356346463346);
 
-    for angular in [true, false] {
+    for split in [
+        SplitStrategy::Angular,
+        SplitStrategy::Euclidean,
+        SplitStrategy::InnerProduct,
+    ] {
         for n in [10, 32, 64] {
             c.bench_function(
-                &format!("make_forest angular={angular} x1024 {n} neighbors"),
+                &format!("make_forest split={split:?} x1024 {n} neighbors"),
                 |b| {
                     let mut data = Vec::with_capacity(N_VECTORS);
                     for _ in 0..N_VECTORS {
@@ -29,8 +33,9 @@ fn benchmark_rp_forest(c: &mut Criterion) {
                             black_box(&data),
                             black_box(N_TREES),
                             black_box(n),
-                            black_box(angular),
+                            black_box(split),
                             black_box(200),
+                            &mut fastrand::Rng::with_seed(0),
                         )
                     })
                 },
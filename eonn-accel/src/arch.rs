@@ -1,13 +1,13 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[derive(Debug, Copy, Clone)]
 /// AVX2 enabled architectures.
-pub struct Avx2(());
+pub struct Avx2(pub(crate) ());
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl Default for Avx2 {
     fn default() -> Self {
         assert!(
-            is_x86_feature_detected!("avx2"),
+            is_x86_feature_detected!("avx2") && is_x86_feature_detected!("f16c"),
             "AVX2 support is not available on the current platform"
         );
         Self(())
@@ -17,7 +17,7 @@ impl Default for Avx2 {
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 #[derive(Debug, Copy, Clone)]
 /// AVX512 enabled architectures.
-pub struct Avx512(());
+pub struct Avx512(pub(crate) ());
 
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 impl Default for Avx512 {
@@ -30,12 +30,47 @@ impl Default for Avx512 {
     }
 }
 
+#[cfg(target_arch = "aarch64")]
+#[derive(Debug, Copy, Clone)]
+/// NEON enabled architectures.
+pub struct Neon(pub(crate) ());
+
+#[cfg(target_arch = "aarch64")]
+impl Default for Neon {
+    fn default() -> Self {
+        assert!(
+            std::arch::is_aarch64_feature_detected!("neon"),
+            "NEON support is not available on the current platform"
+        );
+        Self(())
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+#[derive(Debug, Copy, Clone)]
+/// SVE enabled architectures.
+///
+/// Only the distance routines have SVE specific implementations, the remaining
+/// ops use the NEON routines which are always available alongside SVE.
+pub struct Sve(pub(crate) ());
+
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl Default for Sve {
+    fn default() -> Self {
+        assert!(
+            std::arch::is_aarch64_feature_detected!("sve"),
+            "SVE support is not available on the current platform"
+        );
+        Self(())
+    }
+}
+
 #[derive(Debug, Copy, Clone, Default)]
 /// No specialised features detected, fallback impls.
-pub struct Fallback(());
+pub struct Fallback(pub(crate) ());
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-pub struct Avx2Fma(());
+pub struct Avx2Fma(pub(crate) ());
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl Default for Avx2Fma {
     fn default() -> Self {
@@ -57,6 +92,10 @@ pub(crate) enum SelectedArch {
     Avx2Fma,
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
     Avx512,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+    Sve,
     Fallback,
 }
 
@@ -71,8 +110,13 @@ impl Default for SelectedArch {
             return Self::Avx512;
         }
 
+        // F16C is available on every CPU with AVX2, but it is checked for all the
+        // same as the half precision routines depend on it.
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+        if is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("f16c")
+            && is_x86_feature_detected!("fma")
+        {
             return Self::Avx2Fma;
         }
 
@@ -84,19 +128,47 @@ impl Default for SelectedArch {
             return Self::Avx512;
         }
 
-        if is_x86_feature_detected!("avx2") {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("f16c") {
             return Self::Avx2;
         }
 
         Self::Fallback
     }
 
-    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    #[cfg(target_arch = "aarch64")]
+    fn default() -> Self {
+        #[cfg(feature = "nightly")]
+        if std::arch::is_aarch64_feature_detected!("sve") {
+            return Self::Sve;
+        }
+
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return Self::Neon;
+        }
+
+        Self::Fallback
+    }
+
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64"
+    )))]
     fn default() -> Self {
         Self::Fallback
     }
 }
 
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+/// Returns if the CPU supports the AVX512-BF16 instructions.
+///
+/// Only the `bf16` kernels of [Avx512] make use of them, so rather than adding
+/// another [SelectedArch] the feature is detected once and cached.
+pub(crate) fn has_avx512bf16() -> bool {
+    static DETECTED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *DETECTED.get_or_init(|| is_x86_feature_detected!("avx512bf16"))
+}
+
 pub trait Arch: Default {}
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 impl Arch for Avx2 {}
@@ -104,6 +176,10 @@ impl Arch for Avx2 {}
 impl Arch for Avx2Fma {}
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 impl Arch for Avx512 {}
+#[cfg(target_arch = "aarch64")]
+impl Arch for Neon {}
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl Arch for Sve {}
 impl Arch for Auto {}
 impl Arch for Fallback {}
 
@@ -136,4 +212,30 @@ mod tests {
             );
         }
     }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_if_neon_enabled() {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            Neon::default();
+        } else {
+            assert!(
+                std::panic::catch_unwind(Neon::default).is_err(),
+                "Type should panic due to missing cpu flags",
+            );
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+    #[test]
+    fn test_if_sve_enabled() {
+        if std::arch::is_aarch64_feature_detected!("sve") {
+            Sve::default();
+        } else {
+            assert!(
+                std::panic::catch_unwind(Sve::default).is_err(),
+                "Type should panic due to missing cpu flags",
+            );
+        }
+    }
 }
@@ -0,0 +1,562 @@
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use crate::danger::*;
+use crate::math::AutoMath;
+use crate::ops::SpacialOps;
+use crate::{Dim, VectorCreateError, XAny};
+
+/// The number of bits packed into each word of a [BinaryVector].
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+/// A bit-packed binary vector of `D` bits stored as `u64` words.
+///
+/// The distances are computed by counting the set bits of each word, using the
+/// `AVX512-VPOPCNTDQ` or `popcnt` instructions when they are available at runtime
+/// and falling back to a compiler optimized routine otherwise.
+///
+/// As a [SpacialOps] type each bit is treated as an element with a value of
+/// `0` or `1`, this means the squared Euclidean and Manhattan distances are both
+/// equal to the Hamming distance.
+///
+/// The hyperplanes produced when splitting binary vectors are signed, each bit
+/// where the two points differ is given a weight of `+1` or `-1` towards the point
+/// it is set in. These can only be used as the hyperplane of a split and have no
+/// meaning as a data point.
+pub struct BinaryVector<D: Dim = XAny> {
+    words: Vec<u64>,
+    /// The bits with a weight of `-1`, this is always empty except for hyperplanes.
+    negative: Vec<u64>,
+    ops: SelectedPopcnt,
+    dims: PhantomData<D>,
+}
+
+impl<D: Dim> Debug for BinaryVector<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.words.is_empty() {
+            let first = self.words.first().unwrap();
+            let last = self.words.last().unwrap();
+
+            write!(
+                f,
+                "BinaryVector(ops={:?}, [ {first:#018x} ... {last:#018x} ])",
+                self.ops
+            )
+        } else {
+            write!(f, "BinaryVector(ops={:?}, [])", self.ops)
+        }
+    }
+}
+
+impl<D: Dim> BinaryVector<D> {
+    #[inline]
+    /// Attempt to create a new binary vector from the given bit-packed words.
+    ///
+    /// Bit `i` of the vector is bit `i % 64` of word `i / 64`, the number of bits
+    /// must match the dimensions of `D`.
+    ///
+    /// This method does not allocate.
+    pub fn try_from_words(words: Vec<u64>) -> Result<Self, VectorCreateError> {
+        if let Some(expected_dim) = D::const_size() {
+            let got = words.len() * BITS_PER_WORD;
+            if got != expected_dim {
+                return Err(VectorCreateError::BadDimensions {
+                    expected: expected_dim,
+                    got,
+                });
+            }
+        }
+
+        Ok(Self::from_words_unchecked(words))
+    }
+
+    /// Attempt to create a new binary vector from the given bits, setting
+    /// each bit which is `true`.
+    ///
+    /// The number of bits must be a multiple of `64` and match the dimensions of `D`.
+    pub fn try_from_bits(bits: &[bool]) -> Result<Self, VectorCreateError> {
+        if !bits.len().is_multiple_of(BITS_PER_WORD) {
+            return Err(VectorCreateError::BadDimensions {
+                expected: bits.len().next_multiple_of(BITS_PER_WORD),
+                got: bits.len(),
+            });
+        }
+
+        let words = bits
+            .chunks_exact(BITS_PER_WORD)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (i, &bit)| word | ((bit as u64) << i))
+            })
+            .collect();
+
+        Self::try_from_words(words)
+    }
+
+    #[inline]
+    /// Returns the bit-packed words of the vector.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    #[inline]
+    /// Returns the number of bits which are set.
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    #[inline]
+    fn from_words_unchecked(words: Vec<u64>) -> Self {
+        Self {
+            words,
+            negative: Vec::new(),
+            ops: SelectedPopcnt::default(),
+            dims: PhantomData,
+        }
+    }
+
+    /// Computes the signed hyperplane splitting the bits where `self` and `other`
+    /// differ, returning it along with the weight of each side.
+    fn signed_hyperplane(&self, other: &Self) -> (Self, u32, u32) {
+        let mut positive = Vec::with_capacity(self.words.len());
+        let mut negative = Vec::with_capacity(self.words.len());
+
+        for (&x, &y) in self.words.iter().zip(other.words.iter()) {
+            let diff = x ^ y;
+            positive.push(diff & x);
+            negative.push(diff & y);
+        }
+
+        let n_positive = positive.iter().map(|w| w.count_ones()).sum();
+        let n_negative = negative.iter().map(|w| w.count_ones()).sum();
+
+        let mut hyperplane = Self::from_words_unchecked(positive);
+        hyperplane.negative = negative;
+
+        (hyperplane, n_positive, n_negative)
+    }
+}
+
+impl<D: Dim> SpacialOps for BinaryVector<D> {
+    fn len(&self) -> usize {
+        self.words.len() * BITS_PER_WORD
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        let mut dot = self.ops.intersection(&self.words, &other.words) as f32;
+
+        if !self.negative.is_empty() {
+            dot -= self.ops.intersection(&self.negative, &other.words) as f32;
+        }
+
+        if !other.negative.is_empty() {
+            dot -= self.ops.intersection(&self.words, &other.negative) as f32;
+
+            if !self.negative.is_empty() {
+                dot += self.ops.intersection(&self.negative, &other.negative) as f32;
+            }
+        }
+
+        dot
+    }
+
+    fn squared_norm(&self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Binary vectors cannot be scaled, so this is a no-op.
+    fn normalize(&mut self) {}
+
+    /// Binary vectors cannot be normalized, so this is the same as the
+    /// cosine distance.
+    fn dist_dot(&self, other: &Self) -> f32 {
+        self.dist_cosine(other)
+    }
+
+    fn dist_cosine(&self, other: &Self) -> f32 {
+        let norm_x = self.squared_norm();
+        let norm_y = other.squared_norm();
+
+        if norm_x == 0.0 && norm_y == 0.0 {
+            0.0
+        } else if norm_x == 0.0 || norm_y == 0.0 {
+            1.0
+        } else {
+            1.0 - (self.dot(other) / (norm_x * norm_y).sqrt())
+        }
+    }
+
+    fn dist_squared_euclidean(&self, other: &Self) -> f32 {
+        self.dist_hamming(other)
+    }
+
+    fn dist_manhattan(&self, other: &Self) -> f32 {
+        self.dist_hamming(other)
+    }
+
+    fn dist_chebyshev(&self, other: &Self) -> f32 {
+        (self.words != other.words) as u8 as f32
+    }
+
+    fn dist_hamming(&self, other: &Self) -> f32 {
+        self.ops.hamming(&self.words, &other.words) as f32
+    }
+
+    fn dist_jaccard(&self, other: &Self) -> f32 {
+        self.ops.jaccard(&self.words, &other.words)
+    }
+
+    fn dist_minkowski(&self, other: &Self, p: f32) -> f32 {
+        if p == f32::INFINITY {
+            self.dist_chebyshev(other)
+        } else {
+            self.dist_hamming(other).powf(p.recip())
+        }
+    }
+
+    fn dist_weighted_squared_euclidean(&self, other: &Self, weights: &[f32]) -> f32 {
+        assert_eq!(
+            weights.len(),
+            self.len(),
+            "Lengths of the vector and weights must be equal"
+        );
+
+        let mut dist = 0.0;
+        for (i, (&x, &y)) in self.words.iter().zip(other.words.iter()).enumerate() {
+            let weights = &weights[i * BITS_PER_WORD..];
+            let mut diff = x ^ y;
+            while diff != 0 {
+                dist += weights[diff.trailing_zeros() as usize];
+                diff &= diff - 1;
+            }
+        }
+
+        dist
+    }
+
+    /// Binary vectors always have a multiple of `64` dimensions, so they can never
+    /// be `[latitude, longitude]` points.
+    fn dist_haversine(&self, _other: &Self) -> f32 {
+        panic!("Haversine requires 2 dimensional vectors")
+    }
+
+    /// The means and variances of the bits are derived from the number of set
+    /// bits, so the vectors are never centred explicitly.
+    fn dist_correlation(&self, other: &Self) -> f32 {
+        let n = self.len() as f32;
+        let n_x = self.count_ones() as f32;
+        let n_y = other.count_ones() as f32;
+        let intersection = self.ops.intersection(&self.words, &other.words) as f32;
+
+        let covariance = intersection - (n_x * n_y / n);
+        let variance_x = n_x - (n_x * n_x / n);
+        let variance_y = n_y - (n_y * n_y / n);
+
+        cosine::<f32, AutoMath>(covariance, variance_x, variance_y)
+    }
+
+    fn dist_hellinger(&self, other: &Self) -> f32 {
+        let intersection = self.ops.intersection(&self.words, &other.words) as f32;
+        hellinger::<f32, AutoMath>(
+            intersection,
+            self.count_ones() as f32,
+            other.count_ones() as f32,
+        )
+    }
+
+    /// Each bit falls into one of four cases depending on which vectors it is set
+    /// in, so the divergence is computed once per case and weighted by its count.
+    fn dist_jensen_shannon(&self, other: &Self) -> f32 {
+        let n = self.len() as f32;
+        let n_x = self.count_ones() as f32;
+        let n_y = other.count_ones() as f32;
+        let n_both = self.ops.intersection(&self.words, &other.words) as f32;
+
+        let l1_x = n_x + (f32::EPSILON * n);
+        let l1_y = n_y + (f32::EPSILON * n);
+        let divergence = |x: f32, y: f32| {
+            let px = (x + f32::EPSILON) / l1_x;
+            let py = (y + f32::EPSILON) / l1_y;
+            let m = 0.5 * (px + py);
+            0.5 * (px * (px / m).ln() + py * (py / m).ln())
+        };
+
+        let n_x_only = n_x - n_both;
+        let n_y_only = n_y - n_both;
+        let n_neither = n - n_x_only - n_y_only - n_both;
+
+        (n_both * divergence(1.0, 1.0))
+            + (n_x_only * divergence(1.0, 0.0))
+            + (n_y_only * divergence(0.0, 1.0))
+            + (n_neither * divergence(0.0, 0.0))
+    }
+
+    /// Computes the signed hyperplane between the bits set in self and another
+    /// vector, which always passes through the origin.
+    fn angular_hyperplane(&self, other: &Self) -> Self {
+        let (hyperplane, _, _) = self.signed_hyperplane(other);
+        hyperplane
+    }
+
+    /// Computes the signed hyperplane between the bits set in self and another
+    /// vector, along with the offset which makes it equidistant from both in
+    /// Hamming distance.
+    fn euclidean_hyperplane(&self, other: &Self) -> (Self, f32) {
+        let (hyperplane, n_positive, n_negative) = self.signed_hyperplane(other);
+        let offset = (n_negative as f32 - n_positive as f32) / 2.0;
+        (hyperplane, offset)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// The popcount routines selected at runtime based on the available CPU features.
+enum SelectedPopcnt {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+    Avx512,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Popcnt,
+    Fallback,
+}
+
+impl Default for SelectedPopcnt {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn default() -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512vpopcntdq")
+        {
+            return Self::Avx512;
+        }
+
+        if is_x86_feature_detected!("popcnt") {
+            return Self::Popcnt;
+        }
+
+        Self::Fallback
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    fn default() -> Self {
+        Self::Fallback
+    }
+}
+
+impl SelectedPopcnt {
+    #[inline]
+    fn hamming(&self, x: &[u64], y: &[u64]) -> u32 {
+        debug_assert_eq!(x.len(), y.len(), "Binary vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512 => u64_xany_avx512_popcnt_hamming(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Popcnt => u64_xany_popcnt_hamming(x, y),
+                Self::Fallback => u64_xany_fallback_hamming(x, y),
+            }
+        }
+    }
+
+    #[inline]
+    fn intersection(&self, x: &[u64], y: &[u64]) -> u32 {
+        debug_assert_eq!(x.len(), y.len(), "Binary vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512 => u64_xany_avx512_popcnt_intersection(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Popcnt => u64_xany_popcnt_intersection(x, y),
+                Self::Fallback => u64_xany_fallback_intersection(x, y),
+            }
+        }
+    }
+
+    #[inline]
+    fn jaccard(&self, x: &[u64], y: &[u64]) -> f32 {
+        debug_assert_eq!(x.len(), y.len(), "Binary vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512 => u64_xany_avx512_popcnt_jaccard(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Popcnt => u64_xany_popcnt_jaccard(x, y),
+                Self::Fallback => u64_xany_fallback_jaccard(x, y),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::test_utils::assert_is_close;
+    use crate::{Fallback, Vector, X512};
+
+    fn sample_words(n: usize, seed: u64) -> Vec<u64> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..n).map(|_| rng.gen()).collect()
+    }
+
+    fn to_bits(words: &[u64]) -> Vec<bool> {
+        (0..words.len() * BITS_PER_WORD)
+            .map(|i| words[i / BITS_PER_WORD] & (1 << (i % BITS_PER_WORD)) != 0)
+            .collect()
+    }
+
+    fn simple_hamming(x: &[bool], y: &[bool]) -> u32 {
+        x.iter().zip(y).filter(|(x, y)| x != y).count() as u32
+    }
+
+    fn simple_jaccard(x: &[bool], y: &[bool]) -> f32 {
+        let intersection = x.iter().zip(y).filter(|(&x, &y)| x && y).count();
+        let union = x.iter().zip(y).filter(|(&x, &y)| x || y).count();
+        if union == 0 {
+            0.0
+        } else {
+            1.0 - (intersection as f32 / union as f32)
+        }
+    }
+
+    fn all_ops() -> Vec<SelectedPopcnt> {
+        #[allow(unused_mut)]
+        let mut ops = vec![SelectedPopcnt::Fallback];
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("popcnt") {
+            ops.push(SelectedPopcnt::Popcnt);
+        }
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512vpopcntdq")
+        {
+            ops.push(SelectedPopcnt::Avx512);
+        }
+
+        ops
+    }
+
+    #[test]
+    fn test_popcnt_kernels() {
+        // Include sizes which are not a multiple of the AVX512 register width.
+        for n_words in [0, 1, 7, 8, 13, 16] {
+            let x = sample_words(n_words, 1);
+            let y = sample_words(n_words, 2);
+            let (x_bits, y_bits) = (to_bits(&x), to_bits(&y));
+
+            for ops in all_ops() {
+                assert_eq!(ops.hamming(&x, &y), simple_hamming(&x_bits, &y_bits));
+                assert_eq!(
+                    ops.intersection(&x, &y),
+                    x.iter()
+                        .zip(&y)
+                        .map(|(x, y)| (x & y).count_ones())
+                        .sum::<u32>()
+                );
+                assert_is_close(ops.jaccard(&x, &y), simple_jaccard(&x_bits, &y_bits));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_vector_dims() {
+        let vector = BinaryVector::<X512>::try_from_words(sample_words(8, 1)).unwrap();
+        assert_eq!(vector.len(), 512);
+
+        assert!(BinaryVector::<X512>::try_from_words(sample_words(4, 1)).is_err());
+        assert!(BinaryVector::<XAny>::try_from_bits(&[true; 10]).is_err());
+
+        let vector = BinaryVector::<XAny>::try_from_bits(&to_bits(&[0b1011])).unwrap();
+        assert_eq!(vector.words(), &[0b1011]);
+        assert_eq!(vector.count_ones(), 3);
+    }
+
+    #[test]
+    fn test_binary_vector_distances() {
+        let x = BinaryVector::<X512>::try_from_words(sample_words(8, 1)).unwrap();
+        let y = BinaryVector::<X512>::try_from_words(sample_words(8, 2)).unwrap();
+
+        // The distances should match a dense vector of `0.0` and `1.0` values.
+        let to_dense = |v: &BinaryVector<X512>| {
+            let data = to_bits(v.words())
+                .into_iter()
+                .map(|bit| bit as u8 as f32)
+                .collect();
+            Vector::<X512, Fallback>::try_from_vec(data).unwrap()
+        };
+        let (x_dense, y_dense) = (to_dense(&x), to_dense(&y));
+
+        assert_eq!(x.dot(&y), x_dense.dot(&y_dense));
+        assert_eq!(x.squared_norm(), x_dense.squared_norm());
+        assert_eq!(x.dist_hamming(&y), x_dense.dist_hamming(&y_dense));
+        assert_eq!(
+            x.dist_squared_euclidean(&y),
+            x_dense.dist_squared_euclidean(&y_dense)
+        );
+        assert_is_close(x.dist_jaccard(&y), x_dense.dist_jaccard(&y_dense));
+        assert_is_close(x.dist_cosine(&y), x_dense.dist_cosine(&y_dense));
+        assert_is_close(
+            x.dist_minkowski(&y, 3.0),
+            x_dense.dist_minkowski(&y_dense, 3.0),
+        );
+        let weights = (0..512).map(|i| i as f32 / 512.0).collect::<Vec<_>>();
+        assert_is_close(
+            x.dist_weighted_squared_euclidean(&y, &weights),
+            x_dense.dist_weighted_squared_euclidean(&y_dense, &weights),
+        );
+        assert_is_close(x.dist_correlation(&y), x_dense.dist_correlation(&y_dense));
+        assert_is_close(x.dist_hellinger(&y), x_dense.dist_hellinger(&y_dense));
+        assert_is_close(
+            x.dist_jensen_shannon(&y),
+            x_dense.dist_jensen_shannon(&y_dense),
+        );
+        assert_eq!(x.dist_chebyshev(&y), 1.0);
+        assert_eq!(x.dist_chebyshev(&x), 0.0);
+    }
+
+    #[test]
+    fn test_binary_vector_hyperplanes() {
+        let x = BinaryVector::<X512>::try_from_words(sample_words(8, 1)).unwrap();
+        let y = BinaryVector::<X512>::try_from_words(sample_words(8, 2)).unwrap();
+
+        let (hyperplane, offset) = x.euclidean_hyperplane(&y);
+        for seed in 3..20 {
+            let p = BinaryVector::<X512>::try_from_words(sample_words(8, seed)).unwrap();
+
+            // The margin is half the difference in Hamming distance to each point.
+            let margin = offset + hyperplane.dot(&p);
+            let expected = (p.dist_hamming(&y) - p.dist_hamming(&x)) / 2.0;
+            assert_eq!(margin, expected);
+            assert_eq!(hyperplane.dot(&p), p.dot(&hyperplane));
+        }
+
+        assert!(offset + hyperplane.dot(&x) > 0.0);
+        assert!(offset + hyperplane.dot(&y) < 0.0);
+
+        let angular = x.angular_hyperplane(&y);
+        assert!(angular.dot(&x) > 0.0);
+        assert!(angular.dot(&y) < 0.0);
+    }
+}
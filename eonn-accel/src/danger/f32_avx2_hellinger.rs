@@ -0,0 +1,79 @@
+use std::arch::x86_64::*;
+
+use crate::danger::{hellinger, sum_avx2_ps};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the Hellinger distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx2_nofma_hellinger(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut product_acc1 = _mm256_setzero_ps();
+    let mut product_acc2 = _mm256_setzero_ps();
+    let mut x_acc1 = _mm256_setzero_ps();
+    let mut x_acc2 = _mm256_setzero_ps();
+    let mut y_acc1 = _mm256_setzero_ps();
+    let mut y_acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        product_acc1 =
+            _mm256_add_ps(product_acc1, _mm256_sqrt_ps(_mm256_mul_ps(x1, y1)));
+        product_acc2 =
+            _mm256_add_ps(product_acc2, _mm256_sqrt_ps(_mm256_mul_ps(x2, y2)));
+        x_acc1 = _mm256_add_ps(x_acc1, x1);
+        x_acc2 = _mm256_add_ps(x_acc2, x2);
+        y_acc1 = _mm256_add_ps(y_acc1, y1);
+        y_acc2 = _mm256_add_ps(y_acc2, y2);
+
+        i += 16;
+    }
+
+    let mut product_sum = sum_avx2_ps(_mm256_add_ps(product_acc1, product_acc2));
+    let mut l1_x = sum_avx2_ps(_mm256_add_ps(x_acc1, x_acc2));
+    let mut l1_y = sum_avx2_ps(_mm256_add_ps(y_acc1, y_acc2));
+
+    for n in i..len {
+        let x = *x.get_unchecked(n);
+        let y = *y.get_unchecked(n);
+        product_sum += (x * y).sqrt();
+        l1_x += x;
+        l1_y += y;
+    }
+
+    hellinger::<f32, AutoMath>(product_sum, l1_x, l1_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_hellinger};
+
+    #[test]
+    fn test_xany_hellinger() {
+        let (x, y) = get_sample_vectors(547);
+        let dist = unsafe { f32_xany_avx2_nofma_hellinger(&x, &y) };
+        assert_is_close(dist, simple_hellinger(&x, &y));
+    }
+}
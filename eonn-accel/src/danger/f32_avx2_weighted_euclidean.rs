@@ -0,0 +1,143 @@
+use std::arch::x86_64::*;
+
+use crate::danger::sum_avx2_ps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `f32` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx2_nofma_weighted_euclidean(
+    x: &[f32],
+    y: &[f32],
+    w: &[f32],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), w.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let w_ptr = w.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 =
+            _mm256_sub_ps(_mm256_loadu_ps(x_ptr.add(i)), _mm256_loadu_ps(y_ptr.add(i)));
+        let diff2 = _mm256_sub_ps(
+            _mm256_loadu_ps(x_ptr.add(i + 8)),
+            _mm256_loadu_ps(y_ptr.add(i + 8)),
+        );
+        let w1 = _mm256_loadu_ps(w_ptr.add(i));
+        let w2 = _mm256_loadu_ps(w_ptr.add(i + 8));
+
+        acc1 = _mm256_add_ps(acc1, _mm256_mul_ps(w1, _mm256_mul_ps(diff1, diff1)));
+        acc2 = _mm256_add_ps(acc2, _mm256_mul_ps(w2, _mm256_mul_ps(diff2, diff2)));
+
+        i += 16;
+    }
+
+    let mut total = sum_avx2_ps(_mm256_add_ps(acc1, acc2));
+    for n in i..len {
+        let diff = *x.get_unchecked(n) - *y.get_unchecked(n);
+        total += *w.get_unchecked(n) * diff * diff;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `f32` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx2_fma_weighted_euclidean(
+    x: &[f32],
+    y: &[f32],
+    w: &[f32],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), w.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let w_ptr = w.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 =
+            _mm256_sub_ps(_mm256_loadu_ps(x_ptr.add(i)), _mm256_loadu_ps(y_ptr.add(i)));
+        let diff2 = _mm256_sub_ps(
+            _mm256_loadu_ps(x_ptr.add(i + 8)),
+            _mm256_loadu_ps(y_ptr.add(i + 8)),
+        );
+        let w1 = _mm256_loadu_ps(w_ptr.add(i));
+        let w2 = _mm256_loadu_ps(w_ptr.add(i + 8));
+
+        acc1 = _mm256_fmadd_ps(_mm256_mul_ps(w1, diff1), diff1, acc1);
+        acc2 = _mm256_fmadd_ps(_mm256_mul_ps(w2, diff2), diff2, acc2);
+
+        i += 16;
+    }
+
+    let mut total = sum_avx2_ps(_mm256_add_ps(acc1, acc2));
+    for n in i..len {
+        let diff = *x.get_unchecked(n) - *y.get_unchecked(n);
+        total += *w.get_unchecked(n) * diff * diff;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_weighted_euclidean,
+    };
+
+    #[test]
+    fn test_xany_nofma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(547);
+        let (w, _) = get_sample_vectors(547);
+        let dist = unsafe { f32_xany_avx2_nofma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+
+    #[test]
+    fn test_xany_fma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(547);
+        let (w, _) = get_sample_vectors(547);
+        let dist = unsafe { f32_xany_avx2_fma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+}
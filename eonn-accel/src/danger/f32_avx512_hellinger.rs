@@ -0,0 +1,82 @@
+use std::arch::x86_64::*;
+
+use crate::danger::{hellinger, load_two_variable_size_avx512_ps};
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the Hellinger distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx512_nofma_hellinger(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x = x.as_ptr();
+    let y = y.as_ptr();
+
+    let mut product_acc1 = _mm512_setzero_ps();
+    let mut product_acc2 = _mm512_setzero_ps();
+    let mut x_acc1 = _mm512_setzero_ps();
+    let mut x_acc2 = _mm512_setzero_ps();
+    let mut y_acc1 = _mm512_setzero_ps();
+    let mut y_acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_ps(x.add(i));
+        let x2 = _mm512_loadu_ps(x.add(i + 16));
+        let y1 = _mm512_loadu_ps(y.add(i));
+        let y2 = _mm512_loadu_ps(y.add(i + 16));
+
+        product_acc1 =
+            _mm512_add_ps(product_acc1, _mm512_sqrt_ps(_mm512_mul_ps(x1, y1)));
+        product_acc2 =
+            _mm512_add_ps(product_acc2, _mm512_sqrt_ps(_mm512_mul_ps(x2, y2)));
+        x_acc1 = _mm512_add_ps(x_acc1, x1);
+        x_acc2 = _mm512_add_ps(x_acc2, x2);
+        y_acc1 = _mm512_add_ps(y_acc1, y1);
+        y_acc2 = _mm512_add_ps(y_acc2, y2);
+
+        i += 32;
+    }
+
+    // Masked out lanes are loaded as zeros, so they never contribute to the result.
+    while i < len {
+        let (x, y) = load_two_variable_size_avx512_ps(x.add(i), y.add(i), len - i);
+
+        product_acc1 = _mm512_add_ps(product_acc1, _mm512_sqrt_ps(_mm512_mul_ps(x, y)));
+        x_acc1 = _mm512_add_ps(x_acc1, x);
+        y_acc1 = _mm512_add_ps(y_acc1, y);
+
+        i += 16;
+    }
+
+    let product_sum = _mm512_reduce_add_ps(_mm512_add_ps(product_acc1, product_acc2));
+    let l1_x = _mm512_reduce_add_ps(_mm512_add_ps(x_acc1, x_acc2));
+    let l1_y = _mm512_reduce_add_ps(_mm512_add_ps(y_acc1, y_acc2));
+
+    hellinger::<f32, AutoMath>(product_sum, l1_x, l1_y)
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_hellinger};
+
+    #[test]
+    fn test_xany_hellinger() {
+        let (x, y) = get_sample_vectors(547);
+        let dist = unsafe { f32_xany_avx512_nofma_hellinger(&x, &y) };
+        assert_is_close(dist, simple_hellinger(&x, &y));
+    }
+}
@@ -0,0 +1,82 @@
+use std::arch::x86_64::*;
+
+use crate::danger::load_two_variable_size_avx512_ps;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `f32` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx512_fma_weighted_euclidean(
+    x: &[f32],
+    y: &[f32],
+    w: &[f32],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), w.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x = x.as_ptr();
+    let y = y.as_ptr();
+    let w = w.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 = _mm512_sub_ps(_mm512_loadu_ps(x.add(i)), _mm512_loadu_ps(y.add(i)));
+        let diff2 = _mm512_sub_ps(
+            _mm512_loadu_ps(x.add(i + 16)),
+            _mm512_loadu_ps(y.add(i + 16)),
+        );
+        let w1 = _mm512_loadu_ps(w.add(i));
+        let w2 = _mm512_loadu_ps(w.add(i + 16));
+
+        acc1 = _mm512_fmadd_ps(_mm512_mul_ps(w1, diff1), diff1, acc1);
+        acc2 = _mm512_fmadd_ps(_mm512_mul_ps(w2, diff2), diff2, acc2);
+
+        i += 32;
+    }
+
+    // Masked out lanes are loaded as zeros, so they never contribute to the result.
+    while i < len {
+        let (x, y) = load_two_variable_size_avx512_ps(x.add(i), y.add(i), len - i);
+        let (w, _) = load_two_variable_size_avx512_ps(w.add(i), w.add(i), len - i);
+
+        let diff = _mm512_sub_ps(x, y);
+        acc1 = _mm512_fmadd_ps(_mm512_mul_ps(w, diff), diff, acc1);
+
+        i += 16;
+    }
+
+    _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc2))
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_weighted_euclidean,
+    };
+
+    #[test]
+    fn test_xany_fma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(547);
+        let (w, _) = get_sample_vectors(547);
+        let dist = unsafe { f32_xany_avx512_fma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+}
@@ -0,0 +1,26 @@
+#[inline]
+/// Computes the Hamming distance of two `f32` vectors, the number of
+/// elements which differ.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_hamming(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut dist = 0;
+    for i in 0..x.len() {
+        dist += (*x.get_unchecked(i) != *y.get_unchecked(i)) as u32;
+    }
+
+    dist as f32
+}
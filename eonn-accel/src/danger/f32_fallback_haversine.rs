@@ -0,0 +1,61 @@
+#[inline]
+/// Computes the Haversine (great-circle) distance of two `[latitude, longitude]`
+/// points in radians, on a sphere with a radius of `1`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Both vectors **MUST** be `2` in length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_x2_fallback_nofma_haversine(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), 2, "Improper implementation detected, x must be 2D");
+    debug_assert_eq!(y.len(), 2, "Improper implementation detected, y must be 2D");
+
+    let (x_lat, x_long) = (*x.get_unchecked(0), *x.get_unchecked(1));
+    let (y_lat, y_long) = (*y.get_unchecked(0), *y.get_unchecked(1));
+
+    let sin_lat = (0.5 * (x_lat - y_lat)).sin();
+    let sin_long = (0.5 * (x_long - y_long)).sin();
+    let result =
+        sin_lat.mul_add(sin_lat, x_lat.cos() * y_lat.cos() * sin_long * sin_long);
+
+    // Rounding can push the result slightly above `1.0` for antipodal points.
+    2.0 * result.sqrt().min(1.0).asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+    use crate::test_utils::{assert_is_close, simple_haversine};
+
+    #[test]
+    fn test_x2_haversine() {
+        let points = [
+            [0.0, 0.0],
+            [0.5, -1.2],
+            [-0.9, 3.1],
+            [FRAC_PI_2, 0.0],
+            [-FRAC_PI_2, PI],
+            [0.1, -PI],
+        ];
+
+        for x in points.iter() {
+            for y in points.iter() {
+                let dist = unsafe { f32_x2_fallback_nofma_haversine(x, y) };
+                assert_is_close(dist, simple_haversine(x, y));
+            }
+        }
+
+        let dist = unsafe { f32_x2_fallback_nofma_haversine(&[0.0, 0.0], &[0.0, PI]) };
+        assert_is_close(dist, PI);
+        let dist = unsafe {
+            f32_x2_fallback_nofma_haversine(&[FRAC_PI_2, 0.0], &[-FRAC_PI_2, 0.0])
+        };
+        assert_is_close(dist, PI);
+    }
+}
@@ -0,0 +1,64 @@
+#[inline]
+/// Computes the Jensen-Shannon divergence of two `f32` vectors, treating each
+/// vector as an unnormalized probability distribution with the given L1 norms.
+///
+/// A small epsilon is added to every element so empty bins do not produce
+/// infinite log ratios.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_fallback_nofma_jensen_shannon(
+    x: &[f32],
+    y: &[f32],
+    l1_x: f32,
+    l1_y: f32,
+) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let len = x.len();
+    let l1_x = l1_x + (f32::EPSILON * len as f32);
+    let l1_y = l1_y + (f32::EPSILON * len as f32);
+
+    let mut result = 0.0;
+    for i in 0..len {
+        let px = (*x.get_unchecked(i) + f32::EPSILON) / l1_x;
+        let py = (*y.get_unchecked(i) + f32::EPSILON) / l1_y;
+        let m = 0.5 * (px + py);
+        result += 0.5 * (px * (px / m).ln() + py * (py / m).ln());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_jensen_shannon,
+    };
+
+    #[test]
+    fn test_xany_jensen_shannon() {
+        let (x, y) = get_sample_vectors::<f32>(127);
+        let l1_x = x.iter().sum();
+        let l1_y = y.iter().sum();
+        let dist = unsafe { f32_xany_fallback_nofma_jensen_shannon(&x, &y, l1_x, l1_y) };
+        assert_is_close(dist, simple_jensen_shannon(&x, &y));
+    }
+}
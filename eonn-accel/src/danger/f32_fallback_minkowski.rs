@@ -0,0 +1,56 @@
+#[inline]
+/// Computes the Minkowski distance of two `f32` vectors with the power `p`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// `p` should be finite and positive, although it is not going to crash
+/// the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_fallback_nofma_minkowski(x: &[f32], y: &[f32], p: f32) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut total = 0.0;
+    for i in 0..x.len() {
+        let diff = *x.get_unchecked(i) - *y.get_unchecked(i);
+        total += diff.abs().powf(p);
+    }
+
+    total.powf(p.recip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_euclidean,
+        simple_manhattan,
+        simple_minkowski,
+    };
+
+    #[test]
+    fn test_xany_minkowski() {
+        let (x, y) = get_sample_vectors(127);
+        for p in [0.5, 1.0, 2.0, 3.0] {
+            let dist = unsafe { f32_xany_fallback_nofma_minkowski(&x, &y, p) };
+            assert_is_close(dist, simple_minkowski(&x, &y, p));
+        }
+
+        let dist = unsafe { f32_xany_fallback_nofma_minkowski(&x, &y, 1.0) };
+        assert_is_close(dist, simple_manhattan(&x, &y));
+        let dist = unsafe { f32_xany_fallback_nofma_minkowski(&x, &y, 2.0) };
+        assert_is_close(dist, simple_euclidean(&x, &y).sqrt());
+    }
+}
@@ -0,0 +1,70 @@
+#[inline]
+/// Computes the squared Euclidean distance of two sparse `f32` vectors, each given
+/// as its sorted non-zero indices and the matching values.
+///
+/// # Safety
+///
+/// The indices of each vector **MUST** be the same length as its values and
+/// sorted in ascending order, otherwise this routine will become immediately
+/// UB due to out of bounds pointer accesses.
+pub unsafe fn f32_sparse_fallback_nofma_euclidean(
+    x_indices: &[u32],
+    x_values: &[f32],
+    y_indices: &[u32],
+    y_values: &[f32],
+) -> f32 {
+    debug_assert_eq!(x_indices.len(), x_values.len());
+    debug_assert_eq!(y_indices.len(), y_values.len());
+
+    let mut result = 0.0;
+    let mut i = 0;
+    let mut j = 0;
+    while i < x_indices.len() && j < y_indices.len() {
+        let x_idx = *x_indices.get_unchecked(i);
+        let y_idx = *y_indices.get_unchecked(j);
+
+        let diff = if x_idx == y_idx {
+            let diff = *x_values.get_unchecked(i) - *y_values.get_unchecked(j);
+            i += 1;
+            j += 1;
+            diff
+        } else if x_idx < y_idx {
+            i += 1;
+            *x_values.get_unchecked(i - 1)
+        } else {
+            j += 1;
+            *y_values.get_unchecked(j - 1)
+        };
+
+        result += diff * diff;
+    }
+
+    for n in i..x_values.len() {
+        let x = *x_values.get_unchecked(n);
+        result += x * x;
+    }
+    for n in j..y_values.len() {
+        let y = *y_values.get_unchecked(n);
+        result += y * y;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sparse_sample_vectors,
+        simple_euclidean,
+    };
+
+    #[test]
+    fn test_sparse_euclidean() {
+        let (x, y) = get_sparse_sample_vectors(1000, 0.1);
+        let dist =
+            unsafe { f32_sparse_fallback_nofma_euclidean(&x.0, &x.1, &y.0, &y.1) };
+        assert_is_close(dist, simple_euclidean(&x.2, &y.2));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::danger::jaccard_distance;
+
+#[inline]
+/// Computes the Jaccard distance of two sparse vectors given as their sorted
+/// non-zero indices.
+///
+/// Two empty sets have a distance of `0.0`.
+///
+/// # Safety
+///
+/// The indices of each vector **MUST** be sorted in ascending order, otherwise
+/// the result is meaningless.
+pub unsafe fn f32_sparse_fallback_nofma_jaccard(
+    x_indices: &[u32],
+    y_indices: &[u32],
+) -> f32 {
+    let mut intersection = 0;
+    let mut i = 0;
+    let mut j = 0;
+    while i < x_indices.len() && j < y_indices.len() {
+        let x_idx = *x_indices.get_unchecked(i);
+        let y_idx = *y_indices.get_unchecked(j);
+
+        if x_idx == y_idx {
+            intersection += 1;
+            i += 1;
+            j += 1;
+        } else if x_idx < y_idx {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    let union = (x_indices.len() + y_indices.len()) as u32 - intersection;
+    jaccard_distance(intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::f32_xany_fallback_nofma_jaccard;
+    use crate::test_utils::{assert_is_close, get_sparse_sample_vectors};
+
+    #[test]
+    fn test_sparse_jaccard() {
+        let (x, y) = get_sparse_sample_vectors(1000, 0.1);
+        let dist = unsafe { f32_sparse_fallback_nofma_jaccard(&x.0, &y.0) };
+        let expected = unsafe { f32_xany_fallback_nofma_jaccard(&x.2, &y.2) };
+        assert_is_close(dist, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use std::arch::aarch64::*;
+
+use crate::danger::cosine;
+use crate::math::*;
+
+#[target_feature(enable = "sve")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_sve_fma_cosine(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = svcntw() as usize;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot = svdup_n_f32(0.0);
+    let mut norm_x = svdup_n_f32(0.0);
+    let mut norm_y = svdup_n_f32(0.0);
+
+    let mut i = 0;
+    while i < len {
+        let pg = svwhilelt_b32_u64(i as u64, len as u64);
+        let x = svld1_f32(pg, x_ptr.add(i));
+        let y = svld1_f32(pg, y_ptr.add(i));
+
+        dot = svmla_f32_m(pg, dot, x, y);
+        norm_x = svmla_f32_m(pg, norm_x, x, x);
+        norm_y = svmla_f32_m(pg, norm_y, y, y);
+
+        i += step;
+    }
+
+    let all = svptrue_b32();
+    cosine::<f32, AutoMath>(
+        svaddv_f32(all, dot),
+        svaddv_f32(all, norm_x),
+        svaddv_f32(all, norm_y),
+    )
+}
+
+#[cfg(all(test, target_feature = "sve"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_cosine};
+
+    #[test]
+    fn test_xany_fma_cosine() {
+        for size in [1, 127, 1024] {
+            let (x, y) = get_sample_vectors(size);
+            let dist = unsafe { f32_xany_sve_fma_cosine(&x, &y) };
+            assert_is_close(dist, simple_cosine(&x, &y));
+        }
+    }
+}
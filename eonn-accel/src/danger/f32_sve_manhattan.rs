@@ -0,0 +1,59 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "sve")]
+#[inline]
+/// Computes the Manhattan distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_sve_nofma_manhattan(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = svcntw() as usize;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc = svdup_n_f32(0.0);
+
+    // The predicate masks off the lanes past the end of the vectors, so no
+    // scalar tail is required.
+    let mut i = 0;
+    while i < len {
+        let pg = svwhilelt_b32_u64(i as u64, len as u64);
+        let x = svld1_f32(pg, x_ptr.add(i));
+        let y = svld1_f32(pg, y_ptr.add(i));
+        let diff = svabs_f32_x(pg, svsub_f32_x(pg, x, y));
+        acc = svadd_f32_m(pg, acc, diff);
+
+        i += step;
+    }
+
+    svaddv_f32(svptrue_b32(), acc)
+}
+
+#[cfg(all(test, target_feature = "sve"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_manhattan};
+
+    #[test]
+    fn test_xany_nofma_manhattan() {
+        for size in [1, 127, 1024] {
+            let (x, y) = get_sample_vectors(size);
+            let dist = unsafe { f32_xany_sve_nofma_manhattan(&x, &y) };
+            assert_is_close(dist, simple_manhattan(&x, &y));
+        }
+    }
+}
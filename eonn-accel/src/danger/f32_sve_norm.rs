@@ -0,0 +1,31 @@
+use crate::danger::f32_xany_sve_fma_dot;
+
+#[target_feature(enable = "sve")]
+#[inline]
+/// Computes the squared L2 norm of one `f32` vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting SVE.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_sve_fma_norm(x: &[f32]) -> f32 {
+    f32_xany_sve_fma_dot(x, x)
+}
+
+#[cfg(all(test, target_feature = "sve"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_fma_norm() {
+        for size in [1, 127, 1024] {
+            let (x, _) = get_sample_vectors(size);
+            let norm = unsafe { f32_xany_sve_fma_norm(&x) };
+            assert_is_close(norm, simple_dot(&x, &x));
+        }
+    }
+}
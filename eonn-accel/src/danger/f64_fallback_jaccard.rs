@@ -0,0 +1,34 @@
+use crate::danger::jaccard_distance;
+
+#[inline]
+/// Computes the Jaccard distance of two `f64` vectors, treating each
+/// vector as the set of its non-zero elements.
+///
+/// Two empty sets have a distance of `0.0`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_fallback_nofma_jaccard(x: &[f64], y: &[f64]) -> f64 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut intersection = 0;
+    let mut union = 0;
+    for i in 0..x.len() {
+        let x_set = *x.get_unchecked(i) != 0.0;
+        let y_set = *y.get_unchecked(i) != 0.0;
+        intersection += (x_set & y_set) as u32;
+        union += (x_set | y_set) as u32;
+    }
+
+    jaccard_distance(intersection, union) as f64
+}
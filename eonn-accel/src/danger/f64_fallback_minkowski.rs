@@ -0,0 +1,51 @@
+#[inline]
+/// Computes the Minkowski distance of two `f64` vectors with the power `p`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// `p` should be finite and positive, although it is not going to crash
+/// the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_fallback_nofma_minkowski(x: &[f64], y: &[f64], p: f64) -> f64 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut total = 0.0;
+    for i in 0..x.len() {
+        let diff = *x.get_unchecked(i) - *y.get_unchecked(i);
+        total += diff.abs().powf(p);
+    }
+
+    total.powf(p.recip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_minkowski,
+        to_f32_vector,
+    };
+
+    #[test]
+    fn test_xany_minkowski() {
+        let (x, y) = get_sample_vectors::<f64>(127);
+        let (x_f32, y_f32) = (to_f32_vector(&x), to_f32_vector(&y));
+        for p in [0.5, 1.0, 2.0, 3.0] {
+            let dist = unsafe { f64_xany_fallback_nofma_minkowski(&x, &y, p) };
+            assert_is_close(dist as f32, simple_minkowski(&x_f32, &y_f32, p as f32));
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::danger::hellinger;
+use crate::danger::utils::rollup_scalar_x8;
+use crate::math::*;
+
+#[inline]
+/// Computes the Hellinger distance of two `T` vectors.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn generic_xany_fallback_nofma_hellinger<T>(x: &[T], y: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    fallback_hellinger::<T, AutoMath>(x, y)
+}
+
+#[inline]
+unsafe fn fallback_hellinger<T, M>(x: &[T], y: &[T]) -> T
+where
+    T: Copy,
+    M: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let mut product_extra = M::zero();
+    let mut l1_x = M::zero();
+    let mut l1_y = M::zero();
+
+    let mut acc1 = M::zero();
+    let mut acc2 = M::zero();
+    let mut acc3 = M::zero();
+    let mut acc4 = M::zero();
+    let mut acc5 = M::zero();
+    let mut acc6 = M::zero();
+    let mut acc7 = M::zero();
+    let mut acc8 = M::zero();
+
+    let mut i = 0;
+    while i < offset_from {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+
+        product_extra = M::add(product_extra, M::sqrt(M::mul(x, y)));
+        l1_x = M::add(l1_x, x);
+        l1_y = M::add(l1_y, y);
+
+        i += 1;
+    }
+
+    while i < len {
+        let x1 = *x.get_unchecked(i);
+        let x2 = *x.get_unchecked(i + 1);
+        let x3 = *x.get_unchecked(i + 2);
+        let x4 = *x.get_unchecked(i + 3);
+        let x5 = *x.get_unchecked(i + 4);
+        let x6 = *x.get_unchecked(i + 5);
+        let x7 = *x.get_unchecked(i + 6);
+        let x8 = *x.get_unchecked(i + 7);
+
+        let y1 = *y.get_unchecked(i);
+        let y2 = *y.get_unchecked(i + 1);
+        let y3 = *y.get_unchecked(i + 2);
+        let y4 = *y.get_unchecked(i + 3);
+        let y5 = *y.get_unchecked(i + 4);
+        let y6 = *y.get_unchecked(i + 5);
+        let y7 = *y.get_unchecked(i + 6);
+        let y8 = *y.get_unchecked(i + 7);
+
+        acc1 = M::add(acc1, M::sqrt(M::mul(x1, y1)));
+        acc2 = M::add(acc2, M::sqrt(M::mul(x2, y2)));
+        acc3 = M::add(acc3, M::sqrt(M::mul(x3, y3)));
+        acc4 = M::add(acc4, M::sqrt(M::mul(x4, y4)));
+        acc5 = M::add(acc5, M::sqrt(M::mul(x5, y5)));
+        acc6 = M::add(acc6, M::sqrt(M::mul(x6, y6)));
+        acc7 = M::add(acc7, M::sqrt(M::mul(x7, y7)));
+        acc8 = M::add(acc8, M::sqrt(M::mul(x8, y8)));
+
+        let x_sum = rollup_scalar_x8::<T, M>(x1, x2, x3, x4, x5, x6, x7, x8);
+        let y_sum = rollup_scalar_x8::<T, M>(y1, y2, y3, y4, y5, y6, y7, y8);
+        l1_x = M::add(l1_x, x_sum);
+        l1_y = M::add(l1_y, y_sum);
+
+        i += 8;
+    }
+
+    let product_sum =
+        rollup_scalar_x8::<T, M>(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8);
+    hellinger::<T, M>(M::add(product_sum, product_extra), l1_x, l1_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_hellinger};
+
+    #[test]
+    fn test_x1024_nofma_hellinger() {
+        let (x, y) = get_sample_vectors(1024);
+        let dist = unsafe { generic_xany_fallback_nofma_hellinger(&x, &y) };
+        assert_is_close(dist, simple_hellinger(&x, &y));
+    }
+
+    #[test]
+    fn test_xany_nofma_hellinger() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { generic_xany_fallback_nofma_hellinger(&x, &y) };
+        assert_is_close(dist, simple_hellinger(&x, &y));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::danger::utils::rollup_scalar_x8;
+use crate::math::*;
+
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `T` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_weighted_euclidean<T>(
+    x: &[T],
+    y: &[T],
+    w: &[T],
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    fallback_weighted_euclidean::<T, AutoMath>(x, y, w)
+}
+
+#[inline]
+unsafe fn fallback_weighted_euclidean<T, M>(x: &[T], y: &[T], w: &[T]) -> T
+where
+    T: Copy,
+    M: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+    debug_assert_eq!(
+        w.len(),
+        x.len(),
+        "Improper implementation detected, weights must match the vectors in size"
+    );
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let mut extra = M::zero();
+    let mut acc1 = M::zero();
+    let mut acc2 = M::zero();
+    let mut acc3 = M::zero();
+    let mut acc4 = M::zero();
+    let mut acc5 = M::zero();
+    let mut acc6 = M::zero();
+    let mut acc7 = M::zero();
+    let mut acc8 = M::zero();
+
+    let weighted = |i: usize| {
+        let diff = M::sub(*x.get_unchecked(i), *y.get_unchecked(i));
+        M::mul(*w.get_unchecked(i), M::mul(diff, diff))
+    };
+
+    let mut i = 0;
+    while i < offset_from {
+        extra = M::add(extra, weighted(i));
+        i += 1;
+    }
+
+    while i < len {
+        acc1 = M::add(acc1, weighted(i));
+        acc2 = M::add(acc2, weighted(i + 1));
+        acc3 = M::add(acc3, weighted(i + 2));
+        acc4 = M::add(acc4, weighted(i + 3));
+        acc5 = M::add(acc5, weighted(i + 4));
+        acc6 = M::add(acc6, weighted(i + 5));
+        acc7 = M::add(acc7, weighted(i + 6));
+        acc8 = M::add(acc8, weighted(i + 7));
+
+        i += 8;
+    }
+
+    let res = rollup_scalar_x8::<T, M>(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8);
+    M::add(res, extra)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_weighted_euclidean,
+    };
+
+    #[test]
+    fn test_x1024_nofma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(1024);
+        let (w, _) = get_sample_vectors(1024);
+        let dist = unsafe { generic_xany_fallback_nofma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+
+    #[test]
+    fn test_xany_nofma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(127);
+        let (w, _) = get_sample_vectors(127);
+        let dist = unsafe { generic_xany_fallback_nofma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::danger::generic_neon_dot_product::neon_dot;
+use crate::danger::generic_neon_vector_x_value::neon_op_value;
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the angular hyperplane of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_angular_hyperplane(x: &[f32], y: &[f32]) -> Vec<f32> {
+    neon_angular_hyperplane(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the angular hyperplane of two `f64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_angular_hyperplane(x: &[f64], y: &[f64]) -> Vec<f64> {
+    neon_angular_hyperplane(x, y)
+}
+
+#[inline(always)]
+unsafe fn neon_angular_hyperplane<T>(x: &[T], y: &[T]) -> Vec<T>
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let norm_x = safe_norm(neon_dot(x, x));
+    let norm_y = safe_norm(neon_dot(y, y));
+
+    let len = x.len();
+    let step = T::LANES * 2;
+    let offset_from = len % step;
+
+    let mut hyperplane = vec![AutoMath::zero(); len];
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let hyperplane_ptr = hyperplane.as_mut_ptr();
+
+    let norm_x_reg = T::splat(norm_x);
+    let norm_y_reg = T::splat(norm_y);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::div(T::load(x_ptr.add(i)), norm_x_reg);
+        let x2 = T::div(T::load(x_ptr.add(i + T::LANES)), norm_x_reg);
+
+        let y1 = T::div(T::load(y_ptr.add(i)), norm_y_reg);
+        let y2 = T::div(T::load(y_ptr.add(i + T::LANES)), norm_y_reg);
+
+        T::store(hyperplane_ptr.add(i), T::sub(x1, y1));
+        T::store(hyperplane_ptr.add(i + T::LANES), T::sub(x2, y2));
+
+        i += step;
+    }
+
+    while i < len {
+        let x = AutoMath::div(*x.get_unchecked(i), norm_x);
+        let y = AutoMath::div(*y.get_unchecked(i), norm_y);
+        *hyperplane.get_unchecked_mut(i) = AutoMath::sub(x, y);
+
+        i += 1;
+    }
+
+    let norm_hyperplane = safe_norm(neon_dot(&hyperplane, &hyperplane));
+    neon_op_value(&mut hyperplane, norm_hyperplane, T::div, AutoMath::div);
+
+    hyperplane
+}
+
+#[inline(always)]
+/// Takes the square root of the squared norm, replacing norms which are too
+/// small to divide by with `1.0`.
+fn safe_norm<T>(squared_norm: T) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    let norm = AutoMath::sqrt(squared_norm);
+    if AutoMath::abs(norm) < T::EPSILON {
+        AutoMath::one()
+    } else {
+        norm
+    }
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_angular_hyperplane,
+        to_f32_vector,
+    };
+
+    #[test]
+    fn test_f32_xany_fma_angular_hyperplane() {
+        let (x, y) = get_sample_vectors(127);
+        let hyperplane = unsafe { f32_xany_neon_fma_angular_hyperplane(&x, &y) };
+        assert_is_close_vector(&hyperplane, &simple_angular_hyperplane(&x, &y));
+    }
+
+    #[test]
+    fn test_f64_xany_fma_angular_hyperplane() {
+        let (x, y) = get_sample_vectors::<f32>(127);
+        let x64 = x.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let y64 = y.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let hyperplane = unsafe { f64_xany_neon_fma_angular_hyperplane(&x64, &y64) };
+        assert_is_close_vector(
+            &to_f32_vector(&hyperplane),
+            &simple_angular_hyperplane(&x, &y),
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::danger::{cosine, NeonFloat};
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_cosine(x: &[f32], y: &[f32]) -> f32 {
+    neon_cosine(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the cosine distance of two `f64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_cosine(x: &[f64], y: &[f64]) -> f64 {
+    neon_cosine(x, y)
+}
+
+#[inline(always)]
+unsafe fn neon_cosine<T>(x: &[T], y: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 2;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot1 = T::zero();
+    let mut dot2 = T::zero();
+    let mut norm_x1 = T::zero();
+    let mut norm_x2 = T::zero();
+    let mut norm_y1 = T::zero();
+    let mut norm_y2 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(x_ptr.add(i));
+        let x2 = T::load(x_ptr.add(i + T::LANES));
+
+        let y1 = T::load(y_ptr.add(i));
+        let y2 = T::load(y_ptr.add(i + T::LANES));
+
+        dot1 = T::fma(dot1, x1, y1);
+        dot2 = T::fma(dot2, x2, y2);
+        norm_x1 = T::fma(norm_x1, x1, x1);
+        norm_x2 = T::fma(norm_x2, x2, x2);
+        norm_y1 = T::fma(norm_y1, y1, y1);
+        norm_y2 = T::fma(norm_y2, y2, y2);
+
+        i += step;
+    }
+
+    let mut dot_product = T::sum(T::add(dot1, dot2));
+    let mut norm_x = T::sum(T::add(norm_x1, norm_x2));
+    let mut norm_y = T::sum(T::add(norm_y1, norm_y2));
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(x, x));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(y, y));
+
+        i += 1;
+    }
+
+    cosine::<T, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_cosine};
+
+    #[test]
+    fn test_f32_xany_fma_cosine() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f32_xany_neon_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&x, &y));
+    }
+
+    #[test]
+    fn test_f64_xany_fma_cosine() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f64_xany_neon_fma_cosine(&x, &y) };
+        assert_is_close(dist as f32, simple_cosine(&x, &y) as f32);
+    }
+}
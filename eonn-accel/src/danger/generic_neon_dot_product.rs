@@ -0,0 +1,111 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the dot product of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_dot(x: &[f32], y: &[f32]) -> f32 {
+    neon_dot(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the dot product of two `f64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_dot(x: &[f64], y: &[f64]) -> f64 {
+    neon_dot(x, y)
+}
+
+#[inline(always)]
+pub(super) unsafe fn neon_dot<T>(x: &[T], y: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = T::zero();
+    let mut acc2 = T::zero();
+    let mut acc3 = T::zero();
+    let mut acc4 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(x_ptr.add(i));
+        let x2 = T::load(x_ptr.add(i + T::LANES));
+        let x3 = T::load(x_ptr.add(i + T::LANES * 2));
+        let x4 = T::load(x_ptr.add(i + T::LANES * 3));
+
+        let y1 = T::load(y_ptr.add(i));
+        let y2 = T::load(y_ptr.add(i + T::LANES));
+        let y3 = T::load(y_ptr.add(i + T::LANES * 2));
+        let y4 = T::load(y_ptr.add(i + T::LANES * 3));
+
+        acc1 = T::fma(acc1, x1, y1);
+        acc2 = T::fma(acc2, x2, y2);
+        acc3 = T::fma(acc3, x3, y3);
+        acc4 = T::fma(acc4, x4, y4);
+
+        i += step;
+    }
+
+    let mut total = T::sum(T::add(T::add(acc1, acc2), T::add(acc3, acc4)));
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_fma_dot() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f32_xany_neon_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&x, &y));
+    }
+
+    #[test]
+    fn test_f64_xany_fma_dot() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f64_xany_neon_fma_dot(&x, &y) };
+        assert_is_close(dist as f32, simple_dot(&x, &y) as f32);
+    }
+}
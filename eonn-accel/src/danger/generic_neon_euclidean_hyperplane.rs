@@ -0,0 +1,149 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Euclidean hyperplane of two `f32` vectors
+/// and the offset from origin.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_euclidean_hyperplane(
+    x: &[f32],
+    y: &[f32],
+) -> (Vec<f32>, f32) {
+    neon_euclidean_hyperplane(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Euclidean hyperplane of two `f64` vectors
+/// and the offset from origin.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_euclidean_hyperplane(
+    x: &[f64],
+    y: &[f64],
+) -> (Vec<f64>, f64) {
+    neon_euclidean_hyperplane(x, y)
+}
+
+#[inline(always)]
+unsafe fn neon_euclidean_hyperplane<T>(x: &[T], y: &[T]) -> (Vec<T>, T)
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 2;
+    let offset_from = len % step;
+
+    let mut hyperplane = vec![AutoMath::zero(); len];
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let hyperplane_ptr = hyperplane.as_mut_ptr();
+
+    let half = T::splat(AutoMath::div(
+        AutoMath::one(),
+        AutoMath::add(AutoMath::one(), AutoMath::one()),
+    ));
+    let mut offset_acc1 = T::zero();
+    let mut offset_acc2 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(x_ptr.add(i));
+        let x2 = T::load(x_ptr.add(i + T::LANES));
+
+        let y1 = T::load(y_ptr.add(i));
+        let y2 = T::load(y_ptr.add(i + T::LANES));
+
+        let diff1 = T::sub(x1, y1);
+        let diff2 = T::sub(x2, y2);
+
+        let mean1 = T::mul(T::add(x1, y1), half);
+        let mean2 = T::mul(T::add(x2, y2), half);
+
+        offset_acc1 = T::fma(offset_acc1, diff1, mean1);
+        offset_acc2 = T::fma(offset_acc2, diff2, mean2);
+
+        T::store(hyperplane_ptr.add(i), diff1);
+        T::store(hyperplane_ptr.add(i + T::LANES), diff2);
+
+        i += step;
+    }
+
+    let mut offset = T::sum(T::add(offset_acc1, offset_acc2));
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+
+        let diff = AutoMath::sub(x, y);
+        let mean = AutoMath::div(
+            AutoMath::add(x, y),
+            AutoMath::add(AutoMath::one(), AutoMath::one()),
+        );
+
+        offset = AutoMath::add(offset, AutoMath::mul(diff, mean));
+        *hyperplane.get_unchecked_mut(i) = diff;
+
+        i += 1;
+    }
+
+    (hyperplane, AutoMath::sub(AutoMath::zero(), offset))
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_euclidean_hyperplane,
+        to_f32_vector,
+    };
+
+    #[test]
+    fn test_f32_xany_fma_euclidean_hyperplane() {
+        let (x, y) = get_sample_vectors(127);
+        let (hyperplane, offset) =
+            unsafe { f32_xany_neon_fma_euclidean_hyperplane(&x, &y) };
+        let (expected_hyperplane, expected_offset) = simple_euclidean_hyperplane(&x, &y);
+        assert_is_close_vector(&hyperplane, &expected_hyperplane);
+        assert_is_close(offset, expected_offset);
+    }
+
+    #[test]
+    fn test_f64_xany_fma_euclidean_hyperplane() {
+        let (x, y) = get_sample_vectors::<f32>(127);
+        let x64 = x.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let y64 = y.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let (hyperplane, offset) =
+            unsafe { f64_xany_neon_fma_euclidean_hyperplane(&x64, &y64) };
+        let (expected_hyperplane, expected_offset) = simple_euclidean_hyperplane(&x, &y);
+        assert_is_close_vector(&to_f32_vector(&hyperplane), &expected_hyperplane);
+        assert_is_close(offset as f32, expected_offset);
+    }
+}
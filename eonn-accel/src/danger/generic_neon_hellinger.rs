@@ -0,0 +1,119 @@
+use crate::danger::{hellinger, NeonFloat};
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Hellinger distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_nofma_hellinger(x: &[f32], y: &[f32]) -> f32 {
+    neon_hellinger(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Hellinger distance of two `f64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite and non-negative, although it is
+/// not going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_nofma_hellinger(x: &[f64], y: &[f64]) -> f64 {
+    neon_hellinger(x, y)
+}
+
+#[inline(always)]
+unsafe fn neon_hellinger<T>(x: &[T], y: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 2;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut product1 = T::zero();
+    let mut product2 = T::zero();
+    let mut l1_x1 = T::zero();
+    let mut l1_x2 = T::zero();
+    let mut l1_y1 = T::zero();
+    let mut l1_y2 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(x_ptr.add(i));
+        let x2 = T::load(x_ptr.add(i + T::LANES));
+
+        let y1 = T::load(y_ptr.add(i));
+        let y2 = T::load(y_ptr.add(i + T::LANES));
+
+        product1 = T::add(product1, <T as NeonFloat>::sqrt(T::mul(x1, y1)));
+        product2 = T::add(product2, <T as NeonFloat>::sqrt(T::mul(x2, y2)));
+        l1_x1 = T::add(l1_x1, x1);
+        l1_x2 = T::add(l1_x2, x2);
+        l1_y1 = T::add(l1_y1, y1);
+        l1_y2 = T::add(l1_y2, y2);
+
+        i += step;
+    }
+
+    let mut sqrt_product_sum = T::sum(T::add(product1, product2));
+    let mut l1_x = T::sum(T::add(l1_x1, l1_x2));
+    let mut l1_y = T::sum(T::add(l1_y1, l1_y2));
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+
+        sqrt_product_sum =
+            AutoMath::add(sqrt_product_sum, AutoMath::sqrt(AutoMath::mul(x, y)));
+        l1_x = AutoMath::add(l1_x, x);
+        l1_y = AutoMath::add(l1_y, y);
+
+        i += 1;
+    }
+
+    hellinger::<T, AutoMath>(sqrt_product_sum, l1_x, l1_y)
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_hellinger};
+
+    #[test]
+    fn test_f32_xany_nofma_hellinger() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f32_xany_neon_nofma_hellinger(&x, &y) };
+        assert_is_close(dist, simple_hellinger(&x, &y));
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_hellinger() {
+        let (x, y) = get_sample_vectors::<f32>(127);
+        let x64 = x.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let y64 = y.iter().map(|&v| v as f64).collect::<Vec<_>>();
+        let dist = unsafe { f64_xany_neon_nofma_hellinger(&x64, &y64) };
+        assert_is_close(dist as f32, simple_hellinger(&x, &y));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Manhattan distance of two `f32` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_nofma_manhattan(x: &[f32], y: &[f32]) -> f32 {
+    neon_manhattan(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the Manhattan distance of two `f64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_nofma_manhattan(x: &[f64], y: &[f64]) -> f64 {
+    neon_manhattan(x, y)
+}
+
+#[inline(always)]
+unsafe fn neon_manhattan<T>(x: &[T], y: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = T::zero();
+    let mut acc2 = T::zero();
+    let mut acc3 = T::zero();
+    let mut acc4 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 = T::abs(T::sub(T::load(x_ptr.add(i)), T::load(y_ptr.add(i))));
+        let diff2 = T::abs(T::sub(
+            T::load(x_ptr.add(i + T::LANES)),
+            T::load(y_ptr.add(i + T::LANES)),
+        ));
+        let diff3 = T::abs(T::sub(
+            T::load(x_ptr.add(i + T::LANES * 2)),
+            T::load(y_ptr.add(i + T::LANES * 2)),
+        ));
+        let diff4 = T::abs(T::sub(
+            T::load(x_ptr.add(i + T::LANES * 3)),
+            T::load(y_ptr.add(i + T::LANES * 3)),
+        ));
+
+        acc1 = T::add(acc1, diff1);
+        acc2 = T::add(acc2, diff2);
+        acc3 = T::add(acc3, diff3);
+        acc4 = T::add(acc4, diff4);
+
+        i += step;
+    }
+
+    let mut total = T::sum(T::add(T::add(acc1, acc2), T::add(acc3, acc4)));
+
+    while i < len {
+        let diff =
+            AutoMath::abs(AutoMath::sub(*x.get_unchecked(i), *y.get_unchecked(i)));
+        total = AutoMath::add(total, diff);
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_manhattan};
+
+    #[test]
+    fn test_f32_xany_nofma_manhattan() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f32_xany_neon_nofma_manhattan(&x, &y) };
+        assert_is_close(dist, simple_manhattan(&x, &y));
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_manhattan() {
+        let (x, y) = get_sample_vectors(127);
+        let dist = unsafe { f64_xany_neon_nofma_manhattan(&x, &y) };
+        assert_is_close(dist as f32, simple_manhattan(&x, &y) as f32);
+    }
+}
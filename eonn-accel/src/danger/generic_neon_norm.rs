@@ -0,0 +1,53 @@
+use crate::danger::generic_neon_dot_product::neon_dot;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the squared L2 norm of one `f32` vector.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_norm(x: &[f32]) -> f32 {
+    neon_dot(x, x)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the squared L2 norm of one `f64` vector.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_norm(x: &[f64]) -> f64 {
+    neon_dot(x, x)
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_fma_norm() {
+        let (x, _) = get_sample_vectors(127);
+        let norm = unsafe { f32_xany_neon_fma_norm(&x) };
+        assert_is_close(norm, simple_dot(&x, &x));
+    }
+
+    #[test]
+    fn test_f64_xany_fma_norm() {
+        let (x, _) = get_sample_vectors(127);
+        let norm = unsafe { f64_xany_neon_fma_norm(&x) };
+        assert_is_close(norm as f32, simple_dot(&x, &x) as f32);
+    }
+}
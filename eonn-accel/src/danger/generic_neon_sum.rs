@@ -0,0 +1,90 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the horizontal sum of the given vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_nofma_sum_horizontal(arr: &[f32]) -> f32 {
+    neon_sum_horizontal(arr)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the horizontal sum of the given vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_nofma_sum_horizontal(arr: &[f64]) -> f64 {
+    neon_sum_horizontal(arr)
+}
+
+#[inline(always)]
+unsafe fn neon_sum_horizontal<T>(arr: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    let len = arr.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let arr_ptr = arr.as_ptr();
+
+    let mut acc1 = T::zero();
+    let mut acc2 = T::zero();
+    let mut acc3 = T::zero();
+    let mut acc4 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        acc1 = T::add(acc1, T::load(arr_ptr.add(i)));
+        acc2 = T::add(acc2, T::load(arr_ptr.add(i + T::LANES)));
+        acc3 = T::add(acc3, T::load(arr_ptr.add(i + T::LANES * 2)));
+        acc4 = T::add(acc4, T::load(arr_ptr.add(i + T::LANES * 3)));
+
+        i += step;
+    }
+
+    let mut total = T::sum(T::add(T::add(acc1, acc2), T::add(acc3, acc4)));
+
+    while i < len {
+        total = AutoMath::add(total, *arr.get_unchecked(i));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_f32_xany_nofma_sum() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let sum = unsafe { f32_xany_neon_nofma_sum_horizontal(&x) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_sum() {
+        let (x, _) = get_sample_vectors::<f64>(131);
+        let sum = unsafe { f64_xany_neon_nofma_sum_horizontal(&x) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Divides each element in the provided mutable `[f32]` vector by `value`.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f32_xany_neon_nofma_div_value(arr: &mut [f32], value: f32) {
+    neon_op_value(arr, value, <f32 as NeonFloat>::div, AutoMath::div)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Divides each element in the provided mutable `[f64]` vector by `value`.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f64_xany_neon_nofma_div_value(arr: &mut [f64], value: f64) {
+    neon_op_value(arr, value, <f64 as NeonFloat>::div, AutoMath::div)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Multiplies each element in the provided mutable `[f32]` vector by `value`.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f32_xany_neon_nofma_mul_value(arr: &mut [f32], value: f32) {
+    neon_op_value(arr, value, <f32 as NeonFloat>::mul, AutoMath::mul)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Multiplies each element in the provided mutable `[f64]` vector by `value`.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f64_xany_neon_nofma_mul_value(arr: &mut [f64], value: f64) {
+    neon_op_value(arr, value, <f64 as NeonFloat>::mul, AutoMath::mul)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Adds `value` to each element in the provided mutable `[f32]` vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f32_xany_neon_nofma_add_value(arr: &mut [f32], value: f32) {
+    neon_op_value(arr, value, <f32 as NeonFloat>::add, AutoMath::add)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Adds `value` to each element in the provided mutable `[f64]` vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f64_xany_neon_nofma_add_value(arr: &mut [f64], value: f64) {
+    neon_op_value(arr, value, <f64 as NeonFloat>::add, AutoMath::add)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Subtracts `value` from each element in the provided mutable `[f32]` vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f32_xany_neon_nofma_sub_value(arr: &mut [f32], value: f32) {
+    neon_op_value(arr, value, <f32 as NeonFloat>::sub, AutoMath::sub)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Subtracts `value` from each element in the provided mutable `[f64]` vector.
+///
+/// # Safety
+///
+/// This routine is always safe to call on a CPU supporting NEON.
+pub unsafe fn f64_xany_neon_nofma_sub_value(arr: &mut [f64], value: f64) {
+    neon_op_value(arr, value, <f64 as NeonFloat>::sub, AutoMath::sub)
+}
+
+#[inline(always)]
+pub(super) unsafe fn neon_op_value<T>(
+    arr: &mut [T],
+    value: T,
+    op: unsafe fn(T::Register, T::Register) -> T::Register,
+    scalar_op: fn(T, T) -> T,
+) where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    let len = arr.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let arr_ptr = arr.as_mut_ptr();
+    let value_reg = T::splat(value);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(arr_ptr.add(i));
+        let x2 = T::load(arr_ptr.add(i + T::LANES));
+        let x3 = T::load(arr_ptr.add(i + T::LANES * 2));
+        let x4 = T::load(arr_ptr.add(i + T::LANES * 3));
+
+        T::store(arr_ptr.add(i), op(x1, value_reg));
+        T::store(arr_ptr.add(i + T::LANES), op(x2, value_reg));
+        T::store(arr_ptr.add(i + T::LANES * 2), op(x3, value_reg));
+        T::store(arr_ptr.add(i + T::LANES * 3), op(x4, value_reg));
+
+        i += step;
+    }
+
+    while i < len {
+        let x = arr.get_unchecked_mut(i);
+        *x = scalar_op(*x, value);
+
+        i += 1;
+    }
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_f32_xany_nofma_div() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_div_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_div() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_div_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_mul() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().copied().map(|v| v * value).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_mul_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_mul() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().copied().map(|v| v * value).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_mul_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_add() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().copied().map(|v| v + value).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_add_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_add() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().copied().map(|v| v + value).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_add_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_sub() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().copied().map(|v| v - value).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_sub_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_sub() {
+        let value = 2.0;
+        let (mut x, _) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().copied().map(|v| v - value).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_sub_value(&mut x, value) };
+        assert_eq!(x, expected);
+    }
+}
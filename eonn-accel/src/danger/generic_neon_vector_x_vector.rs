@@ -0,0 +1,219 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Divides the elements of `x` by the matching elements of `y` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_neon_nofma_div_vertical(x: &mut [f32], y: &[f32]) {
+    neon_op_vertical(x, y, <f32 as NeonFloat>::div, AutoMath::div)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Divides the elements of `x` by the matching elements of `y` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_neon_nofma_div_vertical(x: &mut [f64], y: &[f64]) {
+    neon_op_vertical(x, y, <f64 as NeonFloat>::div, AutoMath::div)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Multiplies the elements of `x` by the matching elements of `y` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_neon_nofma_mul_vertical(x: &mut [f32], y: &[f32]) {
+    neon_op_vertical(x, y, <f32 as NeonFloat>::mul, AutoMath::mul)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Multiplies the elements of `x` by the matching elements of `y` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_neon_nofma_mul_vertical(x: &mut [f64], y: &[f64]) {
+    neon_op_vertical(x, y, <f64 as NeonFloat>::mul, AutoMath::mul)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Adds the elements of `y` to the matching elements of `x` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_neon_nofma_add_vertical(x: &mut [f32], y: &[f32]) {
+    neon_op_vertical(x, y, <f32 as NeonFloat>::add, AutoMath::add)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Adds the elements of `y` to the matching elements of `x` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_neon_nofma_add_vertical(x: &mut [f64], y: &[f64]) {
+    neon_op_vertical(x, y, <f64 as NeonFloat>::add, AutoMath::add)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Subtracts the elements of `y` from the matching elements of `x` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_neon_nofma_sub_vertical(x: &mut [f32], y: &[f32]) {
+    neon_op_vertical(x, y, <f32 as NeonFloat>::sub, AutoMath::sub)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Subtracts the elements of `y` from the matching elements of `x` in place.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_neon_nofma_sub_vertical(x: &mut [f64], y: &[f64]) {
+    neon_op_vertical(x, y, <f64 as NeonFloat>::sub, AutoMath::sub)
+}
+
+#[inline(always)]
+unsafe fn neon_op_vertical<T>(
+    x: &mut [T],
+    y: &[T],
+    op: unsafe fn(T::Register, T::Register) -> T::Register,
+    scalar_op: fn(T, T) -> T,
+) where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load(x_ptr.add(i));
+        let x2 = T::load(x_ptr.add(i + T::LANES));
+        let x3 = T::load(x_ptr.add(i + T::LANES * 2));
+        let x4 = T::load(x_ptr.add(i + T::LANES * 3));
+
+        let y1 = T::load(y_ptr.add(i));
+        let y2 = T::load(y_ptr.add(i + T::LANES));
+        let y3 = T::load(y_ptr.add(i + T::LANES * 2));
+        let y4 = T::load(y_ptr.add(i + T::LANES * 3));
+
+        T::store(x_ptr.add(i), op(x1, y1));
+        T::store(x_ptr.add(i + T::LANES), op(x2, y2));
+        T::store(x_ptr.add(i + T::LANES * 2), op(x3, y3));
+        T::store(x_ptr.add(i + T::LANES * 3), op(x4, y4));
+
+        i += step;
+    }
+
+    while i < len {
+        let x = x.get_unchecked_mut(i);
+        *x = scalar_op(*x, *y.get_unchecked(i));
+
+        i += 1;
+    }
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_f32_xany_nofma_div_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x / y).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_div_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_div_vertical() {
+        let (mut x, y) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x / y).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_div_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_mul_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x * y).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_mul_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_mul_vertical() {
+        let (mut x, y) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x * y).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_mul_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_add_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x + y).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_add_vertical() {
+        let (mut x, y) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x + y).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_sub_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x - y).collect::<Vec<_>>();
+        unsafe { f32_xany_neon_nofma_sub_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_sub_vertical() {
+        let (mut x, y) = get_sample_vectors::<f64>(557);
+        let expected = x.iter().zip(&y).map(|(x, y)| x - y).collect::<Vec<_>>();
+        unsafe { f64_xany_neon_nofma_sub_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::danger::NeonFloat;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `f32` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_neon_fma_weighted_euclidean(
+    x: &[f32],
+    y: &[f32],
+    w: &[f32],
+) -> f32 {
+    neon_weighted_euclidean(x, y, w)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the weighted squared Euclidean distance of two `f64` vectors, scaling
+/// the squared difference of each dimension by the matching value in `w`.
+///
+/// # Safety
+///
+/// Vectors and weights **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_neon_fma_weighted_euclidean(
+    x: &[f64],
+    y: &[f64],
+    w: &[f64],
+) -> f64 {
+    neon_weighted_euclidean(x, y, w)
+}
+
+#[inline(always)]
+unsafe fn neon_weighted_euclidean<T>(x: &[T], y: &[T], w: &[T]) -> T
+where
+    T: NeonFloat,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must be equal length"
+    );
+    debug_assert_eq!(
+        w.len(),
+        x.len(),
+        "Improper implementation detected, weights must match the vectors"
+    );
+
+    let len = x.len();
+    let step = T::LANES * 4;
+    let offset_from = len % step;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+    let w_ptr = w.as_ptr();
+
+    let mut acc1 = T::zero();
+    let mut acc2 = T::zero();
+    let mut acc3 = T::zero();
+    let mut acc4 = T::zero();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 = T::sub(T::load(x_ptr.add(i)), T::load(y_ptr.add(i)));
+        let diff2 = T::sub(
+            T::load(x_ptr.add(i + T::LANES)),
+            T::load(y_ptr.add(i + T::LANES)),
+        );
+        let diff3 = T::sub(
+            T::load(x_ptr.add(i + T::LANES * 2)),
+            T::load(y_ptr.add(i + T::LANES * 2)),
+        );
+        let diff4 = T::sub(
+            T::load(x_ptr.add(i + T::LANES * 3)),
+            T::load(y_ptr.add(i + T::LANES * 3)),
+        );
+
+        let w1 = T::load(w_ptr.add(i));
+        let w2 = T::load(w_ptr.add(i + T::LANES));
+        let w3 = T::load(w_ptr.add(i + T::LANES * 2));
+        let w4 = T::load(w_ptr.add(i + T::LANES * 3));
+
+        acc1 = T::fma(acc1, T::mul(diff1, diff1), w1);
+        acc2 = T::fma(acc2, T::mul(diff2, diff2), w2);
+        acc3 = T::fma(acc3, T::mul(diff3, diff3), w3);
+        acc4 = T::fma(acc4, T::mul(diff4, diff4), w4);
+
+        i += step;
+    }
+
+    let mut total = T::sum(T::add(T::add(acc1, acc2), T::add(acc3, acc4)));
+
+    while i < len {
+        let diff = AutoMath::sub(*x.get_unchecked(i), *y.get_unchecked(i));
+        let weighted = AutoMath::mul(AutoMath::mul(diff, diff), *w.get_unchecked(i));
+        total = AutoMath::add(total, weighted);
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_weighted_euclidean,
+    };
+
+    #[test]
+    fn test_f32_xany_fma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors(127);
+        let (w, _) = get_sample_vectors(127);
+        let dist = unsafe { f32_xany_neon_fma_weighted_euclidean(&x, &y, &w) };
+        assert_is_close(dist, simple_weighted_euclidean(&x, &y, &w));
+    }
+
+    #[test]
+    fn test_f64_xany_fma_weighted_euclidean() {
+        let (x, y) = get_sample_vectors::<f32>(127);
+        let (w, _) = get_sample_vectors::<f32>(127);
+        let [x64, y64, w64] =
+            [&x, &y, &w].map(|v| v.iter().map(|&v| v as f64).collect::<Vec<_>>());
+        let dist = unsafe { f64_xany_neon_fma_weighted_euclidean(&x64, &y64, &w64) };
+        assert_is_close(dist as f32, simple_weighted_euclidean(&x, &y, &w));
+    }
+}
@@ -0,0 +1,138 @@
+use std::arch::x86_64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::{cosine, mul_add_avx2_ps, sum_avx2_ps, WidenPs};
+use crate::math::*;
+
+#[target_feature(enable = "avx2", enable = "f16c")]
+#[inline]
+/// Computes the cosine distance of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx2_nofma_cosine(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx2_cosine::<f16, false>(x, y)
+}
+
+#[target_feature(enable = "avx2", enable = "f16c", enable = "fma")]
+#[inline]
+/// Computes the cosine distance of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx2_fma_cosine(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx2_cosine::<f16, true>(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the cosine distance of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx2_nofma_cosine(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx2_cosine::<bf16, false>(x, y)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the cosine distance of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx2_fma_cosine(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx2_cosine::<bf16, true>(x, y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_avx2_cosine<T: WidenPs, const FMA: bool>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot_acc1 = _mm256_setzero_ps();
+    let mut dot_acc2 = _mm256_setzero_ps();
+    let mut norm_x_acc1 = _mm256_setzero_ps();
+    let mut norm_x_acc2 = _mm256_setzero_ps();
+    let mut norm_y_acc1 = _mm256_setzero_ps();
+    let mut norm_y_acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_avx2_ps(x_ptr.add(i));
+        let x2 = T::load_avx2_ps(x_ptr.add(i + 8));
+        let y1 = T::load_avx2_ps(y_ptr.add(i));
+        let y2 = T::load_avx2_ps(y_ptr.add(i + 8));
+
+        dot_acc1 = mul_add_avx2_ps::<FMA>(x1, y1, dot_acc1);
+        dot_acc2 = mul_add_avx2_ps::<FMA>(x2, y2, dot_acc2);
+        norm_x_acc1 = mul_add_avx2_ps::<FMA>(x1, x1, norm_x_acc1);
+        norm_x_acc2 = mul_add_avx2_ps::<FMA>(x2, x2, norm_x_acc2);
+        norm_y_acc1 = mul_add_avx2_ps::<FMA>(y1, y1, norm_y_acc1);
+        norm_y_acc2 = mul_add_avx2_ps::<FMA>(y2, y2, norm_y_acc2);
+
+        i += 16;
+    }
+
+    let mut dot_product = sum_avx2_ps(_mm256_add_ps(dot_acc1, dot_acc2));
+    let mut norm_x = sum_avx2_ps(_mm256_add_ps(norm_x_acc1, norm_x_acc2));
+    let mut norm_y = sum_avx2_ps(_mm256_add_ps(norm_y_acc1, norm_y_acc2));
+
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(x, x));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(y, y));
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_cosine};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx2_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_f16_xany_nofma_cosine() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx2_nofma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx2_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_cosine() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx2_nofma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+}
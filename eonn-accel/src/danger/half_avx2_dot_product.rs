@@ -0,0 +1,136 @@
+use std::arch::x86_64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::{mul_add_avx2_ps, sum_avx2_ps, WidenPs};
+use crate::math::*;
+
+#[target_feature(enable = "avx2", enable = "f16c")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx2_nofma_dot(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx2_dot::<f16, false>(x, y)
+}
+
+#[target_feature(enable = "avx2", enable = "f16c", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx2_fma_dot(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx2_dot::<f16, true>(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx2_nofma_dot(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx2_dot::<bf16, false>(x, y)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx2_fma_dot(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx2_dot::<bf16, true>(x, y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_avx2_dot<T: WidenPs, const FMA: bool>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+    let mut acc4 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_avx2_ps(x_ptr.add(i));
+        let x2 = T::load_avx2_ps(x_ptr.add(i + 8));
+        let x3 = T::load_avx2_ps(x_ptr.add(i + 16));
+        let x4 = T::load_avx2_ps(x_ptr.add(i + 24));
+
+        let y1 = T::load_avx2_ps(y_ptr.add(i));
+        let y2 = T::load_avx2_ps(y_ptr.add(i + 8));
+        let y3 = T::load_avx2_ps(y_ptr.add(i + 16));
+        let y4 = T::load_avx2_ps(y_ptr.add(i + 24));
+
+        acc1 = mul_add_avx2_ps::<FMA>(x1, y1, acc1);
+        acc2 = mul_add_avx2_ps::<FMA>(x2, y2, acc2);
+        acc3 = mul_add_avx2_ps::<FMA>(x3, y3, acc3);
+        acc4 = mul_add_avx2_ps::<FMA>(x4, y4, acc4);
+
+        i += 32;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    acc1 = _mm256_add_ps(acc1, acc2);
+    acc3 = _mm256_add_ps(acc3, acc4);
+    AutoMath::add(total, sum_avx2_ps(_mm256_add_ps(acc1, acc3)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_dot};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx2_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_f16_xany_nofma_dot() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx2_nofma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx2_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_dot() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx2_nofma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+}
@@ -0,0 +1,163 @@
+use std::arch::x86_64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::{cosine, load_avx512_bh, WidenPs};
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the cosine distance of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx512_fma_cosine(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx512_cosine(x, y)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the cosine distance of two `bf16` vectors, accumulating in `f32`.
+///
+/// This widens each value as it is loaded, for CPUs without AVX512-BF16, see
+/// [bf16_xany_avx512bf16_cosine] otherwise.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx512_fma_cosine(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx512_cosine(x, y)
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bf16")]
+#[inline]
+/// Computes the cosine distance of two `bf16` vectors with the AVX512-BF16
+/// pairwise dot product instructions, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx512bf16_cosine(x: &[bf16], y: &[bf16]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot_acc = _mm512_setzero_ps();
+    let mut norm_x_acc = _mm512_setzero_ps();
+    let mut norm_y_acc = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = load_avx512_bh(x_ptr.add(i));
+        let y = load_avx512_bh(y_ptr.add(i));
+
+        dot_acc = _mm512_dpbf16_ps(dot_acc, x, y);
+        norm_x_acc = _mm512_dpbf16_ps(norm_x_acc, x, x);
+        norm_y_acc = _mm512_dpbf16_ps(norm_y_acc, y, y);
+
+        i += 32;
+    }
+
+    let mut dot_product = _mm512_reduce_add_ps(dot_acc);
+    let mut norm_x = _mm512_reduce_add_ps(norm_x_acc);
+    let mut norm_y = _mm512_reduce_add_ps(norm_y_acc);
+
+    for n in i..len {
+        let x = x.get_unchecked(n).to_f32();
+        let y = y.get_unchecked(n).to_f32();
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(x, x));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(y, y));
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_avx512_cosine<T: WidenPs>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot_acc1 = _mm512_setzero_ps();
+    let mut dot_acc2 = _mm512_setzero_ps();
+    let mut norm_x_acc1 = _mm512_setzero_ps();
+    let mut norm_x_acc2 = _mm512_setzero_ps();
+    let mut norm_y_acc1 = _mm512_setzero_ps();
+    let mut norm_y_acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_avx512_ps(x_ptr.add(i));
+        let x2 = T::load_avx512_ps(x_ptr.add(i + 16));
+        let y1 = T::load_avx512_ps(y_ptr.add(i));
+        let y2 = T::load_avx512_ps(y_ptr.add(i + 16));
+
+        dot_acc1 = _mm512_fmadd_ps(x1, y1, dot_acc1);
+        dot_acc2 = _mm512_fmadd_ps(x2, y2, dot_acc2);
+        norm_x_acc1 = _mm512_fmadd_ps(x1, x1, norm_x_acc1);
+        norm_x_acc2 = _mm512_fmadd_ps(x2, x2, norm_x_acc2);
+        norm_y_acc1 = _mm512_fmadd_ps(y1, y1, norm_y_acc1);
+        norm_y_acc2 = _mm512_fmadd_ps(y2, y2, norm_y_acc2);
+
+        i += 32;
+    }
+
+    let mut dot_product = _mm512_reduce_add_ps(_mm512_add_ps(dot_acc1, dot_acc2));
+    let mut norm_x = _mm512_reduce_add_ps(_mm512_add_ps(norm_x_acc1, norm_x_acc2));
+    let mut norm_y = _mm512_reduce_add_ps(_mm512_add_ps(norm_y_acc1, norm_y_acc2));
+
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(x, x));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(y, y));
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_cosine};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx512_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx512_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_avx512bf16_cosine() {
+        if !is_x86_feature_detected!("avx512bf16") {
+            return;
+        }
+
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx512bf16_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+}
@@ -0,0 +1,159 @@
+use std::arch::x86_64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::{load_avx512_bh, WidenPs};
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, accumulating in `f32`.
+///
+/// The AVX512-FP16 arithmetic instructions are not used, as they would accumulate
+/// in half precision, instead each value is widened as it is loaded.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx512_fma_dot(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx512_dot(x, y)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of two `bf16` vectors, accumulating in `f32`.
+///
+/// This widens each value as it is loaded, for CPUs without AVX512-BF16, see
+/// [bf16_xany_avx512bf16_dot] otherwise.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx512_fma_dot(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx512_dot(x, y)
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bf16")]
+#[inline]
+/// Computes the dot product of two `bf16` vectors with the AVX512-BF16 pairwise
+/// dot product instructions, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx512bf16_dot(x: &[bf16], y: &[bf16]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = load_avx512_bh(x_ptr.add(i));
+        let x2 = load_avx512_bh(x_ptr.add(i + 32));
+        let y1 = load_avx512_bh(y_ptr.add(i));
+        let y2 = load_avx512_bh(y_ptr.add(i + 32));
+
+        acc1 = _mm512_dpbf16_ps(acc1, x1, y1);
+        acc2 = _mm512_dpbf16_ps(acc2, x2, y2);
+
+        i += 64;
+    }
+
+    let mut total = _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc2));
+    for n in i..len {
+        let x = x.get_unchecked(n).to_f32();
+        let y = y.get_unchecked(n).to_f32();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    total
+}
+
+#[inline(always)]
+unsafe fn half_xany_avx512_dot<T: WidenPs>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+    let mut acc3 = _mm512_setzero_ps();
+    let mut acc4 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_avx512_ps(x_ptr.add(i));
+        let x2 = T::load_avx512_ps(x_ptr.add(i + 16));
+        let x3 = T::load_avx512_ps(x_ptr.add(i + 32));
+        let x4 = T::load_avx512_ps(x_ptr.add(i + 48));
+
+        let y1 = T::load_avx512_ps(y_ptr.add(i));
+        let y2 = T::load_avx512_ps(y_ptr.add(i + 16));
+        let y3 = T::load_avx512_ps(y_ptr.add(i + 32));
+        let y4 = T::load_avx512_ps(y_ptr.add(i + 48));
+
+        acc1 = _mm512_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm512_fmadd_ps(x2, y2, acc2);
+        acc3 = _mm512_fmadd_ps(x3, y3, acc3);
+        acc4 = _mm512_fmadd_ps(x4, y4, acc4);
+
+        i += 64;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    acc1 = _mm512_add_ps(acc1, acc2);
+    acc3 = _mm512_add_ps(acc3, acc4);
+    AutoMath::add(total, _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc3)))
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_dot};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx512_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx512_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_avx512bf16_dot() {
+        if !is_x86_feature_detected!("avx512bf16") {
+            return;
+        }
+
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx512bf16_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+}
@@ -0,0 +1,112 @@
+use std::arch::x86_64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::WidenPs;
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the squared Euclidean distance of two `f16` vectors, accumulating
+/// in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_avx512_fma_euclidean(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_avx512_euclidean(x, y)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the squared Euclidean distance of two `bf16` vectors, accumulating
+/// in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_avx512_fma_euclidean(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_avx512_euclidean(x, y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_avx512_euclidean<T: WidenPs>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+    let mut acc3 = _mm512_setzero_ps();
+    let mut acc4 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let diff1 = _mm512_sub_ps(
+            T::load_avx512_ps(x_ptr.add(i)),
+            T::load_avx512_ps(y_ptr.add(i)),
+        );
+        let diff2 = _mm512_sub_ps(
+            T::load_avx512_ps(x_ptr.add(i + 16)),
+            T::load_avx512_ps(y_ptr.add(i + 16)),
+        );
+        let diff3 = _mm512_sub_ps(
+            T::load_avx512_ps(x_ptr.add(i + 32)),
+            T::load_avx512_ps(y_ptr.add(i + 32)),
+        );
+        let diff4 = _mm512_sub_ps(
+            T::load_avx512_ps(x_ptr.add(i + 48)),
+            T::load_avx512_ps(y_ptr.add(i + 48)),
+        );
+
+        acc1 = _mm512_fmadd_ps(diff1, diff1, acc1);
+        acc2 = _mm512_fmadd_ps(diff2, diff2, acc2);
+        acc3 = _mm512_fmadd_ps(diff3, diff3, acc3);
+        acc4 = _mm512_fmadd_ps(diff4, diff4, acc4);
+
+        i += 64;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        let diff = AutoMath::sub(x, y);
+        total = AutoMath::add(total, AutoMath::mul(diff, diff));
+    }
+
+    acc1 = _mm512_add_ps(acc1, acc2);
+    acc3 = _mm512_add_ps(acc3, acc4);
+    AutoMath::add(total, _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc3)))
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_half_sample_vectors,
+        simple_euclidean,
+    };
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_euclidean() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_avx512_fma_euclidean(&x, &y) };
+        assert_is_close(dist, simple_euclidean(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_euclidean() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_avx512_fma_euclidean(&x, &y) };
+        assert_is_close(dist, simple_euclidean(&widen(&x), &widen(&y)));
+    }
+}
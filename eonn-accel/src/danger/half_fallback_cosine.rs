@@ -0,0 +1,50 @@
+use crate::danger::{cosine, half_xany_fallback_nofma_dot};
+use crate::math::*;
+use crate::VectorType;
+
+#[inline]
+/// Computes the cosine distance of two half precision `T` vectors, widening each
+/// element and accumulating in `f32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn half_xany_fallback_nofma_cosine<T>(x: &[T], y: &[T]) -> f32
+where
+    T: VectorType<Accumulator = f32>,
+{
+    let norm_x = half_xany_fallback_nofma_dot(x, x);
+    let norm_y = half_xany_fallback_nofma_dot(y, y);
+    let dot_product = half_xany_fallback_nofma_dot(x, y);
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use half::{bf16, f16};
+
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_cosine};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_nofma_cosine() {
+        let (x, y) = get_half_sample_vectors::<f16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_cosine(&x, &y) };
+        let expected = simple_cosine(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_cosine() {
+        let (x, y) = get_half_sample_vectors::<bf16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_cosine(&x, &y) };
+        let expected = simple_cosine(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::math::*;
+use crate::VectorType;
+
+#[inline]
+/// Computes the dot product of two half precision `T` vectors, widening each
+/// element and accumulating in `f32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn half_xany_fallback_nofma_dot<T>(x: &[T], y: &[T]) -> f32
+where
+    T: VectorType<Accumulator = f32>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dot_product = 0.0;
+    for i in 0..x.len() {
+        let x = x.get_unchecked(i).to_accumulator();
+        let y = y.get_unchecked(i).to_accumulator();
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+    }
+
+    dot_product
+}
+
+#[inline]
+/// Computes the sum of a half precision `T` vector, widening each element and
+/// accumulating in `f32`.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn half_xany_fallback_nofma_sum<T>(x: &[T]) -> f32
+where
+    T: VectorType<Accumulator = f32>,
+{
+    let mut sum = 0.0;
+    for v in x {
+        sum = AutoMath::add(sum, v.to_accumulator());
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use half::{bf16, f16};
+
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_dot};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_nofma_dot() {
+        let (x, y) = get_half_sample_vectors::<f16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_dot(&x, &y) };
+        let expected = simple_dot(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_dot() {
+        let (x, y) = get_half_sample_vectors::<bf16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_dot(&x, &y) };
+        let expected = simple_dot(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_sum() {
+        let (x, _) = get_half_sample_vectors::<bf16>(514);
+        let sum = unsafe { half_xany_fallback_nofma_sum(&x) };
+        let expected = widen(&x).iter().sum::<f32>();
+        assert_is_close(sum, expected);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::math::*;
+use crate::VectorType;
+
+#[inline]
+/// Computes the squared Euclidean distance of two half precision `T` vectors,
+/// widening each element and accumulating in `f32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn half_xany_fallback_nofma_euclidean<T>(x: &[T], y: &[T]) -> f32
+where
+    T: VectorType<Accumulator = f32>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dist = 0.0;
+    for i in 0..x.len() {
+        let x = x.get_unchecked(i).to_accumulator();
+        let y = y.get_unchecked(i).to_accumulator();
+        let diff = AutoMath::sub(x, y);
+        dist = AutoMath::add(dist, AutoMath::mul(diff, diff));
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use half::{bf16, f16};
+
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_half_sample_vectors,
+        simple_euclidean,
+    };
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_nofma_euclidean() {
+        let (x, y) = get_half_sample_vectors::<f16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_euclidean(&x, &y) };
+        let expected = simple_euclidean(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_bf16_xany_nofma_euclidean() {
+        let (x, y) = get_half_sample_vectors::<bf16>(514);
+        let dist = unsafe { half_xany_fallback_nofma_euclidean(&x, &y) };
+        let expected = simple_euclidean(&widen(&x), &widen(&y));
+        assert_is_close(dist, expected);
+    }
+}
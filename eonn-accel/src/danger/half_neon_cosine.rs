@@ -0,0 +1,102 @@
+use std::arch::aarch64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::{cosine, WidenNeonPs};
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the cosine distance of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_neon_fma_cosine(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_neon_cosine(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the cosine distance of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_neon_fma_cosine(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_neon_cosine(x, y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_neon_cosine<T: WidenNeonPs>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot1 = vdupq_n_f32(0.0);
+    let mut dot2 = vdupq_n_f32(0.0);
+    let mut norm_x1 = vdupq_n_f32(0.0);
+    let mut norm_x2 = vdupq_n_f32(0.0);
+    let mut norm_y1 = vdupq_n_f32(0.0);
+    let mut norm_y2 = vdupq_n_f32(0.0);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_neon_ps(x_ptr.add(i));
+        let x2 = T::load_neon_ps(x_ptr.add(i + 4));
+
+        let y1 = T::load_neon_ps(y_ptr.add(i));
+        let y2 = T::load_neon_ps(y_ptr.add(i + 4));
+
+        dot1 = vfmaq_f32(dot1, x1, y1);
+        dot2 = vfmaq_f32(dot2, x2, y2);
+        norm_x1 = vfmaq_f32(norm_x1, x1, x1);
+        norm_x2 = vfmaq_f32(norm_x2, x2, x2);
+        norm_y1 = vfmaq_f32(norm_y1, y1, y1);
+        norm_y2 = vfmaq_f32(norm_y2, y2, y2);
+
+        i += 8;
+    }
+
+    let mut dot_product = vaddvq_f32(vaddq_f32(dot1, dot2));
+    let mut norm_x = vaddvq_f32(vaddq_f32(norm_x1, norm_x2));
+    let mut norm_y = vaddvq_f32(vaddq_f32(norm_y1, norm_y2));
+
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(x, x));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(y, y));
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_cosine};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_neon_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_cosine() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_neon_fma_cosine(&x, &y) };
+        assert_is_close(dist, simple_cosine(&widen(&x), &widen(&y)));
+    }
+}
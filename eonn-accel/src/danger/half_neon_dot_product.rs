@@ -0,0 +1,98 @@
+use std::arch::aarch64::*;
+
+use half::{bf16, f16};
+
+use crate::danger::WidenNeonPs;
+use crate::math::*;
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_neon_fma_dot(x: &[f16], y: &[f16]) -> f32 {
+    half_xany_neon_dot(x, y)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+/// Computes the dot product of two `bf16` vectors, accumulating in `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn bf16_xany_neon_fma_dot(x: &[bf16], y: &[bf16]) -> f32 {
+    half_xany_neon_dot(x, y)
+}
+
+#[inline(always)]
+unsafe fn half_xany_neon_dot<T: WidenNeonPs>(x: &[T], y: &[T]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = vdupq_n_f32(0.0);
+    let mut acc2 = vdupq_n_f32(0.0);
+    let mut acc3 = vdupq_n_f32(0.0);
+    let mut acc4 = vdupq_n_f32(0.0);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = T::load_neon_ps(x_ptr.add(i));
+        let x2 = T::load_neon_ps(x_ptr.add(i + 4));
+        let x3 = T::load_neon_ps(x_ptr.add(i + 8));
+        let x4 = T::load_neon_ps(x_ptr.add(i + 12));
+
+        let y1 = T::load_neon_ps(y_ptr.add(i));
+        let y2 = T::load_neon_ps(y_ptr.add(i + 4));
+        let y3 = T::load_neon_ps(y_ptr.add(i + 8));
+        let y4 = T::load_neon_ps(y_ptr.add(i + 12));
+
+        acc1 = vfmaq_f32(acc1, x1, y1);
+        acc2 = vfmaq_f32(acc2, x2, y2);
+        acc3 = vfmaq_f32(acc3, x3, y3);
+        acc4 = vfmaq_f32(acc4, x4, y4);
+
+        i += 16;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = x.get_unchecked(n).to_accumulator();
+        let y = y.get_unchecked(n).to_accumulator();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    acc1 = vaddq_f32(acc1, acc2);
+    acc3 = vaddq_f32(acc3, acc4);
+    AutoMath::add(total, vaddvq_f32(vaddq_f32(acc1, acc3)))
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_half_sample_vectors, simple_dot};
+    use crate::types::widen;
+
+    #[test]
+    fn test_f16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<f16>(537);
+        let dist = unsafe { f16_xany_neon_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+
+    #[test]
+    fn test_bf16_xany_fma_dot() {
+        let (x, y) = get_half_sample_vectors::<bf16>(537);
+        let dist = unsafe { bf16_xany_neon_fma_dot(&x, &y) };
+        assert_is_close(dist, simple_dot(&widen(&x), &widen(&y)));
+    }
+}
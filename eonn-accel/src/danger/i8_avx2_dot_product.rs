@@ -0,0 +1,137 @@
+use std::arch::x86_64::*;
+
+use crate::danger::{load_i8_avx2_ps, sum_avx2_epi32, sum_avx2_ps};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `i8` scalar quantized vectors, accumulating
+/// in `i32`.
+///
+/// The products are computed with `vpmaddubsw`, which multiplies unsigned bytes
+/// by signed bytes, by moving the sign of each `x` value onto `y`. This requires
+/// the codes to be in the range `-127..=127` so the pairwise sums can not saturate.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx2_dot(x: &[i8], y: &[i8]) -> i32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let ones = _mm256_set1_epi16(1);
+    let mut acc1 = _mm256_setzero_si256();
+    let mut acc2 = _mm256_setzero_si256();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let x2 = _mm256_loadu_si256(x_ptr.add(i + 32).cast());
+
+        let y1 = _mm256_loadu_si256(y_ptr.add(i).cast());
+        let y2 = _mm256_loadu_si256(y_ptr.add(i + 32).cast());
+
+        let pairs1 = _mm256_maddubs_epi16(_mm256_abs_epi8(x1), _mm256_sign_epi8(y1, x1));
+        let pairs2 = _mm256_maddubs_epi16(_mm256_abs_epi8(x2), _mm256_sign_epi8(y2, x2));
+
+        acc1 = _mm256_add_epi32(acc1, _mm256_madd_epi16(pairs1, ones));
+        acc2 = _mm256_add_epi32(acc2, _mm256_madd_epi16(pairs2, ones));
+
+        i += 64;
+    }
+
+    let mut total = 0;
+    for n in i..len {
+        total += *x.get_unchecked(n) as i32 * *y.get_unchecked(n) as i32;
+    }
+
+    total + sum_avx2_epi32(_mm256_add_epi32(acc1, acc2))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of a `f32` vector and an `i8` scalar quantized
+/// vector, widening each code to `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_avx2_fma_dot(x: &[f32], y: &[i8]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+    let mut acc4 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        let y1 = load_i8_avx2_ps(y_ptr.add(i));
+        let y2 = load_i8_avx2_ps(y_ptr.add(i + 8));
+        let y3 = load_i8_avx2_ps(y_ptr.add(i + 16));
+        let y4 = load_i8_avx2_ps(y_ptr.add(i + 24));
+
+        acc1 = _mm256_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm256_fmadd_ps(x2, y2, acc2);
+        acc3 = _mm256_fmadd_ps(x3, y3, acc3);
+        acc4 = _mm256_fmadd_ps(x4, y4, acc4);
+
+        i += 32;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = *x.get_unchecked(n);
+        let y = *y.get_unchecked(n) as f32;
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    acc1 = _mm256_add_ps(acc1, acc2);
+    acc3 = _mm256_add_ps(acc3, acc4);
+    AutoMath::add(total, sum_avx2_ps(_mm256_add_ps(acc1, acc3)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_dot,
+        simple_i8_dot,
+    };
+
+    #[test]
+    fn test_i8_xany_dot() {
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx2_dot(&x, &y) };
+        assert_eq!(dist, simple_i8_dot(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_fma_dot() {
+        let (x, _) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist = unsafe { f32_i8_xany_avx2_fma_dot(&x, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_dot(&x, &y));
+    }
+}
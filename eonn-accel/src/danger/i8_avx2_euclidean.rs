@@ -0,0 +1,140 @@
+use std::arch::x86_64::*;
+
+use crate::danger::{load_i8_avx2_ps, sum_avx2_epi32, sum_avx2_ps};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the squared Euclidean distance of two `i8` scalar quantized vectors,
+/// accumulating in `u32`.
+///
+/// The codes are widened to `i16` before being subtracted, as the difference of
+/// two codes does not fit within a byte.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx2_squared_euclidean(x: &[i8], y: &[i8]) -> u32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_si256();
+    let mut acc2 = _mm256_setzero_si256();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_cvtepi8_epi16(_mm_loadu_si128(x_ptr.add(i).cast()));
+        let x2 = _mm256_cvtepi8_epi16(_mm_loadu_si128(x_ptr.add(i + 16).cast()));
+
+        let y1 = _mm256_cvtepi8_epi16(_mm_loadu_si128(y_ptr.add(i).cast()));
+        let y2 = _mm256_cvtepi8_epi16(_mm_loadu_si128(y_ptr.add(i + 16).cast()));
+
+        let diff1 = _mm256_sub_epi16(x1, y1);
+        let diff2 = _mm256_sub_epi16(x2, y2);
+
+        acc1 = _mm256_add_epi32(acc1, _mm256_madd_epi16(diff1, diff1));
+        acc2 = _mm256_add_epi32(acc2, _mm256_madd_epi16(diff2, diff2));
+
+        i += 32;
+    }
+
+    let mut total = 0;
+    for n in i..len {
+        let diff = *x.get_unchecked(n) as i32 - *y.get_unchecked(n) as i32;
+        total += (diff * diff) as u32;
+    }
+
+    total + sum_avx2_epi32(_mm256_add_epi32(acc1, acc2)) as u32
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the squared Euclidean distance between a `f32` vector and an `i8`
+/// scalar quantized vector, where each code is multiplied by the matching
+/// `scale` before being compared.
+///
+/// # Safety
+///
+/// Vectors and the scale **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_avx2_fma_squared_euclidean(
+    x: &[f32],
+    scale: &[f32],
+    y: &[i8],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), scale.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let scale_ptr = scale.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        let scale1 = _mm256_loadu_ps(scale_ptr.add(i));
+        let scale2 = _mm256_loadu_ps(scale_ptr.add(i + 8));
+
+        let y1 = load_i8_avx2_ps(y_ptr.add(i));
+        let y2 = load_i8_avx2_ps(y_ptr.add(i + 8));
+
+        let diff1 = _mm256_fnmadd_ps(scale1, y1, x1);
+        let diff2 = _mm256_fnmadd_ps(scale2, y2, x2);
+
+        acc1 = _mm256_fmadd_ps(diff1, diff1, acc1);
+        acc2 = _mm256_fmadd_ps(diff2, diff2, acc2);
+
+        i += 16;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = *x.get_unchecked(n);
+        let y = AutoMath::mul(*scale.get_unchecked(n), *y.get_unchecked(n) as f32);
+        let diff = AutoMath::sub(x, y);
+        total = AutoMath::add(total, AutoMath::mul(diff, diff));
+    }
+
+    AutoMath::add(total, sum_avx2_ps(_mm256_add_ps(acc1, acc2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_euclidean,
+        simple_i8_euclidean,
+    };
+
+    #[test]
+    fn test_i8_xany_squared_euclidean() {
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx2_squared_euclidean(&x, &y) };
+        assert_eq!(dist, simple_i8_euclidean(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_fma_squared_euclidean() {
+        let (x, scale) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist = unsafe { f32_i8_xany_avx2_fma_squared_euclidean(&x, &scale, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_euclidean(&x, &scale, &y));
+    }
+}
@@ -0,0 +1,191 @@
+use std::arch::x86_64::*;
+
+use crate::danger::load_i8_avx512_ps;
+use crate::math::*;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the dot product of two `i8` scalar quantized vectors, accumulating
+/// in `i32`.
+///
+/// The codes are widened to `i16` and multiplied pairwise, for CPUs without
+/// AVX512-VNNI, see [i8_xany_avx512vnni_dot] otherwise.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx512_dot(x: &[i8], y: &[i8]) -> i32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_si512();
+    let mut acc2 = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(x_ptr.add(i).cast()));
+        let x2 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(x_ptr.add(i + 32).cast()));
+
+        let y1 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(y_ptr.add(i).cast()));
+        let y2 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(y_ptr.add(i + 32).cast()));
+
+        acc1 = _mm512_add_epi32(acc1, _mm512_madd_epi16(x1, y1));
+        acc2 = _mm512_add_epi32(acc2, _mm512_madd_epi16(x2, y2));
+
+        i += 64;
+    }
+
+    let mut total = _mm512_reduce_add_epi32(_mm512_add_epi32(acc1, acc2));
+    for n in i..len {
+        total += *x.get_unchecked(n) as i32 * *y.get_unchecked(n) as i32;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512vnni")]
+#[inline]
+/// Computes the dot product of two `i8` scalar quantized vectors with the
+/// AVX512-VNNI `vpdpbusd` instruction, accumulating in `i32`.
+///
+/// `vpdpbusd` multiplies unsigned bytes by signed bytes, so `x` is offset by
+/// `128` into an unsigned value and `128 * sum(y)` is subtracted from the result.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx512vnni_dot(x: &[i8], y: &[i8]) -> i32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let offset = _mm512_set1_epi8(i8::MIN);
+    let mut acc = _mm512_setzero_si512();
+    let mut correction = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm512_loadu_si512(x_ptr.add(i).cast());
+        let y = _mm512_loadu_si512(y_ptr.add(i).cast());
+
+        acc = _mm512_dpbusd_epi32(acc, _mm512_xor_si512(x, offset), y);
+        correction = _mm512_dpbusd_epi32(correction, offset, y);
+
+        i += 64;
+    }
+
+    let mut total = _mm512_reduce_add_epi32(_mm512_sub_epi32(acc, correction));
+    for n in i..len {
+        total += *x.get_unchecked(n) as i32 * *y.get_unchecked(n) as i32;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of a `f32` vector and an `i8` scalar quantized
+/// vector, widening each code to `f32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_avx512_fma_dot(x: &[f32], y: &[i8]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+    let mut acc3 = _mm512_setzero_ps();
+    let mut acc4 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_ps(x_ptr.add(i));
+        let x2 = _mm512_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm512_loadu_ps(x_ptr.add(i + 32));
+        let x4 = _mm512_loadu_ps(x_ptr.add(i + 48));
+
+        let y1 = load_i8_avx512_ps(y_ptr.add(i));
+        let y2 = load_i8_avx512_ps(y_ptr.add(i + 16));
+        let y3 = load_i8_avx512_ps(y_ptr.add(i + 32));
+        let y4 = load_i8_avx512_ps(y_ptr.add(i + 48));
+
+        acc1 = _mm512_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm512_fmadd_ps(x2, y2, acc2);
+        acc3 = _mm512_fmadd_ps(x3, y3, acc3);
+        acc4 = _mm512_fmadd_ps(x4, y4, acc4);
+
+        i += 64;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = *x.get_unchecked(n);
+        let y = *y.get_unchecked(n) as f32;
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    acc1 = _mm512_add_ps(acc1, acc2);
+    acc3 = _mm512_add_ps(acc3, acc4);
+    AutoMath::add(total, _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc3)))
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_dot,
+        simple_i8_dot,
+    };
+
+    #[test]
+    fn test_i8_xany_dot() {
+        if !is_x86_feature_detected!("avx512bw") {
+            return;
+        }
+
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx512_dot(&x, &y) };
+        assert_eq!(dist, simple_i8_dot(&x, &y));
+    }
+
+    #[test]
+    fn test_i8_xany_avx512vnni_dot() {
+        if !is_x86_feature_detected!("avx512vnni") {
+            return;
+        }
+
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx512vnni_dot(&x, &y) };
+        assert_eq!(dist, simple_i8_dot(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_fma_dot() {
+        let (x, _) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist = unsafe { f32_i8_xany_avx512_fma_dot(&x, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_dot(&x, &y));
+    }
+}
@@ -0,0 +1,183 @@
+use std::arch::x86_64::*;
+
+use crate::danger::load_i8_avx512_ps;
+use crate::math::*;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the squared Euclidean distance of two `i8` scalar quantized vectors,
+/// accumulating in `u32`.
+///
+/// The codes are widened to `i16` before being subtracted, for CPUs without
+/// AVX512-VNNI, see [i8_xany_avx512vnni_squared_euclidean] otherwise.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx512_squared_euclidean(x: &[i8], y: &[i8]) -> u32 {
+    i8_xany_avx512_squared_euclidean_impl::<false>(x, y)
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vnni")]
+#[inline]
+/// Computes the squared Euclidean distance of two `i8` scalar quantized vectors
+/// with the AVX512-VNNI `vpdpwssd` instruction, accumulating in `u32`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_avx512vnni_squared_euclidean(x: &[i8], y: &[i8]) -> u32 {
+    i8_xany_avx512_squared_euclidean_impl::<true>(x, y)
+}
+
+#[inline(always)]
+unsafe fn i8_xany_avx512_squared_euclidean_impl<const VNNI: bool>(
+    x: &[i8],
+    y: &[i8],
+) -> u32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 64;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_si512();
+    let mut acc2 = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(x_ptr.add(i).cast()));
+        let x2 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(x_ptr.add(i + 32).cast()));
+
+        let y1 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(y_ptr.add(i).cast()));
+        let y2 = _mm512_cvtepi8_epi16(_mm256_loadu_si256(y_ptr.add(i + 32).cast()));
+
+        let diff1 = _mm512_sub_epi16(x1, y1);
+        let diff2 = _mm512_sub_epi16(x2, y2);
+
+        if VNNI {
+            acc1 = _mm512_dpwssd_epi32(acc1, diff1, diff1);
+            acc2 = _mm512_dpwssd_epi32(acc2, diff2, diff2);
+        } else {
+            acc1 = _mm512_add_epi32(acc1, _mm512_madd_epi16(diff1, diff1));
+            acc2 = _mm512_add_epi32(acc2, _mm512_madd_epi16(diff2, diff2));
+        }
+
+        i += 64;
+    }
+
+    let mut total = _mm512_reduce_add_epi32(_mm512_add_epi32(acc1, acc2)) as u32;
+    for n in i..len {
+        let diff = *x.get_unchecked(n) as i32 - *y.get_unchecked(n) as i32;
+        total += (diff * diff) as u32;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the squared Euclidean distance between a `f32` vector and an `i8`
+/// scalar quantized vector, where each code is multiplied by the matching
+/// `scale` before being compared.
+///
+/// # Safety
+///
+/// Vectors and the scale **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_avx512_fma_squared_euclidean(
+    x: &[f32],
+    scale: &[f32],
+    y: &[i8],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), scale.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let scale_ptr = scale.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_ps(x_ptr.add(i));
+        let x2 = _mm512_loadu_ps(x_ptr.add(i + 16));
+
+        let scale1 = _mm512_loadu_ps(scale_ptr.add(i));
+        let scale2 = _mm512_loadu_ps(scale_ptr.add(i + 16));
+
+        let y1 = load_i8_avx512_ps(y_ptr.add(i));
+        let y2 = load_i8_avx512_ps(y_ptr.add(i + 16));
+
+        let diff1 = _mm512_fnmadd_ps(scale1, y1, x1);
+        let diff2 = _mm512_fnmadd_ps(scale2, y2, x2);
+
+        acc1 = _mm512_fmadd_ps(diff1, diff1, acc1);
+        acc2 = _mm512_fmadd_ps(diff2, diff2, acc2);
+
+        i += 32;
+    }
+
+    let mut total = 0.0;
+    for n in i..len {
+        let x = *x.get_unchecked(n);
+        let y = AutoMath::mul(*scale.get_unchecked(n), *y.get_unchecked(n) as f32);
+        let diff = AutoMath::sub(x, y);
+        total = AutoMath::add(total, AutoMath::mul(diff, diff));
+    }
+
+    AutoMath::add(total, _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc2)))
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_euclidean,
+        simple_i8_euclidean,
+    };
+
+    #[test]
+    fn test_i8_xany_squared_euclidean() {
+        if !is_x86_feature_detected!("avx512bw") {
+            return;
+        }
+
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx512_squared_euclidean(&x, &y) };
+        assert_eq!(dist, simple_i8_euclidean(&x, &y));
+    }
+
+    #[test]
+    fn test_i8_xany_avx512vnni_squared_euclidean() {
+        if !is_x86_feature_detected!("avx512bw")
+            || !is_x86_feature_detected!("avx512vnni")
+        {
+            return;
+        }
+
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_avx512vnni_squared_euclidean(&x, &y) };
+        assert_eq!(dist, simple_i8_euclidean(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_fma_squared_euclidean() {
+        let (x, scale) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist = unsafe { f32_i8_xany_avx512_fma_squared_euclidean(&x, &scale, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_euclidean(&x, &scale, &y));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::math::*;
+
+#[inline]
+/// Computes the dot product of two `i8` scalar quantized vectors, accumulating
+/// in `i32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_fallback_dot(x: &[i8], y: &[i8]) -> i32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dot_product = 0;
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i) as i32;
+        let y = *y.get_unchecked(i) as i32;
+        dot_product += x * y;
+    }
+
+    dot_product
+}
+
+#[inline]
+/// Computes the dot product of a `f32` vector and an `i8` scalar quantized
+/// vector, widening each code to `f32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_fallback_nofma_dot(x: &[f32], y: &[i8]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dot_product = 0.0;
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i) as f32;
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(x, y));
+    }
+
+    dot_product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_dot,
+        simple_i8_dot,
+    };
+
+    #[test]
+    fn test_i8_xany_dot() {
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_fallback_dot(&x, &y) };
+        assert_eq!(dist, simple_i8_dot(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_nofma_dot() {
+        let (x, _) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist = unsafe { f32_i8_xany_fallback_nofma_dot(&x, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_dot(&x, &y));
+    }
+}
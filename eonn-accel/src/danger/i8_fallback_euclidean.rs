@@ -0,0 +1,85 @@
+use crate::math::*;
+
+#[inline]
+/// Computes the squared Euclidean distance of two `i8` scalar quantized vectors,
+/// accumulating in `u32`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i8_xany_fallback_squared_euclidean(x: &[i8], y: &[i8]) -> u32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dist = 0;
+    for i in 0..x.len() {
+        let diff = *x.get_unchecked(i) as i32 - *y.get_unchecked(i) as i32;
+        dist += (diff * diff) as u32;
+    }
+
+    dist
+}
+
+#[inline]
+/// Computes the squared Euclidean distance between a `f32` vector and an `i8`
+/// scalar quantized vector, where each code is multiplied by the matching
+/// `scale` before being compared.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors and the scale **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_i8_xany_fallback_nofma_squared_euclidean(
+    x: &[f32],
+    scale: &[f32],
+    y: &[i8],
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), scale.len());
+
+    let mut dist = 0.0;
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        let y = AutoMath::mul(*scale.get_unchecked(i), *y.get_unchecked(i) as f32);
+        let diff = AutoMath::sub(x, y);
+        dist = AutoMath::add(dist, AutoMath::mul(diff, diff));
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_f32_i8_euclidean,
+        simple_i8_euclidean,
+    };
+
+    #[test]
+    fn test_i8_xany_squared_euclidean() {
+        let (x, y) = get_i8_sample_codes(537);
+        let dist = unsafe { i8_xany_fallback_squared_euclidean(&x, &y) };
+        assert_eq!(dist, simple_i8_euclidean(&x, &y));
+    }
+
+    #[test]
+    fn test_f32_i8_xany_nofma_squared_euclidean() {
+        let (x, scale) = get_sample_vectors::<f32>(537);
+        let (_, y) = get_i8_sample_codes(537);
+        let dist =
+            unsafe { f32_i8_xany_fallback_nofma_squared_euclidean(&x, &scale, &y) };
+        assert_is_relative_close(dist, simple_f32_i8_euclidean(&x, &scale, &y));
+    }
+}
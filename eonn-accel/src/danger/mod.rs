@@ -1,6 +1,8 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_angular_hyperplane;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_chebyshev;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_cosine;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_dot_product;
@@ -9,6 +11,10 @@ mod f32_avx2_euclidean;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_euclidean_hyperplane;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_hellinger;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_manhattan;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_max;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_min;
@@ -20,9 +26,13 @@ mod f32_avx2_sum;
 mod f32_avx2_vector_x_value;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_vector_x_vector;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_weighted_euclidean;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_angular_hyperplane;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_chebyshev;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_cosine;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_dot_product;
@@ -31,6 +41,10 @@ mod f32_avx512_euclidean;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_euclidean_hyperplane;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_hellinger;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_manhattan;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_max;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_min;
@@ -42,8 +56,32 @@ mod f32_avx512_sum;
 mod f32_avx512_vector_x_value;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_vector_x_vector;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_weighted_euclidean;
 mod f32_fallback_angular_hyperplane;
 mod f32_fallback_euclidean_hyperplane;
+mod f32_fallback_hamming;
+mod f32_fallback_haversine;
+mod f32_fallback_jaccard;
+mod f32_fallback_jensen_shannon;
+mod f32_fallback_minkowski;
+mod f32_sparse_fallback_dot;
+mod f32_sparse_fallback_euclidean;
+mod f32_sparse_fallback_jaccard;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_chebyshev;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_cosine;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_dot_product;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_euclidean;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_manhattan;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod f32_sve_norm;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_angular_hyperplane;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_cosine;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -51,6 +89,8 @@ mod f64_avx2_dot_product;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_euclidean;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_euclidean_hyperplane;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_max;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_min;
@@ -63,12 +103,16 @@ mod f64_avx2_vector_x_value;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_vector_x_vector;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_angular_hyperplane;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_cosine;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_dot_product;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_euclidean;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_euclidean_hyperplane;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_max;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_min;
@@ -80,14 +124,103 @@ mod f64_avx512_sum;
 mod f64_avx512_vector_x_value;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_vector_x_vector;
+mod f64_fallback_angular_hyperplane;
+mod f64_fallback_euclidean_hyperplane;
+mod f64_fallback_hamming;
+mod f64_fallback_haversine;
+mod f64_fallback_jaccard;
+mod f64_fallback_jensen_shannon;
+mod f64_fallback_minkowski;
+mod generic_fallback_chebyshev;
 mod generic_fallback_cosine;
 mod generic_fallback_dot_product;
 mod generic_fallback_euclidean;
+mod generic_fallback_hellinger;
+mod generic_fallback_manhattan;
 mod generic_fallback_max;
 mod generic_fallback_min;
 mod generic_fallback_sum;
 mod generic_fallback_vector_x_value;
 mod generic_fallback_vector_x_vector;
+mod generic_fallback_weighted_euclidean;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_angular_hyperplane;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_chebyshev;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_cosine;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_dot_product;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_euclidean;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_euclidean_hyperplane;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_hellinger;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_manhattan;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_max;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_min;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_norm;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_sum;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_vector_x_value;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_vector_x_vector;
+#[cfg(target_arch = "aarch64")]
+mod generic_neon_weighted_euclidean;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod half_avx2_cosine;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod half_avx2_dot_product;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod half_avx2_euclidean;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod half_avx512_cosine;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod half_avx512_dot_product;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod half_avx512_euclidean;
+mod half_fallback_cosine;
+mod half_fallback_dot_product;
+mod half_fallback_euclidean;
+#[cfg(target_arch = "aarch64")]
+mod half_neon_cosine;
+#[cfg(target_arch = "aarch64")]
+mod half_neon_dot_product;
+#[cfg(target_arch = "aarch64")]
+mod half_neon_euclidean;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i8_avx2_dot_product;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i8_avx2_euclidean;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i8_avx512_dot_product;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i8_avx512_euclidean;
+mod i8_fallback_dot_product;
+mod i8_fallback_euclidean;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u64_avx512_hamming;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u64_avx512_intersection;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u64_avx512_jaccard;
+mod u64_popcnt_hamming;
+mod u64_popcnt_intersection;
+mod u64_popcnt_jaccard;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u8_avx2_fast_scan;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u8_avx2_lookup;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512_lookup;
+mod u8_fallback_fast_scan;
+mod u8_fallback_lookup;
 mod utils;
 
 pub(crate) use utils::*;
@@ -95,6 +228,8 @@ pub(crate) use utils::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_angular_hyperplane::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_chebyshev::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_cosine::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_dot_product::*;
@@ -103,6 +238,10 @@ pub use self::f32_avx2_euclidean::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_euclidean_hyperplane::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_hellinger::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_manhattan::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_max::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_min::*;
@@ -114,9 +253,13 @@ pub use self::f32_avx2_sum::*;
 pub use self::f32_avx2_vector_x_value::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_vector_x_vector::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_weighted_euclidean::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_angular_hyperplane::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_chebyshev::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_cosine::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_dot_product::*;
@@ -125,6 +268,10 @@ pub use self::f32_avx512_euclidean::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_euclidean_hyperplane::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_hellinger::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_manhattan::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_max::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_min::*;
@@ -136,8 +283,32 @@ pub use self::f32_avx512_sum::*;
 pub use self::f32_avx512_vector_x_value::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_vector_x_vector::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_weighted_euclidean::*;
 pub use self::f32_fallback_angular_hyperplane::*;
 pub use self::f32_fallback_euclidean_hyperplane::*;
+pub use self::f32_fallback_hamming::*;
+pub use self::f32_fallback_haversine::*;
+pub use self::f32_fallback_jaccard::*;
+pub use self::f32_fallback_jensen_shannon::*;
+pub use self::f32_fallback_minkowski::*;
+pub use self::f32_sparse_fallback_dot::*;
+pub use self::f32_sparse_fallback_euclidean::*;
+pub use self::f32_sparse_fallback_jaccard::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_chebyshev::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_cosine::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_dot_product::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_euclidean::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_manhattan::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::f32_sve_norm::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_angular_hyperplane::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_cosine::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -145,6 +316,8 @@ pub use self::f64_avx2_dot_product::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_euclidean::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_euclidean_hyperplane::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_max::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_min::*;
@@ -157,12 +330,16 @@ pub use self::f64_avx2_vector_x_value::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_vector_x_vector::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_angular_hyperplane::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_cosine::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_dot_product::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_euclidean::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_euclidean_hyperplane::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_max::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_min::*;
@@ -174,11 +351,100 @@ pub use self::f64_avx512_sum::*;
 pub use self::f64_avx512_vector_x_value::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_vector_x_vector::*;
+pub use self::f64_fallback_angular_hyperplane::*;
+pub use self::f64_fallback_euclidean_hyperplane::*;
+pub use self::f64_fallback_hamming::*;
+pub use self::f64_fallback_haversine::*;
+pub use self::f64_fallback_jaccard::*;
+pub use self::f64_fallback_jensen_shannon::*;
+pub use self::f64_fallback_minkowski::*;
+pub use self::generic_fallback_chebyshev::*;
 pub use self::generic_fallback_cosine::*;
 pub use self::generic_fallback_dot_product::*;
 pub use self::generic_fallback_euclidean::*;
+pub use self::generic_fallback_hellinger::*;
+pub use self::generic_fallback_manhattan::*;
 pub use self::generic_fallback_max::*;
 pub use self::generic_fallback_min::*;
 pub use self::generic_fallback_sum::*;
 pub use self::generic_fallback_vector_x_value::*;
 pub use self::generic_fallback_vector_x_vector::*;
+pub use self::generic_fallback_weighted_euclidean::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_angular_hyperplane::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_chebyshev::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_cosine::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_dot_product::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_euclidean::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_euclidean_hyperplane::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_hellinger::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_manhattan::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_max::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_min::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_norm::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_sum::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_vector_x_value::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_vector_x_vector::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::generic_neon_weighted_euclidean::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::half_avx2_cosine::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::half_avx2_dot_product::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::half_avx2_euclidean::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::half_avx512_cosine::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::half_avx512_dot_product::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::half_avx512_euclidean::*;
+pub use self::half_fallback_cosine::*;
+pub use self::half_fallback_dot_product::*;
+pub use self::half_fallback_euclidean::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::half_neon_cosine::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::half_neon_dot_product::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::half_neon_euclidean::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i8_avx2_dot_product::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i8_avx2_euclidean::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i8_avx512_dot_product::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i8_avx512_euclidean::*;
+pub use self::i8_fallback_dot_product::*;
+pub use self::i8_fallback_euclidean::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u64_avx512_hamming::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u64_avx512_intersection::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u64_avx512_jaccard::*;
+pub use self::u64_popcnt_hamming::*;
+pub use self::u64_popcnt_intersection::*;
+pub use self::u64_popcnt_jaccard::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u8_avx2_fast_scan::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u8_avx2_lookup::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512_lookup::*;
+pub use self::u8_fallback_fast_scan::*;
+pub use self::u8_fallback_lookup::*;
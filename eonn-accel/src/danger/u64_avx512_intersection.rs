@@ -0,0 +1,37 @@
+use std::arch::x86_64::*;
+
+#[target_feature(enable = "avx512f,avx512vpopcntdq")]
+#[inline]
+/// Computes the number of bits set in both of two bit-packed `u64` vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_avx512_popcnt_intersection(x: &[u64], y: &[u64]) -> u32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let mut acc = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm512_loadu_si512(x.as_ptr().add(i).cast());
+        let y = _mm512_loadu_si512(y.as_ptr().add(i).cast());
+        acc = _mm512_add_epi64(acc, _mm512_popcnt_epi64(_mm512_and_si512(x, y)));
+
+        i += 8;
+    }
+
+    let mut count = _mm512_reduce_add_epi64(acc) as u32;
+    for i in (len - offset_from)..len {
+        count += (*x.get_unchecked(i) & *y.get_unchecked(i)).count_ones();
+    }
+    count
+}
@@ -0,0 +1,52 @@
+use std::arch::x86_64::*;
+
+use crate::danger::jaccard_distance;
+
+#[target_feature(enable = "avx512f,avx512vpopcntdq")]
+#[inline]
+/// Computes the Jaccard distance of two bit-packed `u64` vectors, treating each
+/// vector as the set of its set bits.
+///
+/// Two empty sets have a distance of `0.0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_avx512_popcnt_jaccard(x: &[u64], y: &[u64]) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let mut intersection_acc = _mm512_setzero_si512();
+    let mut union_acc = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm512_loadu_si512(x.as_ptr().add(i).cast());
+        let y = _mm512_loadu_si512(y.as_ptr().add(i).cast());
+        intersection_acc = _mm512_add_epi64(
+            intersection_acc,
+            _mm512_popcnt_epi64(_mm512_and_si512(x, y)),
+        );
+        union_acc =
+            _mm512_add_epi64(union_acc, _mm512_popcnt_epi64(_mm512_or_si512(x, y)));
+
+        i += 8;
+    }
+
+    let mut intersection = _mm512_reduce_add_epi64(intersection_acc) as u32;
+    let mut union = _mm512_reduce_add_epi64(union_acc) as u32;
+    for i in (len - offset_from)..len {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+        intersection += (x & y).count_ones();
+        union += (x | y).count_ones();
+    }
+    jaccard_distance(intersection, union)
+}
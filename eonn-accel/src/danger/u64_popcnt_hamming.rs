@@ -0,0 +1,46 @@
+#[inline]
+/// Computes the Hamming distance of two bit-packed `u64` vectors, the number
+/// of bits which differ.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_fallback_hamming(x: &[u64], y: &[u64]) -> u32 {
+    fallback_hamming(x, y)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "popcnt")]
+#[inline]
+/// Computes the Hamming distance of two bit-packed `u64` vectors, the number
+/// of bits which differ.
+///
+/// This is the fallback routine compiled with the `popcnt` instruction enabled.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_popcnt_hamming(x: &[u64], y: &[u64]) -> u32 {
+    fallback_hamming(x, y)
+}
+
+#[inline(always)]
+unsafe fn fallback_hamming(x: &[u64], y: &[u64]) -> u32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut dist = 0;
+    for i in 0..x.len() {
+        dist += (*x.get_unchecked(i) ^ *y.get_unchecked(i)).count_ones();
+    }
+    dist
+}
@@ -0,0 +1,56 @@
+use crate::danger::jaccard_distance;
+
+#[inline]
+/// Computes the Jaccard distance of two bit-packed `u64` vectors, treating each
+/// vector as the set of its set bits.
+///
+/// Two empty sets have a distance of `0.0`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_fallback_jaccard(x: &[u64], y: &[u64]) -> f32 {
+    fallback_jaccard(x, y)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "popcnt")]
+#[inline]
+/// Computes the Jaccard distance of two bit-packed `u64` vectors, treating each
+/// vector as the set of its set bits.
+///
+/// Two empty sets have a distance of `0.0`.
+///
+/// This is the fallback routine compiled with the `popcnt` instruction enabled.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u64_xany_popcnt_jaccard(x: &[u64], y: &[u64]) -> f32 {
+    fallback_jaccard(x, y)
+}
+
+#[inline(always)]
+unsafe fn fallback_jaccard(x: &[u64], y: &[u64]) -> f32 {
+    debug_assert_eq!(
+        y.len(),
+        x.len(),
+        "Improper implementation detected, vectors must match in size"
+    );
+
+    let mut intersection = 0;
+    let mut union = 0;
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+        intersection += (x & y).count_ones();
+        union += (x | y).count_ones();
+    }
+    jaccard_distance(intersection, union)
+}
@@ -0,0 +1,90 @@
+use std::arch::x86_64::*;
+
+use crate::danger::FAST_SCAN_BLOCK;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Scans a block of `32` packed 4-bit product quantization codes against a
+/// quantized lookup table, writing the saturated `u16` sum of each code to `out`.
+///
+/// Each subspace's `16` entry table is held in a register and indexed by the
+/// codes of all `32` vectors at once with `pshufb`.
+///
+/// # Safety
+///
+/// The block and the table **MUST** be equal length and a multiple of `16`,
+/// otherwise this routine will become immediately UB due to out of bounds
+/// pointer accesses.
+pub unsafe fn u8_x32_avx2_fast_scan(
+    block: &[u8],
+    table: &[u8],
+    out: &mut [u16; FAST_SCAN_BLOCK],
+) {
+    debug_assert_eq!(block.len(), table.len());
+    debug_assert_eq!(block.len() % 16, 0);
+
+    let block_ptr = block.as_ptr();
+    let table_ptr = table.as_ptr();
+
+    let mask = _mm256_set1_epi8(0x0F);
+    let zero = _mm256_setzero_si256();
+    let mut acc_low = _mm256_setzero_si256();
+    let mut acc_high = _mm256_setzero_si256();
+
+    let mut i = 0;
+    while i < block.len() {
+        let packed =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(block_ptr.add(i).cast()));
+        let table =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(table_ptr.add(i).cast()));
+
+        // The low lane looks up vectors `0..16` and the high lane vectors `16..32`.
+        let low = _mm256_and_si256(packed, mask);
+        let high = _mm256_and_si256(_mm256_srli_epi16::<4>(packed), mask);
+        let dists = _mm256_shuffle_epi8(table, _mm256_blend_epi32::<0xF0>(low, high));
+
+        // Vectors `0..8` and `16..24`, then `8..16` and `24..32`.
+        acc_low = _mm256_adds_epu16(acc_low, _mm256_unpacklo_epi8(dists, zero));
+        acc_high = _mm256_adds_epu16(acc_high, _mm256_unpackhi_epi8(dists, zero));
+
+        i += 16;
+    }
+
+    let first = _mm256_permute2x128_si256::<0x20>(acc_low, acc_high);
+    let second = _mm256_permute2x128_si256::<0x31>(acc_low, acc_high);
+
+    let out_ptr = out.as_mut_ptr();
+    _mm256_storeu_si256(out_ptr.cast(), first);
+    _mm256_storeu_si256(out_ptr.add(16).cast(), second);
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::danger::u8_x32_fallback_fast_scan;
+
+    #[test]
+    fn test_u8_x32_fast_scan() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+
+        for n_subspaces in [1, 8, 67, 300] {
+            let block = (0..n_subspaces * 16)
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>();
+            let table = (0..n_subspaces * 16)
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>();
+
+            let mut expected = [0; FAST_SCAN_BLOCK];
+            let mut out = [0; FAST_SCAN_BLOCK];
+            unsafe {
+                u8_x32_fallback_fast_scan(&block, &table, &mut expected);
+                u8_x32_avx2_fast_scan(&block, &table, &mut out);
+            }
+            assert_eq!(out, expected);
+        }
+    }
+}
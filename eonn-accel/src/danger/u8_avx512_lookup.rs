@@ -0,0 +1,78 @@
+use std::arch::x86_64::*;
+
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums the entries of a product quantization lookup table selected by `codes`,
+/// gathering `16` entries at a time.
+///
+/// The table holds `n_centroids` values for each subspace, and the `j`th code
+/// selects the entry `table[j * n_centroids + codes[j]]`.
+///
+/// # Safety
+///
+/// The table **MUST** hold `n_centroids` values for every code and each code
+/// **MUST** be less than `n_centroids`, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_avx512_lookup(
+    table: &[f32],
+    n_centroids: usize,
+    codes: &[u8],
+) -> f32 {
+    debug_assert_eq!(table.len(), codes.len() * n_centroids);
+
+    let len = codes.len();
+    let offset_from = len % 16;
+
+    let table_ptr = table.as_ptr();
+    let codes_ptr = codes.as_ptr();
+
+    let stride = n_centroids as i32;
+    let step = _mm512_set1_epi32(stride * 16);
+    let mut offsets = _mm512_mullo_epi32(
+        _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+        _mm512_set1_epi32(stride),
+    );
+    let mut acc = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let codes = _mm512_cvtepu8_epi32(_mm_loadu_si128(codes_ptr.add(i).cast()));
+        let indices = _mm512_add_epi32(offsets, codes);
+        acc = _mm512_add_ps(acc, _mm512_i32gather_ps::<4>(indices, table_ptr));
+
+        offsets = _mm512_add_epi32(offsets, step);
+        i += 16;
+    }
+
+    let mut total = _mm512_reduce_add_ps(acc);
+    for n in i..len {
+        let code = *codes.get_unchecked(n) as usize;
+        total = AutoMath::add(total, *table.get_unchecked(n * n_centroids + code));
+    }
+
+    total
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::danger::u8_xany_fallback_lookup;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_u8_xany_lookup() {
+        for n_centroids in [16, 256] {
+            let (table, _) = get_sample_vectors::<f32>(67 * n_centroids);
+            let codes = (0..67)
+                .map(|i| (i * 7 % n_centroids) as u8)
+                .collect::<Vec<_>>();
+
+            let expected =
+                unsafe { u8_xany_fallback_lookup(&table, n_centroids, &codes) };
+            let total = unsafe { u8_xany_avx512_lookup(&table, n_centroids, &codes) };
+            assert_is_close(total, expected);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/// The number of codes scanned together by the fast-scan routines.
+pub const FAST_SCAN_BLOCK: usize = 32;
+
+#[inline]
+/// Scans a block of `32` packed 4-bit product quantization codes against a
+/// quantized lookup table, writing the saturated `u16` sum of each code to `out`.
+///
+/// For each subspace the block holds `16` bytes, where byte `i` packs the code of
+/// vector `i` in its low nibble and the code of vector `i + 16` in its high nibble,
+/// and the table holds `16` entries.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// The block and the table **MUST** be equal length and a multiple of `16`,
+/// otherwise this routine will become immediately UB due to out of bounds
+/// pointer accesses.
+pub unsafe fn u8_x32_fallback_fast_scan(
+    block: &[u8],
+    table: &[u8],
+    out: &mut [u16; FAST_SCAN_BLOCK],
+) {
+    debug_assert_eq!(block.len(), table.len());
+    debug_assert_eq!(block.len() % 16, 0);
+
+    *out = [0; FAST_SCAN_BLOCK];
+    for j in 0..block.len() / 16 {
+        for i in 0..16 {
+            let packed = *block.get_unchecked(j * 16 + i);
+            let low = *table.get_unchecked(j * 16 + (packed & 0x0F) as usize);
+            let high = *table.get_unchecked(j * 16 + (packed >> 4) as usize);
+
+            out[i] = out[i].saturating_add(low as u16);
+            out[i + 16] = out[i + 16].saturating_add(high as u16);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u8_x32_fast_scan() {
+        // A single subspace where vector `i` has the code `i` and vector `i + 16`
+        // has the code `15 - i`.
+        let block = (0..16u8).map(|i| i | ((15 - i) << 4)).collect::<Vec<_>>();
+        let table = (0..16u8).map(|c| c * 10).collect::<Vec<_>>();
+
+        let mut out = [0; FAST_SCAN_BLOCK];
+        unsafe { u8_x32_fallback_fast_scan(&block, &table, &mut out) };
+
+        for i in 0..16 {
+            assert_eq!(out[i], i as u16 * 10);
+            assert_eq!(out[i + 16], (15 - i) as u16 * 10);
+        }
+    }
+
+    #[test]
+    fn test_u8_x32_fast_scan_saturates() {
+        let block = vec![0xFF; 16 * 300];
+        let table = vec![255; 16 * 300];
+
+        let mut out = [0; FAST_SCAN_BLOCK];
+        unsafe { u8_x32_fallback_fast_scan(&block, &table, &mut out) };
+        assert!(out.iter().all(|&v| v == u16::MAX));
+    }
+}
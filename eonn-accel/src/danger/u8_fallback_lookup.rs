@@ -0,0 +1,53 @@
+use crate::math::*;
+
+#[inline]
+/// Sums the entries of a product quantization lookup table selected by `codes`.
+///
+/// The table holds `n_centroids` values for each subspace, and the `j`th code
+/// selects the entry `table[j * n_centroids + codes[j]]`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// The table **MUST** hold `n_centroids` values for every code and each code
+/// **MUST** be less than `n_centroids`, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_lookup(
+    table: &[f32],
+    n_centroids: usize,
+    codes: &[u8],
+) -> f32 {
+    debug_assert_eq!(table.len(), codes.len() * n_centroids);
+
+    let mut total = 0.0;
+    for i in 0..codes.len() {
+        let code = *codes.get_unchecked(i) as usize;
+        debug_assert!(code < n_centroids);
+        total = AutoMath::add(total, *table.get_unchecked(i * n_centroids + code));
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_u8_xany_lookup() {
+        let (table, _) = get_sample_vectors::<f32>(67 * 16);
+        let codes = (0..67).map(|i| (i * 7 % 16) as u8).collect::<Vec<_>>();
+
+        let expected = codes
+            .iter()
+            .enumerate()
+            .map(|(j, &c)| table[j * 16 + c as usize])
+            .sum();
+        let total = unsafe { u8_xany_fallback_lookup(&table, 16, &codes) };
+        assert_is_close(total, expected);
+    }
+}
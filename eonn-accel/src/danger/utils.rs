@@ -1,9 +1,15 @@
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use std::{mem, ptr};
 
 use crate::math::Math;
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub const CHUNK_0: usize = 0;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub const CHUNK_1: usize = 1;
 
 #[allow(non_snake_case)]
@@ -25,6 +31,21 @@ pub fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T) -> T {
     }
 }
 
+#[inline(always)]
+/// Computes the Hellinger distance from the sum of the square roots of the
+/// element-wise products and the L1 norms of each vector.
+pub fn hellinger<T: Copy, M: Math<T>>(sqrt_product_sum: T, l1_x: T, l1_y: T) -> T {
+    if M::cmp_eq(l1_x, M::zero()) && M::cmp_eq(l1_y, M::zero()) {
+        M::zero()
+    } else if M::cmp_eq(l1_x, M::zero()) || M::cmp_eq(l1_y, M::zero()) {
+        M::one()
+    } else {
+        let similarity = M::div(sqrt_product_sum, M::sqrt(M::mul(l1_x, l1_y)));
+        // Rounding can push the similarity slightly above `1.0` for identical vectors.
+        M::sqrt(M::cmp_max(M::zero(), M::sub(M::one(), similarity)))
+    }
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 /// Performs a sum of all packed values in the provided [__m256] register
@@ -45,6 +66,21 @@ pub(crate) unsafe fn sum_avx2_ps(v: __m256) -> f32 {
     _mm_cvtss_f32(sum)
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Performs a sum of all packed values in the provided [__m256i] register
+/// of `i32` lanes returning the resulting i32 value.
+pub(crate) unsafe fn sum_avx2_epi32(v: __m256i) -> i32 {
+    let left_half = _mm256_extracti128_si256::<1>(v);
+    let right_half = _mm256_castsi256_si128(v);
+    let sum_quad = _mm_add_epi32(left_half, right_half);
+
+    let sum_dual = _mm_add_epi32(sum_quad, _mm_unpackhi_epi64(sum_quad, sum_quad));
+    let sum = _mm_add_epi32(sum_dual, _mm_shuffle_epi32::<0x1>(sum_dual));
+
+    _mm_cvtsi128_si32(sum)
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 /// Performs a sum of all packed values in the provided [__m256d] register
@@ -162,6 +198,7 @@ pub(crate) unsafe fn rollup_x8_pd(
     _mm256_add_pd(acc1, acc5)
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 pub(crate) unsafe fn offsets_avx2_ps<const CHUNK: usize>(
     ptr: *const f32,
@@ -174,6 +211,7 @@ pub(crate) unsafe fn offsets_avx2_ps<const CHUNK: usize>(
     ]
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 pub(crate) unsafe fn offsets_avx2_pd<const CHUNK: usize>(
     ptr: *const f64,
@@ -236,6 +274,17 @@ pub fn rollup_scalar_x8<T: Copy, M: Math<T>>(
     M::add(acc1, acc5)
 }
 
+#[inline(always)]
+/// Computes the Jaccard distance from the number of set bits in the
+/// intersection and union of two sets.
+pub(crate) fn jaccard_distance(intersection: u32, union: u32) -> f32 {
+    if union == 0 {
+        0.0
+    } else {
+        1.0 - (intersection as f32 / union as f32)
+    }
+}
+
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub(crate) unsafe fn load_two_variable_size_avx512_ps(
     x: *const f32,
@@ -346,13 +395,337 @@ pub(crate) unsafe fn copy_avx2_pd_register_to(arr: *mut f64, reg: __m256d) {
     ptr::copy_nonoverlapping(result.as_ptr(), arr, result.len());
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes `a * b + acc`, as a single fused instruction when `FMA` is enabled.
+pub(crate) unsafe fn mul_add_avx2_ps<const FMA: bool>(
+    a: __m256,
+    b: __m256,
+    acc: __m256,
+) -> __m256 {
+    if FMA {
+        _mm256_fmadd_ps(a, b, acc)
+    } else {
+        _mm256_add_ps(_mm256_mul_ps(a, b), acc)
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// Half precision element types which are widened to `f32` as they are loaded.
+pub(crate) trait WidenPs: crate::VectorType<Accumulator = f32> {
+    /// Loads `8` values from `ptr` into a [__m256] register.
+    ///
+    /// # Safety
+    /// The `f16` implementation requires the `f16c` CPU feature.
+    unsafe fn load_avx2_ps(ptr: *const Self) -> __m256;
+
+    #[cfg(feature = "nightly")]
+    /// Loads `16` values from `ptr` into a [__m512] register.
+    unsafe fn load_avx512_ps(ptr: *const Self) -> __m512;
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl WidenPs for half::f16 {
+    #[inline(always)]
+    unsafe fn load_avx2_ps(ptr: *const Self) -> __m256 {
+        _mm256_cvtph_ps(_mm_loadu_si128(ptr.cast()))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    unsafe fn load_avx512_ps(ptr: *const Self) -> __m512 {
+        _mm512_cvtph_ps(_mm256_loadu_si256(ptr.cast()))
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl WidenPs for half::bf16 {
+    #[inline(always)]
+    unsafe fn load_avx2_ps(ptr: *const Self) -> __m256 {
+        // A `bf16` is the upper half of the equivalent `f32`.
+        let widened = _mm256_cvtepu16_epi32(_mm_loadu_si128(ptr.cast()));
+        _mm256_castsi256_ps(_mm256_slli_epi32::<16>(widened))
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    unsafe fn load_avx512_ps(ptr: *const Self) -> __m512 {
+        let widened = _mm512_cvtepu16_epi32(_mm256_loadu_si256(ptr.cast()));
+        _mm512_castsi512_ps(_mm512_slli_epi32::<16>(widened))
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Loads `32` packed `bf16` values from `ptr` for the AVX512-BF16 instructions.
+pub(crate) unsafe fn load_avx512_bh(ptr: *const half::bf16) -> __m512bh {
+    mem::transmute::<__m512i, __m512bh>(_mm512_loadu_si512(ptr.cast()))
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Loads `8` scalar quantized codes from `ptr`, widening them into a [__m256] register.
+pub(crate) unsafe fn load_i8_avx2_ps(ptr: *const i8) -> __m256 {
+    _mm256_cvtepi32_ps(_mm256_cvtepi8_epi32(_mm_loadl_epi64(ptr.cast())))
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Loads `16` scalar quantized codes from `ptr`, widening them into a [__m512] register.
+pub(crate) unsafe fn load_i8_avx512_ps(ptr: *const i8) -> __m512 {
+    _mm512_cvtepi32_ps(_mm512_cvtepi8_epi32(_mm_loadu_si128(ptr.cast())))
+}
+
+#[cfg(target_arch = "aarch64")]
+/// A float type which can be processed in NEON registers, allowing the NEON
+/// routines to be shared between `f32` and `f64` vectors.
+pub(crate) trait NeonFloat:
+    crate::VectorType<Accumulator = Self> + Copy + PartialOrd
+{
+    /// The NEON register holding [NeonFloat::LANES] values.
+    type Register: Copy;
+
+    /// The number of values held in a register.
+    const LANES: usize;
+
+    /// The machine epsilon of the type.
+    const EPSILON: Self;
+
+    unsafe fn zero() -> Self::Register;
+
+    unsafe fn splat(value: Self) -> Self::Register;
+
+    unsafe fn load(ptr: *const Self) -> Self::Register;
+
+    unsafe fn store(ptr: *mut Self, reg: Self::Register);
+
+    unsafe fn add(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    unsafe fn sub(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    unsafe fn mul(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    unsafe fn div(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    /// Computes `acc + (a * b)` with a single rounding.
+    unsafe fn fma(
+        acc: Self::Register,
+        a: Self::Register,
+        b: Self::Register,
+    ) -> Self::Register;
+
+    unsafe fn abs(a: Self::Register) -> Self::Register;
+
+    unsafe fn max(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    unsafe fn min(a: Self::Register, b: Self::Register) -> Self::Register;
+
+    unsafe fn sqrt(a: Self::Register) -> Self::Register;
+
+    unsafe fn sum(a: Self::Register) -> Self;
+
+    unsafe fn max_lane(a: Self::Register) -> Self;
+
+    unsafe fn min_lane(a: Self::Register) -> Self;
+}
+
+#[cfg(target_arch = "aarch64")]
+macro_rules! impl_neon_float {
+    (
+        $t:ident,
+        $reg:ident,
+        lanes = $lanes:expr,
+        dup = $dup:ident,
+        ld1 = $ld1:ident,
+        st1 = $st1:ident,
+        add = $add:ident,
+        sub = $sub:ident,
+        mul = $mul:ident,
+        div = $div:ident,
+        fma = $fma:ident,
+        abs = $abs:ident,
+        max = $max:ident,
+        min = $min:ident,
+        sqrt = $sqrt:ident,
+        addv = $addv:ident,
+        maxv = $maxv:ident,
+        minv = $minv:ident $(,)?
+    ) => {
+        impl NeonFloat for $t {
+            type Register = $reg;
+
+            const LANES: usize = $lanes;
+
+            const EPSILON: $t = $t::EPSILON;
+
+            #[inline(always)]
+            unsafe fn zero() -> $reg {
+                $dup(0.0)
+            }
+
+            #[inline(always)]
+            unsafe fn splat(value: $t) -> $reg {
+                $dup(value)
+            }
+
+            #[inline(always)]
+            unsafe fn load(ptr: *const $t) -> $reg {
+                $ld1(ptr)
+            }
+
+            #[inline(always)]
+            unsafe fn store(ptr: *mut $t, reg: $reg) {
+                $st1(ptr, reg)
+            }
+
+            #[inline(always)]
+            unsafe fn add(a: $reg, b: $reg) -> $reg {
+                $add(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn sub(a: $reg, b: $reg) -> $reg {
+                $sub(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn mul(a: $reg, b: $reg) -> $reg {
+                $mul(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn div(a: $reg, b: $reg) -> $reg {
+                $div(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn fma(acc: $reg, a: $reg, b: $reg) -> $reg {
+                $fma(acc, a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn abs(a: $reg) -> $reg {
+                $abs(a)
+            }
+
+            #[inline(always)]
+            unsafe fn max(a: $reg, b: $reg) -> $reg {
+                $max(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn min(a: $reg, b: $reg) -> $reg {
+                $min(a, b)
+            }
+
+            #[inline(always)]
+            unsafe fn sqrt(a: $reg) -> $reg {
+                $sqrt(a)
+            }
+
+            #[inline(always)]
+            unsafe fn sum(a: $reg) -> $t {
+                $addv(a)
+            }
+
+            #[inline(always)]
+            unsafe fn max_lane(a: $reg) -> $t {
+                $maxv(a)
+            }
+
+            #[inline(always)]
+            unsafe fn min_lane(a: $reg) -> $t {
+                $minv(a)
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "aarch64")]
+impl_neon_float!(
+    f32,
+    float32x4_t,
+    lanes = 4,
+    dup = vdupq_n_f32,
+    ld1 = vld1q_f32,
+    st1 = vst1q_f32,
+    add = vaddq_f32,
+    sub = vsubq_f32,
+    mul = vmulq_f32,
+    div = vdivq_f32,
+    fma = vfmaq_f32,
+    abs = vabsq_f32,
+    max = vmaxq_f32,
+    min = vminq_f32,
+    sqrt = vsqrtq_f32,
+    addv = vaddvq_f32,
+    maxv = vmaxvq_f32,
+    minv = vminvq_f32,
+);
+#[cfg(target_arch = "aarch64")]
+impl_neon_float!(
+    f64,
+    float64x2_t,
+    lanes = 2,
+    dup = vdupq_n_f64,
+    ld1 = vld1q_f64,
+    st1 = vst1q_f64,
+    add = vaddq_f64,
+    sub = vsubq_f64,
+    mul = vmulq_f64,
+    div = vdivq_f64,
+    fma = vfmaq_f64,
+    abs = vabsq_f64,
+    max = vmaxq_f64,
+    min = vminq_f64,
+    sqrt = vsqrtq_f64,
+    addv = vaddvq_f64,
+    maxv = vmaxvq_f64,
+    minv = vminvq_f64,
+);
+
+#[cfg(target_arch = "aarch64")]
+/// A half precision type which can be widened into `f32` NEON registers as it
+/// is loaded.
+pub(crate) trait WidenNeonPs: crate::VectorType<Accumulator = f32> {
+    /// Loads `4` values from `ptr` into a [float32x4_t] register.
+    unsafe fn load_neon_ps(ptr: *const Self) -> float32x4_t;
+}
+
+#[cfg(target_arch = "aarch64")]
+impl WidenNeonPs for half::f16 {
+    #[inline(always)]
+    unsafe fn load_neon_ps(ptr: *const Self) -> float32x4_t {
+        // The half precision NEON conversions are not yet stable, so the exponent
+        // and mantissa are moved into place and rebiased with a multiply instead,
+        // which is exact for every finite value including subnormals.
+        let bits = vmovl_u16(vld1_u16(ptr.cast()));
+        let sign = vshlq_n_u32::<16>(vandq_u32(bits, vdupq_n_u32(0x8000)));
+        let magnitude = vshlq_n_u32::<13>(vandq_u32(bits, vdupq_n_u32(0x7FFF)));
+        let rebias = vreinterpretq_f32_u32(vdupq_n_u32(0x7780_0000));
+        let widened = vmulq_f32(vreinterpretq_f32_u32(magnitude), rebias);
+        vreinterpretq_f32_u32(vorrq_u32(vreinterpretq_u32_f32(widened), sign))
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl WidenNeonPs for half::bf16 {
+    #[inline(always)]
+    unsafe fn load_neon_ps(ptr: *const Self) -> float32x4_t {
+        // A `bf16` is the upper half of the equivalent `f32`.
+        vreinterpretq_f32_u32(vshll_n_u16::<16>(vld1_u16(ptr.cast())))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     use std::array;
 
     use super::*;
     use crate::math::AutoMath;
 
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn test_avx2_offsets() {
         let x: [f32; 32] = array::from_fn(|i| i as f32);
@@ -363,6 +736,7 @@ mod tests {
         assert_eq!(x[24..].as_ptr(), p4);
     }
 
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn test_avx512_offsets() {
         let x: [f32; 64] = array::from_fn(|i| i as f32);
@@ -17,12 +17,16 @@ pub struct X768;
 /// Vector dimensions of 512
 pub struct X512;
 #[derive(Debug, Copy, Clone, Default)]
+/// Vector dimensions of 2, i.e. latitude and longitude pairs.
+pub struct X2;
+#[derive(Debug, Copy, Clone, Default)]
 /// Vector dimensions of any size.
 pub struct XAny;
 
 add_const_dim_attr!(X1024 = 1024);
 add_const_dim_attr!(X768 = 768);
 add_const_dim_attr!(X512 = 512);
+add_const_dim_attr!(X2 = 2);
 
 /// Dimension specification information.
 pub trait Dim: Default {
@@ -50,6 +54,12 @@ impl Dim for X512 {
     }
 }
 
+impl Dim for X2 {
+    fn const_size() -> Option<usize> {
+        Some(2)
+    }
+}
+
 impl Dim for XAny {
     fn const_size() -> Option<usize> {
         None
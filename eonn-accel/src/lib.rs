@@ -8,8 +8,13 @@
     all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"),
     feature(stdarch_x86_avx512)
 )]
+#![cfg_attr(
+    all(target_arch = "aarch64", feature = "nightly"),
+    feature(stdarch_aarch64_sve)
+)]
 
 mod arch;
+mod binary;
 mod dims;
 pub mod math;
 
@@ -18,15 +23,37 @@ pub mod danger;
 #[cfg(not(feature = "dangerous-access"))]
 pub(crate) mod danger;
 mod ops;
+mod pq;
+mod quantize;
+mod sparse;
 #[cfg(test)]
 mod test_ops;
 #[cfg(test)]
 mod test_utils;
 mod types;
 mod vector;
+mod view;
 
 pub use self::arch::*;
-pub use self::dims::{Dim, X1024, X512, X768};
+pub use self::binary::BinaryVector;
+pub use self::dims::{Dim, XAny, X1024, X2, X512, X768};
 pub use self::ops::{DangerousOps, MetricOps, SpacialOps};
-pub use self::types::VectorType;
+pub use self::pq::{
+    FastScanCodes,
+    PqCodeSize,
+    PqCodes,
+    PqLookupTable,
+    PqTrainError,
+    ProductQuantizer,
+};
+pub use self::quantize::{
+    QuantizedQuery,
+    QuantizedVector,
+    QuantizerRange,
+    QuantizerTrainError,
+    ScalarQuantizer,
+};
+pub use self::sparse::{SparseVector, SparseVectorCreateError};
+pub use self::types::{bf16, f16, VectorType};
 pub use self::vector::{Vector, VectorCreateError};
+pub use self::view::VectorView;
@@ -0,0 +1,85 @@
+use half::{bf16, f16};
+
+#[cfg(feature = "nightly")]
+use super::FastMath;
+use super::{Math, StdMath};
+
+// The half precision types have no native arithmetic, each operation is performed
+// in `f32` and rounded back down to the element type.
+macro_rules! impl_half_math {
+    ($math:ident, $t:ident) => {
+        impl Math<$t> for $math {
+            #[inline(always)]
+            fn zero() -> $t {
+                $t::ZERO
+            }
+
+            #[inline(always)]
+            fn one() -> $t {
+                $t::ONE
+            }
+
+            #[inline(always)]
+            fn max() -> $t {
+                $t::INFINITY
+            }
+
+            #[inline(always)]
+            fn min() -> $t {
+                $t::NEG_INFINITY
+            }
+
+            #[inline(always)]
+            fn sqrt(a: $t) -> $t {
+                $t::from_f32(a.to_f32().sqrt())
+            }
+
+            #[inline(always)]
+            fn abs(a: $t) -> $t {
+                $t::from_bits(a.to_bits() & 0x7FFF)
+            }
+
+            #[inline(always)]
+            fn cmp_eq(a: $t, b: $t) -> bool {
+                a == b
+            }
+
+            #[inline(always)]
+            fn cmp_min(a: $t, b: $t) -> $t {
+                a.min(b)
+            }
+
+            #[inline(always)]
+            fn cmp_max(a: $t, b: $t) -> $t {
+                a.max(b)
+            }
+
+            #[inline(always)]
+            fn add(a: $t, b: $t) -> $t {
+                a + b
+            }
+
+            #[inline(always)]
+            fn sub(a: $t, b: $t) -> $t {
+                a - b
+            }
+
+            #[inline(always)]
+            fn mul(a: $t, b: $t) -> $t {
+                a * b
+            }
+
+            #[inline(always)]
+            fn div(a: $t, b: $t) -> $t {
+                a / b
+            }
+        }
+    };
+}
+
+impl_half_math!(StdMath, f16);
+impl_half_math!(StdMath, bf16);
+#[cfg(feature = "nightly")]
+impl_half_math!(FastMath, f16);
+#[cfg(feature = "nightly")]
+impl_half_math!(FastMath, bf16);
@@ -1,6 +1,7 @@
 mod default;
 #[cfg(feature = "nightly")]
 mod fast_math;
+mod half_precision;
 
 pub use default::StdMath;
 #[cfg(feature = "nightly")]
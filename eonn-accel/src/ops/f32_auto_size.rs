@@ -17,6 +17,10 @@ macro_rules! impl_dangerous_auto_ops {
                         feature = "nightly"
                     ))]
                     SelectedArch::Avx512 => crate::danger::f32_xconst_avx512_fma_dot::<{$dim::DIMS}>(x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_fma_dot(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_fma_dot(x, y),
                     SelectedArch::Fallback => crate::danger::generic_xany_fallback_nofma_dot(x, y),
                 }
             }
@@ -33,6 +37,10 @@ macro_rules! impl_dangerous_auto_ops {
                         feature = "nightly"
                     ))]
                     SelectedArch::Avx512 => crate::danger::f32_xconst_avx512_fma_cosine::<{$dim::DIMS}>(x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_fma_cosine(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_fma_cosine(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_cosine(x, y)
                     },
@@ -53,12 +61,116 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_fma_euclidean::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_fma_euclidean(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_fma_euclidean(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_euclidean(x, y)
                     },
                 }
             }
 
+            #[inline]
+            unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                        crate::danger::f32_xconst_avx2_nofma_manhattan::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f32_xconst_avx512_nofma_manhattan::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_manhattan(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_nofma_manhattan(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_manhattan(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                        crate::danger::f32_xconst_avx2_nofma_chebyshev::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f32_xconst_avx512_nofma_chebyshev::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_chebyshev(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_nofma_chebyshev(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_chebyshev(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                        crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f32_xany_avx512_nofma_hellinger(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_hellinger(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_hellinger(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_hellinger(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => crate::danger::f32_xany_avx2_nofma_weighted_euclidean(x, y, w),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => crate::danger::f32_xany_avx2_fma_weighted_euclidean(x, y, w),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f32_xany_avx512_fma_weighted_euclidean(x, y, w)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => {
+                        crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+                    },
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => {
+                        crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+                    },
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_weighted_euclidean(x, y, w)
+                    },
+                }
+            }
+
             #[inline]
             unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
                 match self.1 .0 {
@@ -77,6 +189,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_fma_angular_hyperplane::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::f32_xany_fallback_nofma_angular_hyperplane(x, y)
                     },
@@ -101,6 +217,14 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_fma_euclidean_hyperplane::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => {
+                        crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+                    },
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => {
+                        crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+                    },
                     SelectedArch::Fallback => {
                         crate::danger::f32_xany_fallback_nofma_euclidean_hyperplane(x, y)
                     },
@@ -119,6 +243,10 @@ macro_rules! impl_dangerous_auto_ops {
                         feature = "nightly"
                     ))]
                     SelectedArch::Avx512 => crate::danger::f32_xconst_avx512_fma_norm::<{$dim::DIMS}>(x),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_fma_norm(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_sve_fma_norm(x),
                     SelectedArch::Fallback => crate::danger::generic_xany_fallback_nofma_dot(x, x),
                 }
             }
@@ -141,6 +269,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_add_value::<{$dim::DIMS}>(x, val)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_add_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_add_value(x, val),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_add_value(x, val)
                     },
@@ -165,6 +297,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_sub_value::<{$dim::DIMS}>(x, val)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sub_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sub_value(x, val),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_sub_value(x, val)
                     },
@@ -189,6 +325,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_mul_value::<{$dim::DIMS}>(x, val)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_mul_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_mul_value(x, val),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_mul_value(x, val)
                     },
@@ -213,6 +353,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_div_value::<{$dim::DIMS}>(x, val)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_div_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_div_value(x, val),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_div_value(x, val)
                     },
@@ -237,6 +381,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_add_vertical::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_add_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_add_vertical(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_add_vertical(x, y)
                     },
@@ -261,6 +409,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_sub_vertical::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sub_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sub_vertical(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_sub_vertical(x, y)
                     },
@@ -285,6 +437,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_mul_vertical::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_mul_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_mul_vertical(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_mul_vertical(x, y)
                     },
@@ -309,6 +465,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_div_vertical::<{$dim::DIMS}>(x, y)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_div_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_div_vertical(x, y),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_div_vertical(x, y)
                     },
@@ -333,6 +493,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_sum_horizontal::<{$dim::DIMS}>(x)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sum_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sum_horizontal(x),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_sum_horizontal(x)
                     },
@@ -357,6 +521,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_min_horizontal::<{$dim::DIMS}>(x)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_min_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_min_horizontal(x),
                     SelectedArch::Fallback => {
                         crate::danger::f32_xany_fallback_nofma_min_horizontal(x)
                     },
@@ -381,6 +549,10 @@ macro_rules! impl_dangerous_auto_ops {
                     SelectedArch::Avx512 => {
                         crate::danger::f32_xconst_avx512_nofma_max_horizontal::<{$dim::DIMS}>(x)
                     },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_max_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_max_horizontal(x),
                     SelectedArch::Fallback => {
                         crate::danger::generic_xany_fallback_nofma_max_horizontal(x)
                     },
@@ -408,6 +580,10 @@ impl DangerousOps for (XAny, Auto) {
                 feature = "nightly"
             ))]
             SelectedArch::Avx512 => crate::danger::f32_xany_avx512_fma_dot(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_fma_dot(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_fma_dot(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_dot(x, y)
             },
@@ -427,6 +603,10 @@ impl DangerousOps for (XAny, Auto) {
                 feature = "nightly"
             ))]
             SelectedArch::Avx512 => crate::danger::f32_xany_avx512_fma_cosine(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_fma_cosine(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_fma_cosine(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_cosine(x, y)
             },
@@ -446,12 +626,119 @@ impl DangerousOps for (XAny, Auto) {
                 feature = "nightly"
             ))]
             SelectedArch::Avx512 => crate::danger::f32_xany_avx512_fma_euclidean(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_fma_euclidean(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_fma_euclidean(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_euclidean(x, y)
             },
         }
     }
 
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                crate::danger::f32_xany_avx2_nofma_manhattan(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f32_xany_avx512_nofma_manhattan(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_manhattan(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_nofma_manhattan(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_manhattan(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                crate::danger::f32_xany_avx2_nofma_chebyshev(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f32_xany_avx512_nofma_chebyshev(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_chebyshev(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_nofma_chebyshev(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_chebyshev(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f32_xany_avx512_nofma_hellinger(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_hellinger(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_hellinger(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_hellinger(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => {
+                crate::danger::f32_xany_avx2_nofma_weighted_euclidean(x, y, w)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f32_xany_avx2_fma_weighted_euclidean(x, y, w)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f32_xany_avx512_fma_weighted_euclidean(x, y, w)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => {
+                crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+            },
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_weighted_euclidean(x, y, w)
+            },
+        }
+    }
+
     #[inline]
     unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
         assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
@@ -471,6 +758,14 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_fma_angular_hyperplane(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => {
+                crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y)
+            },
             SelectedArch::Fallback => {
                 crate::danger::f32_xany_fallback_nofma_angular_hyperplane(x, y)
             },
@@ -496,6 +791,14 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_fma_euclidean_hyperplane(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => {
+                crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+            },
             SelectedArch::Fallback => {
                 crate::danger::f32_xany_fallback_nofma_euclidean_hyperplane(x, y)
             },
@@ -514,6 +817,10 @@ impl DangerousOps for (XAny, Auto) {
                 feature = "nightly"
             ))]
             SelectedArch::Avx512 => crate::danger::f32_xany_avx512_fma_norm(x),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_fma_norm(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_sve_fma_norm(x),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_dot(x, x)
             },
@@ -536,6 +843,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_add_value(x, val)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_add_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_add_value(x, val),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_add_value(x, val)
             },
@@ -558,6 +869,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_sub_value(x, val)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sub_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sub_value(x, val),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_sub_value(x, val)
             },
@@ -580,6 +895,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_mul_value(x, val)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_mul_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_mul_value(x, val),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_mul_value(x, val)
             },
@@ -602,6 +921,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_div_value(x, val)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_div_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_div_value(x, val),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_div_value(x, val)
             },
@@ -625,6 +948,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_add_vertical(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_add_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_add_vertical(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_add_vertical(x, y)
             },
@@ -648,6 +975,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_sub_vertical(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sub_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sub_vertical(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_sub_vertical(x, y)
             },
@@ -671,6 +1002,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_mul_vertical(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_mul_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_mul_vertical(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_mul_vertical(x, y)
             },
@@ -694,6 +1029,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_div_vertical(x, y)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_div_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_div_vertical(x, y),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_div_vertical(x, y)
             },
@@ -716,6 +1055,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_sum_horizontal(x)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_sum_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_sum_horizontal(x),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_sum_horizontal(x)
             },
@@ -738,6 +1081,10 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_min_horizontal(x)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_min_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_min_horizontal(x),
             SelectedArch::Fallback => {
                 crate::danger::f32_xany_fallback_nofma_min_horizontal(x)
             },
@@ -760,9 +1107,122 @@ impl DangerousOps for (XAny, Auto) {
             SelectedArch::Avx512 => {
                 crate::danger::f32_xany_avx512_nofma_max_horizontal(x)
             },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f32_xany_neon_nofma_max_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f32_xany_neon_nofma_max_horizontal(x),
             SelectedArch::Fallback => {
                 crate::danger::generic_xany_fallback_nofma_max_horizontal(x)
             },
         }
     }
 }
+
+/// Two dimensional vectors are too small to benefit from SIMD, so the auto
+/// selected ops always use the fallback routines.
+impl DangerousOps for (X2, Auto) {
+    #[inline]
+    unsafe fn dot(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).dot(x, y)
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).cosine(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).squared_euclidean(x, y)
+    }
+
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        (X2, Fallback::default()).weighted_squared_euclidean(x, y, w)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        (X2, Fallback::default()).hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
+        (X2, Fallback::default()).angular_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn euclidean_hyperplane(&self, x: &[f32], y: &[f32]) -> (Vec<f32>, f32) {
+        (X2, Fallback::default()).euclidean_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_norm(&self, x: &[f32]) -> f32 {
+        (X2, Fallback::default()).squared_norm(x)
+    }
+
+    #[inline]
+    unsafe fn add_value(&self, x: &mut [f32], val: f32) {
+        (X2, Fallback::default()).add_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn sub_value(&self, x: &mut [f32], val: f32) {
+        (X2, Fallback::default()).sub_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn mul_value(&self, x: &mut [f32], val: f32) {
+        (X2, Fallback::default()).mul_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn div_value(&self, x: &mut [f32], y: f32) {
+        (X2, Fallback::default()).div_value(x, y)
+    }
+
+    #[inline]
+    unsafe fn add_vertical(&self, x: &mut [f32], y: &[f32]) {
+        (X2, Fallback::default()).add_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sub_vertical(&self, x: &mut [f32], y: &[f32]) {
+        (X2, Fallback::default()).sub_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn mul_vertical(&self, x: &mut [f32], y: &[f32]) {
+        (X2, Fallback::default()).mul_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn div_vertical(&self, x: &mut [f32], y: &[f32]) {
+        (X2, Fallback::default()).div_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sum(&self, x: &[f32]) -> f32 {
+        (X2, Fallback::default()).sum(x)
+    }
+
+    #[inline]
+    unsafe fn min(&self, x: &[f32]) -> f32 {
+        (X2, Fallback::default()).min(x)
+    }
+
+    #[inline]
+    unsafe fn max(&self, x: &[f32]) -> f32 {
+        (X2, Fallback::default()).max(x)
+    }
+}
@@ -21,6 +21,31 @@ impl<D: Dim> DangerousOps for (D, Fallback) {
         crate::danger::generic_xany_fallback_nofma_euclidean(x, y)
     }
 
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::generic_xany_fallback_nofma_weighted_euclidean(x, y, w)
+    }
+
     #[inline]
     unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
         assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
@@ -117,6 +142,26 @@ macro_rules! impl_dangerous_avx2_nofma_fixed_ops {
                 crate::danger::f32_xconst_avx2_nofma_euclidean::<{$dim::DIMS}>(x, y)
             }
 
+            #[inline]
+            unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx2_nofma_manhattan::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx2_nofma_chebyshev::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx2_nofma_weighted_euclidean(x, y, w)
+            }
+
             #[inline]
             unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
                 crate::danger::f32_xconst_avx2_nofma_angular_hyperplane::<{$dim::DIMS}>(x, y)
@@ -209,6 +254,26 @@ macro_rules! impl_dangerous_avx2_fma_fixed_ops {
                 crate::danger::f32_xconst_avx2_fma_euclidean::<{$dim::DIMS}>(x, y)
             }
 
+            #[inline]
+            unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx2_nofma_manhattan::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx2_nofma_chebyshev::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx2_fma_weighted_euclidean(x, y, w)
+            }
+
             #[inline]
             unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
                 crate::danger::f32_xconst_avx2_fma_angular_hyperplane::<{$dim::DIMS}>(x, y)
@@ -301,6 +366,26 @@ macro_rules! impl_dangerous_avx512_fixed_ops {
                 crate::danger::f32_xconst_avx512_fma_euclidean::<{$dim::DIMS}>(x, y)
             }
 
+            #[inline]
+            unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx512_nofma_manhattan::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xconst_avx512_nofma_chebyshev::<{$dim::DIMS}>(x, y)
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx512_nofma_hellinger(x, y)
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+                crate::danger::f32_xany_avx512_fma_weighted_euclidean(x, y, w)
+            }
+
             #[inline]
             unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
                 crate::danger::f32_xconst_avx512_fma_angular_hyperplane::<{$dim::DIMS}>(x, y)
@@ -415,6 +500,31 @@ impl DangerousOps for (XAny, Avx2) {
         crate::danger::f32_xany_avx2_nofma_euclidean(x, y)
     }
 
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::f32_xany_avx2_nofma_weighted_euclidean(x, y, w)
+    }
+
     #[inline]
     unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
         assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
@@ -512,6 +622,31 @@ impl DangerousOps for (XAny, Avx2Fma) {
         crate::danger::f32_xany_avx2_fma_euclidean(x, y)
     }
 
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx2_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::f32_xany_avx2_fma_weighted_euclidean(x, y, w)
+    }
+
     #[inline]
     unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
         assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
@@ -609,6 +744,31 @@ impl DangerousOps for (XAny, Avx512) {
         crate::danger::f32_xany_avx512_fma_euclidean(x, y)
     }
 
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx512_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx512_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_avx512_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::f32_xany_avx512_fma_weighted_euclidean(x, y, w)
+    }
+
     #[inline]
     unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
         assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
@@ -685,3 +845,247 @@ impl DangerousOps for (XAny, Avx512) {
         crate::danger::f32_xany_avx512_nofma_max_horizontal(x)
     }
 }
+
+#[cfg(target_arch = "aarch64")]
+impl<D: Dim> DangerousOps for (D, Neon) {
+    #[inline]
+    unsafe fn dot(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_dot(x, y)
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_cosine(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_euclidean(x, y)
+    }
+
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+    }
+
+    #[inline]
+    unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn euclidean_hyperplane(&self, x: &[f32], y: &[f32]) -> (Vec<f32>, f32) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_norm(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_fma_norm(x)
+    }
+
+    #[inline]
+    unsafe fn add_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_add_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn sub_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_sub_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn mul_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_mul_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn div_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_div_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn add_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_add_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sub_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_sub_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn mul_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_mul_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn div_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_div_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sum(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_sum_horizontal(x)
+    }
+
+    #[inline]
+    unsafe fn min(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_min_horizontal(x)
+    }
+
+    #[inline]
+    unsafe fn max(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_max_horizontal(x)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl<D: Dim> DangerousOps for (D, Sve) {
+    #[inline]
+    unsafe fn dot(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_sve_fma_dot(x, y)
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_sve_fma_cosine(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_sve_fma_euclidean(x, y)
+    }
+
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_sve_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_sve_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f32], y: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::f32_xany_neon_fma_weighted_euclidean(x, y, w)
+    }
+
+    #[inline]
+    unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32> {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_angular_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn euclidean_hyperplane(&self, x: &[f32], y: &[f32]) -> (Vec<f32>, f32) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_fma_euclidean_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_norm(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_sve_fma_norm(x)
+    }
+
+    #[inline]
+    unsafe fn add_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_add_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn sub_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_sub_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn mul_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_mul_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn div_value(&self, x: &mut [f32], val: f32) {
+        crate::danger::f32_xany_neon_nofma_div_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn add_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_add_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sub_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_sub_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn mul_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_mul_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn div_vertical(&self, x: &mut [f32], y: &[f32]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::f32_xany_neon_nofma_div_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sum(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_sum_horizontal(x)
+    }
+
+    #[inline]
+    unsafe fn min(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_min_horizontal(x)
+    }
+
+    #[inline]
+    unsafe fn max(&self, x: &[f32]) -> f32 {
+        crate::danger::f32_xany_neon_nofma_max_horizontal(x)
+    }
+}
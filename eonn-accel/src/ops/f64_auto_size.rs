@@ -0,0 +1,1070 @@
+use super::DangerousOps;
+use crate::arch::*;
+use crate::dims::*;
+
+macro_rules! impl_dangerous_auto_ops {
+    ($dim:ident) => {
+        impl DangerousOps<f64> for ($dim, Auto) {
+            #[inline]
+            unsafe fn dot(&self, x: &[f64], y: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => crate::danger::f64_xconst_avx2_nofma_dot::<{$dim::DIMS}>(x, y),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => crate::danger::f64_xconst_avx2_fma_dot::<{$dim::DIMS}>(x, y),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => crate::danger::f64_xconst_avx512_fma_dot::<{$dim::DIMS}>(x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_fma_dot(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_fma_dot(x, y),
+                    SelectedArch::Fallback => crate::danger::generic_xany_fallback_nofma_dot(x, y),
+                }
+            }
+
+            #[inline]
+            unsafe fn cosine(&self, x: &[f64], y: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => crate::danger::f64_xconst_avx2_nofma_cosine::<{$dim::DIMS}>(x, y),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => crate::danger::f64_xconst_avx2_fma_cosine::<{$dim::DIMS}>(x, y),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => crate::danger::f64_xconst_avx512_fma_cosine::<{$dim::DIMS}>(x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_fma_cosine(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_fma_cosine(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_cosine(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn squared_euclidean(&self, x: &[f64], y: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => crate::danger::f64_xconst_avx2_nofma_euclidean::<{$dim::DIMS}>(x, y),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => crate::danger::f64_xconst_avx2_fma_euclidean::<{$dim::DIMS}>(x, y),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_fma_euclidean::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_fma_euclidean(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_fma_euclidean(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_euclidean(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn manhattan(&self, x: &[f64], y: &[f64]) -> f64 {
+                crate::danger::generic_xany_fallback_nofma_manhattan(x, y)
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[f64], y: &[f64]) -> f64 {
+                crate::danger::generic_xany_fallback_nofma_chebyshev(x, y)
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[f64], y: &[f64]) -> f64 {
+                crate::danger::generic_xany_fallback_nofma_hellinger(x, y)
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(&self, x: &[f64], y: &[f64], w: &[f64]) -> f64 {
+                crate::danger::generic_xany_fallback_nofma_weighted_euclidean(x, y, w)
+            }
+
+            #[inline]
+            unsafe fn angular_hyperplane(&self, x: &[f64], y: &[f64]) -> Vec<f64> {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_angular_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_fma_angular_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_fma_angular_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_fma_angular_hyperplane(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_fma_angular_hyperplane(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::f64_xany_fallback_nofma_angular_hyperplane(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn euclidean_hyperplane(&self, x: &[f64], y: &[f64]) -> (Vec<f64>, f64) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_euclidean_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_fma_euclidean_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_fma_euclidean_hyperplane::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => {
+                        crate::danger::f64_xany_neon_fma_euclidean_hyperplane(x, y)
+                    },
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => {
+                        crate::danger::f64_xany_neon_fma_euclidean_hyperplane(x, y)
+                    },
+                    SelectedArch::Fallback => {
+                        crate::danger::f64_xany_fallback_nofma_euclidean_hyperplane(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn squared_norm(&self, x: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => crate::danger::f64_xconst_avx2_nofma_norm::<{$dim::DIMS}>(x),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => crate::danger::f64_xconst_avx2_fma_norm::<{$dim::DIMS}>(x),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => crate::danger::f64_xconst_avx512_fma_norm::<{$dim::DIMS}>(x),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_fma_norm(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_fma_norm(x),
+                    SelectedArch::Fallback => crate::danger::generic_xany_fallback_nofma_dot(x, x),
+                }
+            }
+
+            #[inline]
+            unsafe fn add_value(&self, x: &mut [f64], val: f64) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_add_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_add_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_add_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_add_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_add_value(x, val),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_add_value(x, val)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn sub_value(&self, x: &mut [f64], val: f64) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_sub_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_sub_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_sub_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sub_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sub_value(x, val),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_sub_value(x, val)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn mul_value(&self, x: &mut [f64], val: f64) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_mul_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_mul_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_mul_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_mul_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_mul_value(x, val),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_mul_value(x, val)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn div_value(&self, x: &mut [f64], val: f64) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_div_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_div_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_div_value::<{$dim::DIMS}>(x, val)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_div_value(x, val),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_div_value(x, val),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_div_value(x, val)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn add_vertical(&self, x: &mut [f64], y: &[f64]) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_add_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_add_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_add_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_add_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_add_vertical(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_add_vertical(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn sub_vertical(&self, x: &mut [f64], y: &[f64]) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_sub_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_sub_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_sub_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sub_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sub_vertical(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_sub_vertical(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn mul_vertical(&self, x: &mut [f64], y: &[f64]) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_mul_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_mul_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_mul_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_mul_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_mul_vertical(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_mul_vertical(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn div_vertical(&self, x: &mut [f64], y: &[f64]) {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_div_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_div_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_div_vertical::<{$dim::DIMS}>(x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_div_vertical(x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_div_vertical(x, y),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_div_vertical(x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn sum(&self, x: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_sum_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_sum_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_sum_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sum_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sum_horizontal(x),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_sum_horizontal(x)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn min(&self, x: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_min_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_min_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_min_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_min_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_min_horizontal(x),
+                    SelectedArch::Fallback => {
+                        crate::danger::f32_xany_fallback_nofma_min_horizontal(x)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn max(&self, x: &[f64]) -> f64 {
+                match self.1 .0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        crate::danger::f64_xconst_avx2_nofma_max_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        crate::danger::f64_xconst_avx2_nofma_max_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        crate::danger::f64_xconst_avx512_nofma_max_horizontal::<{$dim::DIMS}>(x)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_max_horizontal(x),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_max_horizontal(x),
+                    SelectedArch::Fallback => {
+                        crate::danger::generic_xany_fallback_nofma_max_horizontal(x)
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_dangerous_auto_ops!(X1024);
+impl_dangerous_auto_ops!(X768);
+impl_dangerous_auto_ops!(X512);
+
+impl DangerousOps<f64> for (XAny, Auto) {
+    #[inline]
+    unsafe fn dot(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_dot(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => crate::danger::f64_xany_avx2_fma_dot(x, y),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f64_xany_avx512_fma_dot(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_fma_dot(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_fma_dot(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_dot(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_cosine(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => crate::danger::f64_xany_avx2_fma_cosine(x, y),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f64_xany_avx512_fma_cosine(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_fma_cosine(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_fma_cosine(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_cosine(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_euclidean(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => crate::danger::f64_xany_avx2_fma_euclidean(x, y),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f64_xany_avx512_fma_euclidean(x, y),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_fma_euclidean(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_fma_euclidean(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_euclidean(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f64], y: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        crate::danger::generic_xany_fallback_nofma_hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f64], y: &[f64], w: &[f64]) -> f64 {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        assert_eq!(x.len(), w.len(), "Lengths of `x` and `w` must be equal");
+        crate::danger::generic_xany_fallback_nofma_weighted_euclidean(x, y, w)
+    }
+
+    #[inline]
+    unsafe fn angular_hyperplane(&self, x: &[f64], y: &[f64]) -> Vec<f64> {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => {
+                crate::danger::f64_xany_avx2_nofma_angular_hyperplane(x, y)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_fma_angular_hyperplane(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_fma_angular_hyperplane(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                crate::danger::f64_xany_neon_fma_angular_hyperplane(x, y)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => {
+                crate::danger::f64_xany_neon_fma_angular_hyperplane(x, y)
+            },
+            SelectedArch::Fallback => {
+                crate::danger::f64_xany_fallback_nofma_angular_hyperplane(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn euclidean_hyperplane(&self, x: &[f64], y: &[f64]) -> (Vec<f64>, f64) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => {
+                crate::danger::f64_xany_avx2_nofma_euclidean_hyperplane(x, y)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_fma_euclidean_hyperplane(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_fma_euclidean_hyperplane(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                crate::danger::f64_xany_neon_fma_euclidean_hyperplane(x, y)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => {
+                crate::danger::f64_xany_neon_fma_euclidean_hyperplane(x, y)
+            },
+            SelectedArch::Fallback => {
+                crate::danger::f64_xany_fallback_nofma_euclidean_hyperplane(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn squared_norm(&self, x: &[f64]) -> f64 {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_norm(x),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => crate::danger::f64_xany_avx2_fma_norm(x),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => crate::danger::f64_xany_avx512_fma_norm(x),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_fma_norm(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_fma_norm(x),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_dot(x, x)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn add_value(&self, x: &mut [f64], val: f64) {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_add_value(x, val),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_add_value(x, val)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_add_value(x, val)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_add_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_add_value(x, val),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_add_value(x, val)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn sub_value(&self, x: &mut [f64], val: f64) {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_sub_value(x, val),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_sub_value(x, val)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_sub_value(x, val)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sub_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sub_value(x, val),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_sub_value(x, val)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn mul_value(&self, x: &mut [f64], val: f64) {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_mul_value(x, val),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_mul_value(x, val)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_mul_value(x, val)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_mul_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_mul_value(x, val),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_mul_value(x, val)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn div_value(&self, x: &mut [f64], val: f64) {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_div_value(x, val),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_div_value(x, val)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_div_value(x, val)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_div_value(x, val),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_div_value(x, val),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_div_value(x, val)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn add_vertical(&self, x: &mut [f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_add_vertical(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_add_vertical(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_add_vertical(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_add_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_add_vertical(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_add_vertical(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn sub_vertical(&self, x: &mut [f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_sub_vertical(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_sub_vertical(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_sub_vertical(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sub_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sub_vertical(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_sub_vertical(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn mul_vertical(&self, x: &mut [f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_mul_vertical(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_mul_vertical(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_mul_vertical(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_mul_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_mul_vertical(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_mul_vertical(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn div_vertical(&self, x: &mut [f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "Lengths of `x` and `y` must be equal");
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_div_vertical(x, y),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_div_vertical(x, y)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_div_vertical(x, y)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_div_vertical(x, y),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_div_vertical(x, y),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_div_vertical(x, y)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn sum(&self, x: &[f64]) -> f64 {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_sum_horizontal(x),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_sum_horizontal(x)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_sum_horizontal(x)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_sum_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_sum_horizontal(x),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_sum_horizontal(x)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn min(&self, x: &[f64]) -> f64 {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_min_horizontal(x),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_min_horizontal(x)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_min_horizontal(x)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_min_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_min_horizontal(x),
+            SelectedArch::Fallback => {
+                crate::danger::f32_xany_fallback_nofma_min_horizontal(x)
+            },
+        }
+    }
+
+    #[inline]
+    unsafe fn max(&self, x: &[f64]) -> f64 {
+        match self.1 .0 {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 => crate::danger::f64_xany_avx2_nofma_max_horizontal(x),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2Fma => {
+                crate::danger::f64_xany_avx2_nofma_max_horizontal(x)
+            },
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                crate::danger::f64_xany_avx512_nofma_max_horizontal(x)
+            },
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => crate::danger::f64_xany_neon_nofma_max_horizontal(x),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => crate::danger::f64_xany_neon_nofma_max_horizontal(x),
+            SelectedArch::Fallback => {
+                crate::danger::generic_xany_fallback_nofma_max_horizontal(x)
+            },
+        }
+    }
+}
+
+/// Two dimensional vectors are too small to benefit from SIMD, so the auto
+/// selected ops always use the fallback routines.
+impl DangerousOps<f64> for (X2, Auto) {
+    #[inline]
+    unsafe fn dot(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).dot(x, y)
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).cosine(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).squared_euclidean(x, y)
+    }
+
+    #[inline]
+    unsafe fn manhattan(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).manhattan(x, y)
+    }
+
+    #[inline]
+    unsafe fn chebyshev(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).chebyshev(x, y)
+    }
+
+    #[inline]
+    unsafe fn weighted_squared_euclidean(&self, x: &[f64], y: &[f64], w: &[f64]) -> f64 {
+        (X2, Fallback::default()).weighted_squared_euclidean(x, y, w)
+    }
+
+    #[inline]
+    unsafe fn hellinger(&self, x: &[f64], y: &[f64]) -> f64 {
+        (X2, Fallback::default()).hellinger(x, y)
+    }
+
+    #[inline]
+    unsafe fn angular_hyperplane(&self, x: &[f64], y: &[f64]) -> Vec<f64> {
+        (X2, Fallback::default()).angular_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn euclidean_hyperplane(&self, x: &[f64], y: &[f64]) -> (Vec<f64>, f64) {
+        (X2, Fallback::default()).euclidean_hyperplane(x, y)
+    }
+
+    #[inline]
+    unsafe fn squared_norm(&self, x: &[f64]) -> f64 {
+        (X2, Fallback::default()).squared_norm(x)
+    }
+
+    #[inline]
+    unsafe fn add_value(&self, x: &mut [f64], val: f64) {
+        (X2, Fallback::default()).add_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn sub_value(&self, x: &mut [f64], val: f64) {
+        (X2, Fallback::default()).sub_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn mul_value(&self, x: &mut [f64], val: f64) {
+        (X2, Fallback::default()).mul_value(x, val)
+    }
+
+    #[inline]
+    unsafe fn div_value(&self, x: &mut [f64], y: f64) {
+        (X2, Fallback::default()).div_value(x, y)
+    }
+
+    #[inline]
+    unsafe fn add_vertical(&self, x: &mut [f64], y: &[f64]) {
+        (X2, Fallback::default()).add_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sub_vertical(&self, x: &mut [f64], y: &[f64]) {
+        (X2, Fallback::default()).sub_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn mul_vertical(&self, x: &mut [f64], y: &[f64]) {
+        (X2, Fallback::default()).mul_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn div_vertical(&self, x: &mut [f64], y: &[f64]) {
+        (X2, Fallback::default()).div_vertical(x, y)
+    }
+
+    #[inline]
+    unsafe fn sum(&self, x: &[f64]) -> f64 {
+        (X2, Fallback::default()).sum(x)
+    }
+
+    #[inline]
+    unsafe fn min(&self, x: &[f64]) -> f64 {
+        (X2, Fallback::default()).min(x)
+    }
+
+    #[inline]
+    unsafe fn max(&self, x: &[f64]) -> f64 {
+        (X2, Fallback::default()).max(x)
+    }
+}
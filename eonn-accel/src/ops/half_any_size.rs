@@ -0,0 +1,402 @@
+use half::{bf16, f16};
+
+use super::DangerousOps;
+use crate::arch::*;
+use crate::dims::Dim;
+use crate::types::widen;
+
+/// The routines which are accelerated for the half precision types, every other
+/// op widens the vectors to `f32` first.
+trait HalfKernels<T> {
+    unsafe fn dot(&self, x: &[T], y: &[T]) -> f32;
+
+    unsafe fn cosine(&self, x: &[T], y: &[T]) -> f32;
+
+    unsafe fn squared_euclidean(&self, x: &[T], y: &[T]) -> f32;
+}
+
+macro_rules! impl_half_kernels {
+    ($t:ident, $arch:ident, dot = $dot:ident, cosine = $cosine:ident, euclidean = $euclidean:ident) => {
+        impl HalfKernels<$t> for $arch {
+            #[inline]
+            unsafe fn dot(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::$dot(x, y)
+            }
+
+            #[inline]
+            unsafe fn cosine(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::$cosine(x, y)
+            }
+
+            #[inline]
+            unsafe fn squared_euclidean(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::$euclidean(x, y)
+            }
+        }
+    };
+}
+
+impl_half_kernels!(
+    f16,
+    Fallback,
+    dot = half_xany_fallback_nofma_dot,
+    cosine = half_xany_fallback_nofma_cosine,
+    euclidean = half_xany_fallback_nofma_euclidean
+);
+impl_half_kernels!(
+    bf16,
+    Fallback,
+    dot = half_xany_fallback_nofma_dot,
+    cosine = half_xany_fallback_nofma_cosine,
+    euclidean = half_xany_fallback_nofma_euclidean
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_kernels!(
+    f16,
+    Avx2,
+    dot = f16_xany_avx2_nofma_dot,
+    cosine = f16_xany_avx2_nofma_cosine,
+    euclidean = f16_xany_avx2_nofma_euclidean
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_kernels!(
+    bf16,
+    Avx2,
+    dot = bf16_xany_avx2_nofma_dot,
+    cosine = bf16_xany_avx2_nofma_cosine,
+    euclidean = bf16_xany_avx2_nofma_euclidean
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_kernels!(
+    f16,
+    Avx2Fma,
+    dot = f16_xany_avx2_fma_dot,
+    cosine = f16_xany_avx2_fma_cosine,
+    euclidean = f16_xany_avx2_fma_euclidean
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_kernels!(
+    bf16,
+    Avx2Fma,
+    dot = bf16_xany_avx2_fma_dot,
+    cosine = bf16_xany_avx2_fma_cosine,
+    euclidean = bf16_xany_avx2_fma_euclidean
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+impl_half_kernels!(
+    f16,
+    Avx512,
+    dot = f16_xany_avx512_fma_dot,
+    cosine = f16_xany_avx512_fma_cosine,
+    euclidean = f16_xany_avx512_fma_euclidean
+);
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+impl HalfKernels<bf16> for Avx512 {
+    #[inline]
+    unsafe fn dot(&self, x: &[bf16], y: &[bf16]) -> f32 {
+        if has_avx512bf16() {
+            crate::danger::bf16_xany_avx512bf16_dot(x, y)
+        } else {
+            crate::danger::bf16_xany_avx512_fma_dot(x, y)
+        }
+    }
+
+    #[inline]
+    unsafe fn cosine(&self, x: &[bf16], y: &[bf16]) -> f32 {
+        if has_avx512bf16() {
+            crate::danger::bf16_xany_avx512bf16_cosine(x, y)
+        } else {
+            crate::danger::bf16_xany_avx512_fma_cosine(x, y)
+        }
+    }
+
+    #[inline]
+    unsafe fn squared_euclidean(&self, x: &[bf16], y: &[bf16]) -> f32 {
+        crate::danger::bf16_xany_avx512_fma_euclidean(x, y)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl_half_kernels!(
+    f16,
+    Neon,
+    dot = f16_xany_neon_fma_dot,
+    cosine = f16_xany_neon_fma_cosine,
+    euclidean = f16_xany_neon_fma_euclidean
+);
+#[cfg(target_arch = "aarch64")]
+impl_half_kernels!(
+    bf16,
+    Neon,
+    dot = bf16_xany_neon_fma_dot,
+    cosine = bf16_xany_neon_fma_cosine,
+    euclidean = bf16_xany_neon_fma_euclidean
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl_half_kernels!(
+    f16,
+    Sve,
+    dot = f16_xany_neon_fma_dot,
+    cosine = f16_xany_neon_fma_cosine,
+    euclidean = f16_xany_neon_fma_euclidean
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl_half_kernels!(
+    bf16,
+    Sve,
+    dot = bf16_xany_neon_fma_dot,
+    cosine = bf16_xany_neon_fma_cosine,
+    euclidean = bf16_xany_neon_fma_euclidean
+);
+
+macro_rules! impl_half_auto_kernels {
+    ($t:ident) => {
+        impl HalfKernels<$t> for Auto {
+            #[inline]
+            unsafe fn dot(&self, x: &[$t], y: &[$t]) -> f32 {
+                match self.0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => HalfKernels::<$t>::dot(&Avx2(()), x, y),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => HalfKernels::<$t>::dot(&Avx2Fma(()), x, y),
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => HalfKernels::<$t>::dot(&Avx512(()), x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => HalfKernels::<$t>::dot(&Neon(()), x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => HalfKernels::<$t>::dot(&Sve(()), x, y),
+                    SelectedArch::Fallback => {
+                        HalfKernels::<$t>::dot(&Fallback(()), x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn cosine(&self, x: &[$t], y: &[$t]) -> f32 {
+                match self.0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => HalfKernels::<$t>::cosine(&Avx2(()), x, y),
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        HalfKernels::<$t>::cosine(&Avx2Fma(()), x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => HalfKernels::<$t>::cosine(&Avx512(()), x, y),
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => HalfKernels::<$t>::cosine(&Neon(()), x, y),
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => HalfKernels::<$t>::cosine(&Sve(()), x, y),
+                    SelectedArch::Fallback => {
+                        HalfKernels::<$t>::cosine(&Fallback(()), x, y)
+                    },
+                }
+            }
+
+            #[inline]
+            unsafe fn squared_euclidean(&self, x: &[$t], y: &[$t]) -> f32 {
+                match self.0 {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2 => {
+                        HalfKernels::<$t>::squared_euclidean(&Avx2(()), x, y)
+                    },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    SelectedArch::Avx2Fma => {
+                        HalfKernels::<$t>::squared_euclidean(&Avx2Fma(()), x, y)
+                    },
+                    #[cfg(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        feature = "nightly"
+                    ))]
+                    SelectedArch::Avx512 => {
+                        HalfKernels::<$t>::squared_euclidean(&Avx512(()), x, y)
+                    },
+                    #[cfg(target_arch = "aarch64")]
+                    SelectedArch::Neon => {
+                        HalfKernels::<$t>::squared_euclidean(&Neon(()), x, y)
+                    },
+                    #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+                    SelectedArch::Sve => {
+                        HalfKernels::<$t>::squared_euclidean(&Sve(()), x, y)
+                    },
+                    SelectedArch::Fallback => {
+                        HalfKernels::<$t>::squared_euclidean(&Fallback(()), x, y)
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_half_auto_kernels!(f16);
+impl_half_auto_kernels!(bf16);
+
+macro_rules! impl_half_dangerous_ops {
+    ($t:ident, $arch:ident) => {
+        impl<D: Dim> DangerousOps<$t> for (D, $arch) {
+            #[inline]
+            unsafe fn dot(&self, x: &[$t], y: &[$t]) -> f32 {
+                HalfKernels::<$t>::dot(&self.1, x, y)
+            }
+
+            #[inline]
+            unsafe fn cosine(&self, x: &[$t], y: &[$t]) -> f32 {
+                HalfKernels::<$t>::cosine(&self.1, x, y)
+            }
+
+            #[inline]
+            unsafe fn squared_euclidean(&self, x: &[$t], y: &[$t]) -> f32 {
+                HalfKernels::<$t>::squared_euclidean(&self.1, x, y)
+            }
+
+            #[inline]
+            unsafe fn manhattan(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::generic_xany_fallback_nofma_manhattan(
+                    &widen(x),
+                    &widen(y),
+                )
+            }
+
+            #[inline]
+            unsafe fn chebyshev(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::generic_xany_fallback_nofma_chebyshev(
+                    &widen(x),
+                    &widen(y),
+                )
+            }
+
+            #[inline]
+            unsafe fn weighted_squared_euclidean(
+                &self,
+                x: &[$t],
+                y: &[$t],
+                w: &[f32],
+            ) -> f32 {
+                crate::danger::generic_xany_fallback_nofma_weighted_euclidean(
+                    &widen(x),
+                    &widen(y),
+                    w,
+                )
+            }
+
+            #[inline]
+            unsafe fn hellinger(&self, x: &[$t], y: &[$t]) -> f32 {
+                crate::danger::generic_xany_fallback_nofma_hellinger(
+                    &widen(x),
+                    &widen(y),
+                )
+            }
+
+            #[inline]
+            unsafe fn angular_hyperplane(&self, x: &[$t], y: &[$t]) -> Vec<$t> {
+                let hyperplane =
+                    crate::danger::f32_xany_fallback_nofma_angular_hyperplane(
+                        &widen(x),
+                        &widen(y),
+                    );
+                hyperplane.into_iter().map($t::from_f32).collect()
+            }
+
+            #[inline]
+            unsafe fn euclidean_hyperplane(&self, x: &[$t], y: &[$t]) -> (Vec<$t>, f32) {
+                let (hyperplane, offset) =
+                    crate::danger::f32_xany_fallback_nofma_euclidean_hyperplane(
+                        &widen(x),
+                        &widen(y),
+                    );
+                (hyperplane.into_iter().map($t::from_f32).collect(), offset)
+            }
+
+            #[inline]
+            unsafe fn squared_norm(&self, x: &[$t]) -> f32 {
+                HalfKernels::<$t>::dot(&self.1, x, x)
+            }
+
+            #[inline]
+            unsafe fn add_value(&self, x: &mut [$t], val: $t) {
+                crate::danger::generic_xany_fallback_nofma_add_value(x, val)
+            }
+
+            #[inline]
+            unsafe fn sub_value(&self, x: &mut [$t], val: $t) {
+                crate::danger::generic_xany_fallback_nofma_sub_value(x, val)
+            }
+
+            #[inline]
+            unsafe fn mul_value(&self, x: &mut [$t], val: $t) {
+                crate::danger::generic_xany_fallback_nofma_mul_value(x, val)
+            }
+
+            #[inline]
+            unsafe fn div_value(&self, x: &mut [$t], val: $t) {
+                crate::danger::generic_xany_fallback_nofma_div_value(x, val)
+            }
+
+            #[inline]
+            unsafe fn add_vertical(&self, x: &mut [$t], y: &[$t]) {
+                crate::danger::generic_xany_fallback_nofma_add_vertical(x, y)
+            }
+
+            #[inline]
+            unsafe fn sub_vertical(&self, x: &mut [$t], y: &[$t]) {
+                crate::danger::generic_xany_fallback_nofma_sub_vertical(x, y)
+            }
+
+            #[inline]
+            unsafe fn mul_vertical(&self, x: &mut [$t], y: &[$t]) {
+                crate::danger::generic_xany_fallback_nofma_mul_vertical(x, y)
+            }
+
+            #[inline]
+            unsafe fn div_vertical(&self, x: &mut [$t], y: &[$t]) {
+                crate::danger::generic_xany_fallback_nofma_div_vertical(x, y)
+            }
+
+            #[inline]
+            unsafe fn sum(&self, x: &[$t]) -> f32 {
+                crate::danger::half_xany_fallback_nofma_sum(x)
+            }
+
+            #[inline]
+            unsafe fn min(&self, x: &[$t]) -> $t {
+                crate::danger::f32_xany_fallback_nofma_min_horizontal(x)
+            }
+
+            #[inline]
+            unsafe fn max(&self, x: &[$t]) -> $t {
+                crate::danger::generic_xany_fallback_nofma_max_horizontal(x)
+            }
+        }
+    };
+}
+
+impl_half_dangerous_ops!(f16, Fallback);
+impl_half_dangerous_ops!(bf16, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_dangerous_ops!(f16, Avx2);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_dangerous_ops!(bf16, Avx2);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_dangerous_ops!(f16, Avx2Fma);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl_half_dangerous_ops!(bf16, Avx2Fma);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+impl_half_dangerous_ops!(f16, Avx512);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+impl_half_dangerous_ops!(bf16, Avx512);
+impl_half_dangerous_ops!(f16, Auto);
+impl_half_dangerous_ops!(bf16, Auto);
+#[cfg(target_arch = "aarch64")]
+impl_half_dangerous_ops!(f16, Neon);
+#[cfg(target_arch = "aarch64")]
+impl_half_dangerous_ops!(bf16, Neon);
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl_half_dangerous_ops!(f16, Sve);
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+impl_half_dangerous_ops!(bf16, Sve);
@@ -1,5 +1,11 @@
 mod f32_auto_size;
 mod f32_fixed_size;
+mod f64_auto_size;
+mod f64_fixed_size;
+mod half_any_size;
+
+use crate::types::Sealed;
+use crate::VectorType;
 
 #[allow(clippy::len_without_is_empty)]
 /// Safe spacial type operations.
@@ -18,6 +24,36 @@ pub trait SpacialOps: Sized {
     fn dist_cosine(&self, other: &Self) -> f32;
     /// Computes the cosine distance between self and another vector.
     fn dist_squared_euclidean(&self, other: &Self) -> f32;
+    /// Computes the Manhattan (L1) distance between self and another vector.
+    fn dist_manhattan(&self, other: &Self) -> f32;
+    /// Computes the Chebyshev (L∞) distance between self and another vector.
+    fn dist_chebyshev(&self, other: &Self) -> f32;
+    /// Computes the Hamming distance between self and another vector, the number
+    /// of elements which differ.
+    fn dist_hamming(&self, other: &Self) -> f32;
+    /// Computes the Jaccard distance between self and another vector, treating
+    /// each vector as the set of its non-zero elements.
+    fn dist_jaccard(&self, other: &Self) -> f32;
+    /// Computes the Minkowski distance between self and another vector with the
+    /// power `p`.
+    fn dist_minkowski(&self, other: &Self, p: f32) -> f32;
+    /// Computes the squared Euclidean distance between self and another vector,
+    /// scaling the squared difference of each dimension by the matching weight.
+    ///
+    /// The number of weights must match the length of the vectors.
+    fn dist_weighted_squared_euclidean(&self, other: &Self, weights: &[f32]) -> f32;
+    /// Computes the Haversine (great-circle) distance between self and another
+    /// `[latitude, longitude]` point in radians.
+    ///
+    /// # Panics
+    /// If either vector does not have exactly `2` dimensions.
+    fn dist_haversine(&self, other: &Self) -> f32;
+    /// Computes the Pearson correlation distance between self and another vector.
+    fn dist_correlation(&self, other: &Self) -> f32;
+    /// Computes the Hellinger distance between self and another vector.
+    fn dist_hellinger(&self, other: &Self) -> f32;
+    /// Computes the Jensen-Shannon divergence between self and another vector.
+    fn dist_jensen_shannon(&self, other: &Self) -> f32;
     /// Computes the angular hyperplane vector between self and another vector.
     fn angular_hyperplane(&self, other: &Self) -> Self;
     /// Computes the Euclidean hyperplane vector between self and another vector and
@@ -26,92 +62,188 @@ pub trait SpacialOps: Sized {
 }
 
 /// Safe metric type operations.
-pub trait MetricOps: Sized {
+pub trait MetricOps<T = f32>: Sized {
     /// Returns the minimum value in the vector.
-    fn min(&self) -> f32;
+    fn min(&self) -> T;
     /// Returns the maximum value in the vector.
-    fn max(&self) -> f32;
+    fn max(&self) -> T;
     /// Returns the sum of all values in the vector.
-    fn sum(&self) -> f32;
+    fn sum(&self) -> T;
     /// Returns the mean value of the vector.
-    fn mean(&self) -> f32;
+    fn mean(&self) -> T;
 }
 
-/// A set of compute ops various archs and dims implement.
+/// A set of compute ops various archs and dims implement for vectors of the
+/// element type `T`.
+///
+/// Distances and reductions are returned as the element type's
+/// [Accumulator](VectorType::Accumulator).
 ///
 /// # Safety
 /// All vectors must contain only finite values.
-pub trait DangerousOps {
+pub trait DangerousOps<T: VectorType = f32> {
     /// Computes the dot product of the two provided vectors.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn dot(&self, x: &[f32], y: &[f32]) -> f32;
+    unsafe fn dot(&self, x: &[T], y: &[T]) -> T::Accumulator;
     /// Computes the cosine distance of the two provided vectors.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn cosine(&self, x: &[f32], y: &[f32]) -> f32;
+    unsafe fn cosine(&self, x: &[T], y: &[T]) -> T::Accumulator;
     /// Computes the squared Euclidean distance of the two provided vectors.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn squared_euclidean(&self, x: &[f32], y: &[f32]) -> f32;
+    unsafe fn squared_euclidean(&self, x: &[T], y: &[T]) -> T::Accumulator;
+    /// Computes the Manhattan (L1) distance of the two provided vectors.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x` and `y` must also be equal and align with the implementor's required
+    /// dimension sizes.
+    unsafe fn manhattan(&self, x: &[T], y: &[T]) -> T::Accumulator;
+    /// Computes the Chebyshev (L∞) distance of the two provided vectors.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x` and `y` must also be equal and align with the implementor's required
+    /// dimension sizes.
+    unsafe fn chebyshev(&self, x: &[T], y: &[T]) -> T::Accumulator;
+    /// Computes the weighted squared Euclidean distance of the two provided vectors,
+    /// scaling the squared difference of each dimension by the matching weight.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x`, `y` and `w` must also be equal and align with the implementor's
+    /// required dimension sizes.
+    unsafe fn weighted_squared_euclidean(
+        &self,
+        x: &[T],
+        y: &[T],
+        w: &[T::Accumulator],
+    ) -> T::Accumulator;
+    /// Computes the Minkowski distance of the two provided vectors with the power `p`.
+    ///
+    /// The Manhattan, Euclidean and Chebyshev distances are used when `p` is `1`,
+    /// `2` or infinite respectively.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x` and `y` must also be equal and align with the implementor's required
+    /// dimension sizes. `p` must be positive.
+    unsafe fn minkowski(&self, x: &[T], y: &[T], p: T::Accumulator) -> T::Accumulator {
+        if p == T::Accumulator::ONE {
+            self.manhattan(x, y)
+        } else if p == T::Accumulator::TWO {
+            T::Accumulator::sqrt(self.squared_euclidean(x, y))
+        } else if p == T::Accumulator::INFINITY {
+            self.chebyshev(x, y)
+        } else {
+            T::fallback_minkowski(x, y, p)
+        }
+    }
+    /// Computes the Haversine (great-circle) distance of the two provided
+    /// `[latitude, longitude]` points in radians, on a sphere with a radius of `1`.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x` and `y` must both be `2`.
+    unsafe fn haversine(&self, x: &[T], y: &[T]) -> T::Accumulator {
+        T::fallback_haversine(x, y)
+    }
+    /// Computes the Pearson correlation distance of the two provided vectors.
+    ///
+    /// Each vector is centred on its mean before taking the cosine distance.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite values and be not-nan. The dimensions
+    /// of `x` and `y` must also be equal and align with the implementor's required
+    /// dimension sizes.
+    unsafe fn correlation(&self, x: &[T], y: &[T]) -> T::Accumulator {
+        let mut x_centred = x.to_vec();
+        let mut y_centred = y.to_vec();
+        let mean_x = T::Accumulator::mean(self.sum(x), x.len());
+        let mean_y = T::Accumulator::mean(self.sum(y), y.len());
+        self.sub_value(&mut x_centred, T::from_accumulator(mean_x));
+        self.sub_value(&mut y_centred, T::from_accumulator(mean_y));
+        self.cosine(&x_centred, &y_centred)
+    }
+    /// Computes the Hellinger distance of the two provided vectors.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite, non-negative values and be not-nan.
+    /// The dimensions of `x` and `y` must also be equal and align with the
+    /// implementor's required dimension sizes.
+    unsafe fn hellinger(&self, x: &[T], y: &[T]) -> T::Accumulator;
+    /// Computes the Jensen-Shannon divergence of the two provided vectors,
+    /// treating each as an unnormalized probability distribution.
+    ///
+    /// # Safety
+    /// All vectors must contain only finite, non-negative values and be not-nan.
+    /// The dimensions of `x` and `y` must also be equal and align with the
+    /// implementor's required dimension sizes.
+    unsafe fn jensen_shannon(&self, x: &[T], y: &[T]) -> T::Accumulator {
+        let l1_x = self.sum(x);
+        let l1_y = self.sum(y);
+        T::fallback_jensen_shannon(x, y, l1_x, l1_y)
+    }
     /// Computes the angular hyperplane to the two vector points.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn angular_hyperplane(&self, x: &[f32], y: &[f32]) -> Vec<f32>;
+    unsafe fn angular_hyperplane(&self, x: &[T], y: &[T]) -> Vec<T>;
     /// Computes the Euclidean hyperplane and hyperplane offset.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn euclidean_hyperplane(&self, x: &[f32], y: &[f32]) -> (Vec<f32>, f32);
+    unsafe fn euclidean_hyperplane(&self, x: &[T], y: &[T]) -> (Vec<T>, T::Accumulator);
     /// Computes the squared norm of the given vector.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn squared_norm(&self, x: &[f32]) -> f32;
+    unsafe fn squared_norm(&self, x: &[T]) -> T::Accumulator;
     /// Adds the value `val` to each element in the vector `x`.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn add_value(&self, x: &mut [f32], val: f32);
+    unsafe fn add_value(&self, x: &mut [T], val: T);
     /// Adds the value `val` to each element in the vector `x`.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn sub_value(&self, x: &mut [f32], val: f32);
+    unsafe fn sub_value(&self, x: &mut [T], val: T);
     /// Adds the value `val` to each element in the vector `x`.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn mul_value(&self, x: &mut [f32], val: f32);
+    unsafe fn mul_value(&self, x: &mut [T], val: T);
     /// Adds the value `val` to each element in the vector `x`.
     ///
     /// # Safety
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn div_value(&self, x: &mut [f32], y: f32);
+    unsafe fn div_value(&self, x: &mut [T], y: T);
     /// Performs a vertical addition of each element in `x` with each respective element in `y`.
     ///
     /// # Safety
@@ -120,7 +252,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn add_vertical(&self, x: &mut [f32], y: &[f32]);
+    unsafe fn add_vertical(&self, x: &mut [T], y: &[T]);
     /// Performs a vertical subtraction of each element in `x` with each respective
     /// element in `y`.
     ///
@@ -130,7 +262,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn sub_vertical(&self, x: &mut [f32], y: &[f32]);
+    unsafe fn sub_vertical(&self, x: &mut [T], y: &[T]);
     /// Performs a vertical multiplication of each element in `x` by each respective
     /// element in `y`.
     ///
@@ -140,7 +272,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn mul_vertical(&self, x: &mut [f32], y: &[f32]);
+    unsafe fn mul_vertical(&self, x: &mut [T], y: &[T]);
     /// Performs a vertical division of each element in `x` by each respective element in `y`.
     ///
     /// # Safety
@@ -149,7 +281,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn div_vertical(&self, x: &mut [f32], y: &[f32]);
+    unsafe fn div_vertical(&self, x: &mut [T], y: &[T]);
     /// Performs a horizontal sum of the given vector.
     ///
     /// # Safety
@@ -157,7 +289,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn sum(&self, x: &[f32]) -> f32;
+    unsafe fn sum(&self, x: &[T]) -> T::Accumulator;
     /// Performs a horizontal min of the given vector.
     ///
     /// # Safety
@@ -165,7 +297,7 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn min(&self, x: &[f32]) -> f32;
+    unsafe fn min(&self, x: &[T]) -> T;
     /// Performs a horizontal max of the given vector.
     ///
     /// # Safety
@@ -173,5 +305,5 @@ pub trait DangerousOps {
     /// All vectors must contain only finite values and be not-nan. The dimensions
     /// of `x` and `y` must also be equal and align with the implementor's required
     /// dimension sizes.
-    unsafe fn max(&self, x: &[f32]) -> f32;
+    unsafe fn max(&self, x: &[T]) -> T;
 }
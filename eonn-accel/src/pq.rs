@@ -0,0 +1,937 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::arch::{Arch, SelectedArch};
+use crate::danger::*;
+use crate::ops::DangerousOps;
+use crate::{Auto, Dim, Vector, VectorCreateError, XAny};
+
+/// The maximum number of Lloyd iterations ran when training each codebook.
+const KMEANS_ITERS: usize = 25;
+
+#[derive(Debug)]
+/// An error that occurs while training a [ProductQuantizer].
+pub enum PqTrainError {
+    /// No sample vectors were provided.
+    EmptySample,
+    /// The sample vectors do not all have the same dimensions.
+    BadDimensions { expected: usize, got: usize },
+    /// The dimensions can not be split evenly into the number of subspaces.
+    BadSubspaces { dims: usize, n_subspaces: usize },
+    /// There are fewer sample vectors than centroids in each codebook.
+    NotEnoughSamples { needed: usize, got: usize },
+    /// The sample vectors contain some non-finite values or Nan.
+    NonFinite,
+}
+
+impl Display for PqTrainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySample => {
+                write!(f, "Empty Sample: at least one vector is required to train")
+            },
+            Self::BadDimensions { expected, got } => {
+                write!(f, "Bad Dimensions: expected {expected} but got {got}")
+            },
+            Self::BadSubspaces { dims, n_subspaces } => {
+                write!(
+                    f,
+                    "Bad Subspaces: {dims} dimensions can not be split into \
+                    {n_subspaces} equal subspaces"
+                )
+            },
+            Self::NotEnoughSamples { needed, got } => {
+                write!(
+                    f,
+                    "Not Enough Samples: at least {needed} vectors are required but \
+                    got {got}"
+                )
+            },
+            Self::NonFinite => {
+                write!(f, "Non-Finite Value: values in sample must be finite")
+            },
+        }
+    }
+}
+
+impl std::error::Error for PqTrainError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The number of centroids in each codebook of a [ProductQuantizer].
+pub enum PqCodeSize {
+    /// `16` centroids per subspace.
+    ///
+    /// The codes can be scanned with the 4-bit fast-scan routines, see
+    /// [PqCodes::fast_scan].
+    Bits4,
+    /// `256` centroids per subspace.
+    Bits8,
+}
+
+impl PqCodeSize {
+    #[inline]
+    /// Returns the number of centroids in each codebook.
+    pub fn n_centroids(&self) -> usize {
+        match self {
+            Self::Bits4 => 16,
+            Self::Bits8 => 256,
+        }
+    }
+}
+
+/// A product quantizer mapping `f32` vectors onto one byte per subspace.
+///
+/// The dimensions are split into `m` equal subspaces and a codebook of centroids is
+/// trained for each one with k-means, a vector is then encoded as the index of the
+/// nearest centroid within each subspace, compressing it down to `m` bytes.
+///
+/// Distances are computed asymmetrically between a full precision query and the
+/// codes, by first building a [PqLookupTable] holding the distance from each query
+/// subspace to every centroid, after which the distance to any code is a sum of
+/// `m` table entries. The distances are approximate, so the usual approach is to
+/// search over the codes and re-rank the best candidates against the full
+/// precision vectors.
+pub struct ProductQuantizer<D: Dim = XAny> {
+    /// The centroids laid out as `[subspace][centroid][dimension]`.
+    centroids: Vec<f32>,
+    n_subspaces: usize,
+    sub_dims: usize,
+    code_size: PqCodeSize,
+    ops: (XAny, Auto),
+    dims: PhantomData<D>,
+}
+
+impl<D: Dim> Debug for ProductQuantizer<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ProductQuantizer(ops={:?}, code_size={:?}, n_subspaces={}, dims={})",
+            self.ops.1,
+            self.code_size,
+            self.n_subspaces,
+            self.dims()
+        )
+    }
+}
+
+impl<D: Dim> ProductQuantizer<D> {
+    /// Trains a new quantizer with `n_subspaces` codebooks from the sample.
+    ///
+    /// The codebooks are seeded with evenly spaced sample vectors, so the sample
+    /// should be shuffled if it is sorted in any way.
+    pub fn train<A: Arch>(
+        sample: &[Vector<D, A, f32>],
+        n_subspaces: usize,
+        code_size: PqCodeSize,
+    ) -> Result<Self, PqTrainError>
+    where
+        (D, A): DangerousOps,
+    {
+        let Some(first) = sample.first() else {
+            return Err(PqTrainError::EmptySample);
+        };
+
+        let dims = first.len();
+        if let Some(vector) = sample.iter().find(|v| v.len() != dims) {
+            return Err(PqTrainError::BadDimensions {
+                expected: dims,
+                got: vector.len(),
+            });
+        }
+
+        if n_subspaces == 0 || dims % n_subspaces != 0 {
+            return Err(PqTrainError::BadSubspaces { dims, n_subspaces });
+        }
+
+        let n_centroids = code_size.n_centroids();
+        if sample.len() < n_centroids {
+            return Err(PqTrainError::NotEnoughSamples {
+                needed: n_centroids,
+                got: sample.len(),
+            });
+        }
+
+        if sample.iter().any(|v| v.iter().any(|x| !x.is_finite())) {
+            return Err(PqTrainError::NonFinite);
+        }
+
+        let ops = <(XAny, Auto)>::default();
+        let sub_dims = dims / n_subspaces;
+
+        let mut centroids = Vec::with_capacity(n_subspaces * n_centroids * sub_dims);
+        for j in 0..n_subspaces {
+            let points = sample
+                .iter()
+                .map(|v| &v[j * sub_dims..(j + 1) * sub_dims])
+                .collect::<Vec<_>>();
+            centroids.extend(train_codebook(&ops, &points, n_centroids, sub_dims));
+        }
+
+        Ok(Self {
+            centroids,
+            n_subspaces,
+            sub_dims,
+            code_size,
+            ops,
+            dims: PhantomData,
+        })
+    }
+
+    #[inline]
+    /// Returns the number of subspaces, and so the number of bytes in each code.
+    pub fn n_subspaces(&self) -> usize {
+        self.n_subspaces
+    }
+
+    #[inline]
+    /// Returns the number of centroids in each codebook.
+    pub fn code_size(&self) -> PqCodeSize {
+        self.code_size
+    }
+
+    #[inline]
+    /// Returns the number of dimensions of the vectors the quantizer was trained on.
+    pub fn dims(&self) -> usize {
+        self.n_subspaces * self.sub_dims
+    }
+
+    #[inline]
+    /// Returns the centroids of the codebook for subspace `j`, laid out as
+    /// `[centroid][dimension]`.
+    pub fn codebook(&self, j: usize) -> &[f32] {
+        let size = self.code_size.n_centroids() * self.sub_dims;
+        &self.centroids[j * size..(j + 1) * size]
+    }
+
+    /// Encodes the given vector into one byte per subspace.
+    pub fn encode<A: Arch>(
+        &self,
+        vector: &Vector<D, A, f32>,
+    ) -> Result<Vec<u8>, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_dims(vector.len())?;
+        Ok(self.encode_values(vector))
+    }
+
+    /// Encodes all of the given vectors into a contiguous block of codes.
+    pub fn encode_all<A: Arch>(
+        &self,
+        vectors: &[Vector<D, A, f32>],
+    ) -> Result<PqCodes, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        let mut codes = Vec::with_capacity(vectors.len() * self.n_subspaces);
+        for vector in vectors {
+            self.check_dims(vector.len())?;
+            codes.extend(self.encode_values(vector));
+        }
+
+        Ok(PqCodes {
+            codes,
+            n_subspaces: self.n_subspaces,
+            code_size: self.code_size,
+            arch: self.ops.1 .0,
+        })
+    }
+
+    /// Decodes the code back into an approximation of the original vector, made
+    /// up of the selected centroid of each subspace.
+    pub fn decode<A: Arch>(&self, code: &[u8]) -> Vector<D, A, f32>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_code(code);
+
+        let mut values = Vec::with_capacity(self.dims());
+        for (j, &c) in code.iter().enumerate() {
+            values.extend_from_slice(self.centroid(j, c as usize));
+        }
+
+        unsafe { Vector::from_vec_unchecked(values) }
+    }
+
+    /// Builds the lookup table of the squared Euclidean distances between each
+    /// subspace of the query and every centroid.
+    ///
+    /// The sum of the table entries selected by a code is the squared Euclidean
+    /// distance between the query and the decoded vector.
+    pub fn lookup_table<A: Arch>(
+        &self,
+        query: &Vector<D, A, f32>,
+    ) -> Result<PqLookupTable, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_dims(query.len())?;
+        Ok(self.build_table(query, |x, y| unsafe { self.ops.squared_euclidean(x, y) }))
+    }
+
+    /// Builds the lookup table of the dot products between each subspace of the
+    /// query and every centroid.
+    ///
+    /// The sum of the table entries selected by a code is the dot product between
+    /// the query and the decoded vector.
+    pub fn inner_product_table<A: Arch>(
+        &self,
+        query: &Vector<D, A, f32>,
+    ) -> Result<PqLookupTable, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_dims(query.len())?;
+        Ok(self.build_table(query, |x, y| unsafe { self.ops.dot(x, y) }))
+    }
+
+    fn check_dims(&self, got: usize) -> Result<(), VectorCreateError> {
+        if got != self.dims() {
+            return Err(VectorCreateError::BadDimensions {
+                expected: self.dims(),
+                got,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_code(&self, code: &[u8]) {
+        assert_eq!(
+            code.len(),
+            self.n_subspaces,
+            "Code must contain one byte per subspace"
+        );
+        assert!(
+            code.iter()
+                .all(|&c| (c as usize) < self.code_size.n_centroids()),
+            "Code contains a centroid outside of the codebook"
+        );
+    }
+
+    #[inline]
+    fn centroid(&self, j: usize, c: usize) -> &[f32] {
+        let start = (j * self.code_size.n_centroids() + c) * self.sub_dims;
+        &self.centroids[start..start + self.sub_dims]
+    }
+
+    fn encode_values(&self, values: &[f32]) -> Vec<u8> {
+        (0..self.n_subspaces)
+            .map(|j| {
+                let point = &values[j * self.sub_dims..(j + 1) * self.sub_dims];
+                nearest_centroid(&self.ops, self.codebook(j), self.sub_dims, point) as u8
+            })
+            .collect()
+    }
+
+    fn build_table(
+        &self,
+        query: &[f32],
+        distance: impl Fn(&[f32], &[f32]) -> f32,
+    ) -> PqLookupTable {
+        let n_centroids = self.code_size.n_centroids();
+
+        let mut table = Vec::with_capacity(self.n_subspaces * n_centroids);
+        for j in 0..self.n_subspaces {
+            let point = &query[j * self.sub_dims..(j + 1) * self.sub_dims];
+            for c in 0..n_centroids {
+                table.push(distance(point, self.centroid(j, c)));
+            }
+        }
+
+        PqLookupTable {
+            table,
+            n_subspaces: self.n_subspaces,
+            code_size: self.code_size,
+            arch: self.ops.1 .0,
+        }
+    }
+}
+
+/// Trains a codebook of `n_centroids` centroids over the points with k-means.
+fn train_codebook(
+    ops: &(XAny, Auto),
+    points: &[&[f32]],
+    n_centroids: usize,
+    sub_dims: usize,
+) -> Vec<f32> {
+    let n_points = points.len();
+
+    let mut centroids = (0..n_centroids)
+        .flat_map(|c| points[c * n_points / n_centroids].iter().copied())
+        .collect::<Vec<_>>();
+    let mut assignments = vec![usize::MAX; n_points];
+
+    for _ in 0..KMEANS_ITERS {
+        let mut changed = false;
+        for (assignment, point) in assignments.iter_mut().zip(points) {
+            let nearest = nearest_centroid(ops, &centroids, sub_dims, point);
+            if *assignment != nearest {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![0.0; n_centroids * sub_dims];
+        let mut counts = vec![0usize; n_centroids];
+        for (&assignment, point) in assignments.iter().zip(points) {
+            counts[assignment] += 1;
+            let sum = &mut sums[assignment * sub_dims..(assignment + 1) * sub_dims];
+            for (s, &x) in sum.iter_mut().zip(point.iter()) {
+                *s += x;
+            }
+        }
+
+        // Empty clusters keep their previous centroid.
+        for (c, &count) in counts.iter().enumerate() {
+            if count > 0 {
+                let range = c * sub_dims..(c + 1) * sub_dims;
+                for (centroid, &sum) in
+                    centroids[range.clone()].iter_mut().zip(&sums[range])
+                {
+                    *centroid = sum / count as f32;
+                }
+            }
+        }
+    }
+
+    centroids
+}
+
+/// Returns the index of the centroid nearest to the point.
+fn nearest_centroid(
+    ops: &(XAny, Auto),
+    centroids: &[f32],
+    sub_dims: usize,
+    point: &[f32],
+) -> usize {
+    let mut nearest = 0;
+    let mut nearest_dist = f32::INFINITY;
+    for (c, centroid) in centroids.chunks_exact(sub_dims).enumerate() {
+        let dist = unsafe { ops.squared_euclidean(point, centroid) };
+        if dist < nearest_dist {
+            nearest = c;
+            nearest_dist = dist;
+        }
+    }
+    nearest
+}
+
+#[derive(Clone)]
+/// A contiguous block of codes produced by a [ProductQuantizer], each code taking
+/// one byte per subspace.
+pub struct PqCodes {
+    codes: Vec<u8>,
+    n_subspaces: usize,
+    code_size: PqCodeSize,
+    arch: SelectedArch,
+}
+
+impl Debug for PqCodes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PqCodes(code_size={:?}, n_subspaces={}, len={})",
+            self.code_size,
+            self.n_subspaces,
+            self.len()
+        )
+    }
+}
+
+impl PqCodes {
+    #[inline]
+    /// Returns the number of codes.
+    pub fn len(&self) -> usize {
+        self.codes.len() / self.n_subspaces
+    }
+
+    #[inline]
+    /// Returns if there are no codes.
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    #[inline]
+    /// Returns the number of subspaces, and so the number of bytes in each code.
+    pub fn n_subspaces(&self) -> usize {
+        self.n_subspaces
+    }
+
+    #[inline]
+    /// Returns the number of centroids in each codebook the codes index into.
+    pub fn code_size(&self) -> PqCodeSize {
+        self.code_size
+    }
+
+    #[inline]
+    /// Returns the code of vector `idx`.
+    pub fn get(&self, idx: usize) -> &[u8] {
+        &self.codes[idx * self.n_subspaces..(idx + 1) * self.n_subspaces]
+    }
+
+    #[inline]
+    /// Computes the value of vector `idx` from the lookup table, see
+    /// [PqLookupTable::lookup].
+    pub fn lookup(&self, table: &PqLookupTable, idx: usize) -> f32 {
+        assert_eq!(
+            (table.n_subspaces, table.code_size),
+            (self.n_subspaces, self.code_size),
+            "Lookup table must match the codes"
+        );
+        // The codes were produced by the quantizer so are always within the table.
+        unsafe { table.lookup_unchecked(self.get(idx)) }
+    }
+
+    /// Packs the codes into the blocked layout used by the 4-bit fast-scan routines.
+    ///
+    /// Returns `None` unless the codes were produced with [PqCodeSize::Bits4].
+    pub fn fast_scan(&self) -> Option<FastScanCodes> {
+        if self.code_size != PqCodeSize::Bits4 {
+            return None;
+        }
+
+        let n_blocks = self.len().div_ceil(FAST_SCAN_BLOCK);
+        let block_size = self.n_subspaces * 16;
+
+        let mut blocks = vec![0; n_blocks * block_size];
+        for idx in 0..self.len() {
+            let block = idx / FAST_SCAN_BLOCK;
+            let lane = idx % 16;
+            let shift = if idx % FAST_SCAN_BLOCK < 16 { 0 } else { 4 };
+
+            for (j, &c) in self.get(idx).iter().enumerate() {
+                blocks[block * block_size + j * 16 + lane] |= c << shift;
+            }
+        }
+
+        Some(FastScanCodes {
+            blocks,
+            len: self.len(),
+            n_subspaces: self.n_subspaces,
+            arch: self.arch,
+        })
+    }
+}
+
+#[derive(Clone)]
+/// The per-query lookup table built by a [ProductQuantizer], holding the value of
+/// each subspace of the query against every centroid.
+pub struct PqLookupTable {
+    table: Vec<f32>,
+    n_subspaces: usize,
+    code_size: PqCodeSize,
+    arch: SelectedArch,
+}
+
+impl Debug for PqLookupTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PqLookupTable(code_size={:?}, n_subspaces={})",
+            self.code_size, self.n_subspaces
+        )
+    }
+}
+
+impl PqLookupTable {
+    #[inline]
+    /// Sums the table entries selected by the code, giving the distance or dot
+    /// product between the query and the decoded vector.
+    pub fn lookup(&self, code: &[u8]) -> f32 {
+        assert_eq!(
+            code.len(),
+            self.n_subspaces,
+            "Code must contain one byte per subspace"
+        );
+        if self.code_size == PqCodeSize::Bits4 {
+            assert!(
+                code.iter().all(|&c| c < 16),
+                "Code contains a centroid outside of the codebook"
+            );
+        }
+
+        unsafe { self.lookup_unchecked(code) }
+    }
+
+    #[inline]
+    unsafe fn lookup_unchecked(&self, code: &[u8]) -> f32 {
+        let n_centroids = self.code_size.n_centroids();
+
+        match self.arch {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => {
+                u8_xany_avx512_lookup(&self.table, n_centroids, code)
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                u8_xany_avx2_lookup(&self.table, n_centroids, code)
+            },
+            // The lookups are gather bound, which NEON and SVE have no advantage in.
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => {
+                u8_xany_fallback_lookup(&self.table, n_centroids, code)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => u8_xany_fallback_lookup(&self.table, n_centroids, code),
+            SelectedArch::Fallback => {
+                u8_xany_fallback_lookup(&self.table, n_centroids, code)
+            },
+        }
+    }
+
+    /// Quantizes each entry of a `16` centroid table down to a byte for the
+    /// fast-scan routines, returning the table along with the bias and scale to
+    /// map the summed bytes back to a value.
+    fn quantize(&self) -> (Vec<u8>, f32, f32) {
+        let mut bias = 0.0;
+        let mut max_range = 0.0f32;
+        for entries in self.table.chunks_exact(16) {
+            let min = entries.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = entries.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            bias += min;
+            max_range = max_range.max(max - min);
+        }
+
+        let scale = max_range / u8::MAX as f32;
+        let table = self
+            .table
+            .chunks_exact(16)
+            .flat_map(|entries| {
+                let min = entries.iter().copied().fold(f32::INFINITY, f32::min);
+                entries.iter().map(move |&v| {
+                    if scale == 0.0 {
+                        0
+                    } else {
+                        ((v - min) / scale).round().min(u8::MAX as f32) as u8
+                    }
+                })
+            })
+            .collect();
+
+        (table, bias, scale)
+    }
+}
+
+#[derive(Clone)]
+/// 4-bit product quantization codes packed into blocks of `32` for scanning with
+/// the fast-scan routines.
+///
+/// Rather than gathering each table entry from memory, the `16` entries of each
+/// subspace are quantized to bytes and held in a single register, then looked up
+/// for a whole block of codes at once with `pshufb`. The values produced are an
+/// approximation of [PqLookupTable::lookup] as the table loses precision, and
+/// large sums saturate, so the results should be re-ranked.
+pub struct FastScanCodes {
+    blocks: Vec<u8>,
+    len: usize,
+    n_subspaces: usize,
+    arch: SelectedArch,
+}
+
+impl Debug for FastScanCodes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FastScanCodes(n_subspaces={}, len={})",
+            self.n_subspaces, self.len
+        )
+    }
+}
+
+impl FastScanCodes {
+    #[inline]
+    /// Returns the number of codes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    /// Returns if there are no codes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Computes the approximate value of every code from the lookup table.
+    pub fn scan(&self, table: &PqLookupTable) -> Vec<f32> {
+        assert_eq!(
+            (table.n_subspaces, table.code_size),
+            (self.n_subspaces, PqCodeSize::Bits4),
+            "Lookup table must match the codes"
+        );
+
+        let (quantized, bias, scale) = table.quantize();
+        let block_size = self.n_subspaces * 16;
+
+        let mut values = Vec::with_capacity(self.len);
+        let mut sums = [0; FAST_SCAN_BLOCK];
+        for block in self.blocks.chunks_exact(block_size) {
+            unsafe { self.scan_block(block, &quantized, &mut sums) };
+
+            let remaining = std::cmp::min(FAST_SCAN_BLOCK, self.len - values.len());
+            values.extend(
+                sums[..remaining]
+                    .iter()
+                    .map(|&sum| bias + scale * sum as f32),
+            );
+        }
+
+        values
+    }
+
+    #[inline]
+    unsafe fn scan_block(
+        &self,
+        block: &[u8],
+        table: &[u8],
+        out: &mut [u16; FAST_SCAN_BLOCK],
+    ) {
+        match self.arch {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            SelectedArch::Avx2 | SelectedArch::Avx2Fma => {
+                u8_x32_avx2_fast_scan(block, table, out)
+            },
+            // AVX512 CPUs always support AVX2, which is enough to hold a table.
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            SelectedArch::Avx512 => u8_x32_avx2_fast_scan(block, table, out),
+            #[cfg(target_arch = "aarch64")]
+            SelectedArch::Neon => u8_x32_fallback_fast_scan(block, table, out),
+            #[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+            SelectedArch::Sve => u8_x32_fallback_fast_scan(block, table, out),
+            SelectedArch::Fallback => u8_x32_fallback_fast_scan(block, table, out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_relative_close, simple_dot, simple_euclidean};
+    use crate::X1024;
+
+    fn sample_vectors(n: usize) -> Vec<Vector<X1024, Auto>> {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 24) as f32
+        };
+
+        (0..n)
+            .map(|_| Vector::try_from_vec((0..1024).map(|_| next()).collect()).unwrap())
+            .collect()
+    }
+
+    fn all_archs() -> Vec<SelectedArch> {
+        #[allow(unused_mut)]
+        let mut archs = vec![SelectedArch::Fallback];
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("avx2") {
+            archs.push(SelectedArch::Avx2);
+        }
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if is_x86_feature_detected!("avx512f") {
+            archs.push(SelectedArch::Avx512);
+        }
+
+        archs
+    }
+
+    #[test]
+    fn test_train_errors() {
+        let sample = sample_vectors(32);
+
+        let err = ProductQuantizer::<X1024>::train::<Auto>(&[], 8, PqCodeSize::Bits4)
+            .unwrap_err();
+        assert!(matches!(err, PqTrainError::EmptySample));
+
+        let err = ProductQuantizer::train(&sample, 7, PqCodeSize::Bits4).unwrap_err();
+        assert!(matches!(
+            err,
+            PqTrainError::BadSubspaces {
+                dims: 1024,
+                n_subspaces: 7
+            }
+        ));
+
+        let err = ProductQuantizer::train(&sample, 0, PqCodeSize::Bits4).unwrap_err();
+        assert!(matches!(err, PqTrainError::BadSubspaces { .. }));
+
+        let err = ProductQuantizer::train(&sample, 8, PqCodeSize::Bits8).unwrap_err();
+        assert!(matches!(
+            err,
+            PqTrainError::NotEnoughSamples {
+                needed: 256,
+                got: 32
+            }
+        ));
+
+        let sample = vec![
+            Vector::<XAny, Auto>::try_from_vec(vec![1.0; 4]).unwrap(),
+            Vector::<XAny, Auto>::try_from_vec(vec![1.0; 2]).unwrap(),
+        ];
+        let err = ProductQuantizer::train(&sample, 2, PqCodeSize::Bits4).unwrap_err();
+        assert!(matches!(
+            err,
+            PqTrainError::BadDimensions {
+                expected: 4,
+                got: 2
+            }
+        ));
+
+        let sample = (0..16)
+            .map(|_| Vector::<XAny, Auto>::try_from_vec(vec![f32::NAN; 4]).unwrap())
+            .collect::<Vec<_>>();
+        let err = ProductQuantizer::train(&sample, 2, PqCodeSize::Bits4).unwrap_err();
+        assert!(matches!(err, PqTrainError::NonFinite));
+    }
+
+    #[test]
+    fn test_exact_codebook() {
+        // With as many distinct points as centroids every point is a centroid.
+        let sample = (0..16)
+            .map(|i| {
+                let values = vec![i as f32, -(i as f32), i as f32 * 2.0, 1.0];
+                Vector::<XAny, Auto>::try_from_vec(values).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let quantizer = ProductQuantizer::train(&sample, 2, PqCodeSize::Bits4).unwrap();
+        assert_eq!(quantizer.dims(), 4);
+        assert_eq!(quantizer.n_subspaces(), 2);
+        assert_eq!(quantizer.codebook(0).len(), 16 * 2);
+
+        for vector in &sample {
+            let code = quantizer.encode(vector).unwrap();
+            assert_eq!(code.len(), 2);
+
+            let decoded: Vector<XAny, Auto> = quantizer.decode(&code);
+            assert_eq!(decoded.as_ref(), vector.as_ref());
+        }
+
+        let vector = Vector::<XAny, Auto>::try_from_vec(vec![1.0; 3]).unwrap();
+        assert!(matches!(
+            quantizer.encode(&vector),
+            Err(VectorCreateError::BadDimensions {
+                expected: 4,
+                got: 3
+            })
+        ));
+        assert!(quantizer.lookup_table(&vector).is_err());
+    }
+
+    #[test]
+    fn test_training_reduces_error() {
+        let sample = sample_vectors(512);
+        let quantizer =
+            ProductQuantizer::train(&sample, 128, PqCodeSize::Bits8).unwrap();
+
+        // The centroids should be closer than the average point in the sample.
+        let mean = sample_vectors(1)[0].as_ref().to_vec();
+        let mut error = 0.0;
+        let mut baseline = 0.0;
+        for vector in &sample {
+            let decoded: Vector<X1024, Auto> =
+                quantizer.decode(&quantizer.encode(vector).unwrap());
+            error += simple_euclidean(vector, &decoded);
+            baseline += simple_euclidean(vector, &mean);
+        }
+        assert!(error < baseline, "{error} vs {baseline}");
+    }
+
+    #[test]
+    fn test_lookup_tables() {
+        let sample = sample_vectors(300);
+        let query = &sample_vectors(301)[300];
+
+        for code_size in [PqCodeSize::Bits4, PqCodeSize::Bits8] {
+            let mut quantizer = ProductQuantizer::train(&sample, 64, code_size).unwrap();
+
+            for arch in all_archs() {
+                quantizer.ops = (XAny, Auto(arch));
+
+                let codes = quantizer.encode_all(&sample).unwrap();
+                assert_eq!(codes.len(), 300);
+                assert_eq!(codes.n_subspaces(), 64);
+
+                let euclidean = quantizer.lookup_table(query).unwrap();
+                let dot = quantizer.inner_product_table(query).unwrap();
+
+                for idx in [0, 1, 150, 299] {
+                    let decoded: Vector<X1024, Auto> = quantizer.decode(codes.get(idx));
+
+                    assert_is_relative_close(
+                        codes.lookup(&euclidean, idx),
+                        simple_euclidean(query, &decoded),
+                    );
+                    assert_is_relative_close(
+                        dot.lookup(codes.get(idx)),
+                        simple_dot(query, &decoded),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_scan() {
+        let sample = sample_vectors(100);
+        let query = &sample_vectors(101)[100];
+
+        let mut quantizer =
+            ProductQuantizer::train(&sample, 128, PqCodeSize::Bits4).unwrap();
+        let table = quantizer.lookup_table(query).unwrap();
+        let (_, _, scale) = table.quantize();
+
+        for arch in all_archs() {
+            quantizer.ops = (XAny, Auto(arch));
+
+            let codes = quantizer.encode_all(&sample).unwrap();
+            let fast_scan = codes.fast_scan().unwrap();
+            assert_eq!(fast_scan.len(), 100);
+
+            let values = fast_scan.scan(&table);
+            assert_eq!(values.len(), 100);
+
+            // Each entry is rounded by at most half of the quantized scale.
+            let tolerance = scale * 0.5 * 128.0 + 1e-3;
+            for (idx, value) in values.iter().enumerate() {
+                let expected = codes.lookup(&table, idx);
+                assert!(
+                    (value - expected).abs() <= tolerance,
+                    "{arch:?} {idx}: {value} vs {expected}"
+                );
+            }
+        }
+
+        let sample = sample_vectors(256);
+        let codes = ProductQuantizer::train(&sample, 8, PqCodeSize::Bits8)
+            .unwrap()
+            .encode_all(&sample)
+            .unwrap();
+        assert!(codes.fast_scan().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Code contains a centroid outside of the codebook")]
+    fn test_lookup_bad_code() {
+        let sample = sample_vectors(16);
+        let quantizer = ProductQuantizer::train(&sample, 2, PqCodeSize::Bits4).unwrap();
+        let table = quantizer.lookup_table(&sample[0]).unwrap();
+        table.lookup(&[0, 16]);
+    }
+}
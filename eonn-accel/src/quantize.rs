@@ -0,0 +1,801 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::arch::Arch;
+use crate::danger::*;
+use crate::ops::{DangerousOps, MetricOps};
+use crate::{Dim, Vector, VectorCreateError, XAny};
+
+/// The largest magnitude of a code, `-128` is never produced so the codes are
+/// symmetric around zero and can be negated without overflowing.
+const MAX_CODE: f32 = 127.0;
+
+#[derive(Debug)]
+/// An error that occurs while training a [ScalarQuantizer].
+pub enum QuantizerTrainError {
+    /// No sample vectors were provided.
+    EmptySample,
+    /// The sample vectors do not all have the same dimensions.
+    BadDimensions { expected: usize, got: usize },
+    /// The sample vectors contain some non-finite values or Nan.
+    NonFinite,
+}
+
+impl Display for QuantizerTrainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySample => {
+                write!(f, "Empty Sample: at least one vector is required to train")
+            },
+            Self::BadDimensions { expected, got } => {
+                write!(f, "Bad Dimensions: expected {expected} but got {got}")
+            },
+            Self::NonFinite => {
+                write!(f, "Non-Finite Value: values in sample must be finite")
+            },
+        }
+    }
+}
+
+impl std::error::Error for QuantizerTrainError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The range a [ScalarQuantizer] is trained over.
+pub enum QuantizerRange {
+    /// A single range is shared by every dimension.
+    ///
+    /// This allows the distances between two quantized vectors to be computed
+    /// entirely with integer instructions.
+    Global,
+    /// Each dimension is given its own range.
+    ///
+    /// This is more accurate when the dimensions vary in magnitude, but the
+    /// distances between two quantized vectors must dequantize one side first.
+    PerDimension,
+}
+
+/// An int8 scalar quantizer mapping `f32` vectors onto `i8` codes.
+///
+/// Each value is mapped onto one of `255` evenly spaced levels across the range
+/// seen while training, with a code of `c` being decoded as `offset + scale * c`,
+/// values outside of the trained range are clamped.
+///
+/// Distances can either be computed between two [QuantizedVector]s, or
+/// asymmetrically between a full precision query and a [QuantizedVector], see
+/// [ScalarQuantizer::query]. The asymmetric distances are more accurate as only
+/// one side has lost precision, so the usual approach is to search over the
+/// quantized vectors and re-rank the best candidates against the full precision
+/// vectors.
+///
+/// The integer kernels use `vpmaddubsw` on AVX2 and `vpdpbusd`/`vpdpwssd` on
+/// AVX512-VNNI when they are available at runtime, accumulating in `i32` which
+/// limits vectors to `133,000` dimensions.
+pub struct ScalarQuantizer<D: Dim = XAny> {
+    offset: Vec<f32>,
+    scale: Vec<f32>,
+    range: QuantizerRange,
+    ops: SelectedInt8,
+    dims: PhantomData<D>,
+}
+
+impl<D: Dim> Debug for ScalarQuantizer<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ScalarQuantizer(ops={:?}, range={:?}, dims={})",
+            self.ops,
+            self.range,
+            self.offset.len()
+        )
+    }
+}
+
+impl<D: Dim> ScalarQuantizer<D> {
+    /// Trains a new quantizer from the minimum and maximum values of the sample.
+    pub fn train<A: Arch>(
+        sample: &[Vector<D, A, f32>],
+        range: QuantizerRange,
+    ) -> Result<Self, QuantizerTrainError>
+    where
+        (D, A): DangerousOps,
+    {
+        let Some(first) = sample.first() else {
+            return Err(QuantizerTrainError::EmptySample);
+        };
+
+        let dims = first.len();
+        if let Some(vector) = sample.iter().find(|v| v.len() != dims) {
+            return Err(QuantizerTrainError::BadDimensions {
+                expected: dims,
+                got: vector.len(),
+            });
+        }
+
+        let (min, max) = match range {
+            QuantizerRange::Global => {
+                let (min, max) = sample
+                    .iter()
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+                        (min.min(v.min()), max.max(v.max()))
+                    });
+                (vec![min; dims], vec![max; dims])
+            },
+            QuantizerRange::PerDimension => {
+                let mut min = vec![f32::INFINITY; dims];
+                let mut max = vec![f32::NEG_INFINITY; dims];
+                for vector in sample {
+                    for (i, &value) in vector.iter().enumerate() {
+                        min[i] = min[i].min(value);
+                        max[i] = max[i].max(value);
+                    }
+                }
+                (min, max)
+            },
+        };
+
+        if min.iter().chain(max.iter()).any(|v| !v.is_finite()) {
+            return Err(QuantizerTrainError::NonFinite);
+        }
+
+        let offset = min
+            .iter()
+            .zip(max.iter())
+            .map(|(&min, &max)| min + (max - min) / 2.0)
+            .collect();
+        let scale = min
+            .iter()
+            .zip(max.iter())
+            .map(|(&min, &max)| (max - min) / (MAX_CODE * 2.0))
+            .collect();
+
+        Ok(Self {
+            offset,
+            scale,
+            range,
+            ops: SelectedInt8::default(),
+            dims: PhantomData,
+        })
+    }
+
+    #[inline]
+    /// Returns the range the quantizer was trained over.
+    pub fn range(&self) -> QuantizerRange {
+        self.range
+    }
+
+    #[inline]
+    /// Returns the number of dimensions of the vectors the quantizer was trained on.
+    pub fn dims(&self) -> usize {
+        self.offset.len()
+    }
+
+    /// Quantizes the given vector into its `i8` codes.
+    pub fn encode<A: Arch>(
+        &self,
+        vector: &Vector<D, A, f32>,
+    ) -> Result<QuantizedVector<D>, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_dims(vector.len())?;
+
+        let codes = vector
+            .iter()
+            .zip(self.offset.iter().zip(self.scale.iter()))
+            .map(|(&value, (&offset, &scale))| {
+                if scale == 0.0 {
+                    0
+                } else {
+                    ((value - offset) / scale)
+                        .round()
+                        .clamp(-MAX_CODE, MAX_CODE) as i8
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let sum = codes.iter().map(|&c| c as i32).sum();
+        let squared_norm = self.decode_values(&codes).iter().map(|v| v * v).sum();
+
+        Ok(QuantizedVector {
+            codes,
+            sum,
+            squared_norm,
+            dims: PhantomData,
+        })
+    }
+
+    /// Decodes the quantized vector back into an approximation of the original.
+    pub fn decode<A: Arch>(&self, vector: &QuantizedVector<D>) -> Vector<D, A, f32>
+    where
+        (D, A): DangerousOps,
+    {
+        unsafe { Vector::from_vec_unchecked(self.decode_values(&vector.codes)) }
+    }
+
+    /// Prepares a full precision query for computing asymmetric distances against
+    /// quantized vectors.
+    pub fn query<A: Arch>(
+        &self,
+        vector: &Vector<D, A, f32>,
+    ) -> Result<QuantizedQuery<'_, D>, VectorCreateError>
+    where
+        (D, A): DangerousOps,
+    {
+        self.check_dims(vector.len())?;
+        Ok(self.query_from_values(vector))
+    }
+
+    /// Computes the dot product between two quantized vectors.
+    pub fn dot(&self, x: &QuantizedVector<D>, y: &QuantizedVector<D>) -> f32 {
+        match self.range {
+            QuantizerRange::Global => {
+                // Expanding `(o + s * x) * (o + s * y)` over each dimension.
+                let offset = self.offset.first().copied().unwrap_or_default();
+                let scale = self.scale.first().copied().unwrap_or_default();
+                let codes_dot = self.ops.dot(&x.codes, &y.codes) as f32;
+
+                (self.dims() as f32 * offset * offset)
+                    + (offset * scale * (x.sum + y.sum) as f32)
+                    + (scale * scale * codes_dot)
+            },
+            QuantizerRange::PerDimension => {
+                let decoded = self.decode_values(&x.codes);
+                self.query_from_values(&decoded).dot(y)
+            },
+        }
+    }
+
+    /// Computes the dot product distance between two quantized vectors.
+    pub fn dist_dot(&self, x: &QuantizedVector<D>, y: &QuantizedVector<D>) -> f32 {
+        dot_distance(self.dot(x, y))
+    }
+
+    /// Computes the cosine distance between two quantized vectors.
+    pub fn dist_cosine(&self, x: &QuantizedVector<D>, y: &QuantizedVector<D>) -> f32 {
+        cosine_distance(self.dot(x, y), x.squared_norm, y.squared_norm)
+    }
+
+    /// Computes the squared Euclidean distance between two quantized vectors.
+    pub fn dist_squared_euclidean(
+        &self,
+        x: &QuantizedVector<D>,
+        y: &QuantizedVector<D>,
+    ) -> f32 {
+        match self.range {
+            QuantizerRange::Global => {
+                // The offsets cancel out, leaving only the scaled codes.
+                let scale = self.scale.first().copied().unwrap_or_default();
+                scale * scale * self.ops.squared_euclidean(&x.codes, &y.codes) as f32
+            },
+            QuantizerRange::PerDimension => {
+                let decoded = self.decode_values(&x.codes);
+                self.query_from_values(&decoded).dist_squared_euclidean(y)
+            },
+        }
+    }
+
+    fn check_dims(&self, got: usize) -> Result<(), VectorCreateError> {
+        if got != self.dims() {
+            return Err(VectorCreateError::BadDimensions {
+                expected: self.dims(),
+                got,
+            });
+        }
+        Ok(())
+    }
+
+    fn decode_values(&self, codes: &[i8]) -> Vec<f32> {
+        codes
+            .iter()
+            .zip(self.offset.iter().zip(self.scale.iter()))
+            .map(|(&code, (&offset, &scale))| offset + scale * code as f32)
+            .collect()
+    }
+
+    fn query_from_values(&self, values: &[f32]) -> QuantizedQuery<'_, D> {
+        let residual = values
+            .iter()
+            .zip(self.offset.iter())
+            .map(|(&value, &offset)| value - offset)
+            .collect();
+        let scaled = values
+            .iter()
+            .zip(self.scale.iter())
+            .map(|(&value, &scale)| value * scale)
+            .collect();
+        let bias = values
+            .iter()
+            .zip(self.offset.iter())
+            .map(|(&value, &offset)| value * offset)
+            .sum();
+        let squared_norm = values.iter().map(|v| v * v).sum();
+
+        QuantizedQuery {
+            quantizer: self,
+            residual,
+            scaled,
+            bias,
+            squared_norm,
+        }
+    }
+}
+
+#[derive(Clone)]
+/// A vector of `i8` codes produced by a [ScalarQuantizer].
+///
+/// The codes only have meaning alongside the quantizer which produced them, so
+/// all distances are computed through the quantizer.
+pub struct QuantizedVector<D: Dim = XAny> {
+    codes: Vec<i8>,
+    /// The sum of the codes, used to expand the dot product of a global range.
+    sum: i32,
+    /// The squared norm of the decoded vector.
+    squared_norm: f32,
+    dims: PhantomData<D>,
+}
+
+impl<D: Dim> Debug for QuantizedVector<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.codes.is_empty() {
+            let first = self.codes.first().unwrap();
+            let last = self.codes.last().unwrap();
+
+            write!(f, "QuantizedVector([ {first} ... {last} ])")
+        } else {
+            write!(f, "QuantizedVector([])")
+        }
+    }
+}
+
+impl<D: Dim> QuantizedVector<D> {
+    #[inline]
+    /// Returns the `i8` codes of the vector, each in the range `-127..=127`.
+    pub fn codes(&self) -> &[i8] {
+        &self.codes
+    }
+
+    #[inline]
+    /// Returns the codes offset into the unsigned range `1..=255`.
+    pub fn unsigned_codes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.codes.iter().map(|&c| (c as u8) ^ 0x80)
+    }
+
+    #[inline]
+    /// Returns the number of codes in the vector.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    #[inline]
+    /// Returns if the vector contains no codes.
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+}
+
+/// A full precision query prepared by a [ScalarQuantizer] for computing
+/// asymmetric distances against quantized vectors.
+///
+/// Only the quantized side loses precision, and the distances are computed
+/// directly against the codes without decoding them.
+pub struct QuantizedQuery<'a, D: Dim = XAny> {
+    quantizer: &'a ScalarQuantizer<D>,
+    /// The query with the offset of each dimension removed.
+    residual: Vec<f32>,
+    /// The query multiplied by the scale of each dimension.
+    scaled: Vec<f32>,
+    /// The dot product of the query and the offsets.
+    bias: f32,
+    squared_norm: f32,
+}
+
+impl<'a, D: Dim> Debug for QuantizedQuery<'a, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "QuantizedQuery(quantizer={:?})", self.quantizer)
+    }
+}
+
+impl<'a, D: Dim> QuantizedQuery<'a, D> {
+    #[inline]
+    /// Computes the dot product between the query and a quantized vector.
+    pub fn dot(&self, other: &QuantizedVector<D>) -> f32 {
+        self.bias + self.quantizer.ops.f32_dot(&self.scaled, &other.codes)
+    }
+
+    #[inline]
+    /// Computes the dot product distance between the query and a quantized vector.
+    pub fn dist_dot(&self, other: &QuantizedVector<D>) -> f32 {
+        dot_distance(self.dot(other))
+    }
+
+    #[inline]
+    /// Computes the cosine distance between the query and a quantized vector.
+    pub fn dist_cosine(&self, other: &QuantizedVector<D>) -> f32 {
+        cosine_distance(self.dot(other), self.squared_norm, other.squared_norm)
+    }
+
+    #[inline]
+    /// Computes the squared Euclidean distance between the query and a quantized vector.
+    pub fn dist_squared_euclidean(&self, other: &QuantizedVector<D>) -> f32 {
+        self.quantizer.ops.f32_squared_euclidean(
+            &self.residual,
+            &self.quantizer.scale,
+            &other.codes,
+        )
+    }
+}
+
+#[inline]
+fn dot_distance(product: f32) -> f32 {
+    if product <= 0.0 {
+        1.0
+    } else {
+        1.0 - product
+    }
+}
+
+#[inline]
+fn cosine_distance(product: f32, norm_x: f32, norm_y: f32) -> f32 {
+    if norm_x == 0.0 && norm_y == 0.0 {
+        0.0
+    } else if norm_x == 0.0 || norm_y == 0.0 {
+        1.0
+    } else {
+        1.0 - (product / (norm_x * norm_y).sqrt())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// The int8 routines selected at runtime based on the available CPU features.
+enum SelectedInt8 {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+    Avx512Vnni,
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+    Avx512,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2,
+    Fallback,
+}
+
+impl Default for SelectedInt8 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn default() -> Self {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+            if is_x86_feature_detected!("avx512vnni") {
+                return Self::Avx512Vnni;
+            }
+            return Self::Avx512;
+        }
+
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return Self::Avx2;
+        }
+
+        Self::Fallback
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    fn default() -> Self {
+        Self::Fallback
+    }
+}
+
+impl SelectedInt8 {
+    #[inline]
+    fn dot(&self, x: &[i8], y: &[i8]) -> i32 {
+        assert_eq!(x.len(), y.len(), "Quantized vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512Vnni => i8_xany_avx512vnni_dot(x, y),
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512 => i8_xany_avx512_dot(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Avx2 => i8_xany_avx2_dot(x, y),
+                Self::Fallback => i8_xany_fallback_dot(x, y),
+            }
+        }
+    }
+
+    #[inline]
+    fn squared_euclidean(&self, x: &[i8], y: &[i8]) -> u32 {
+        assert_eq!(x.len(), y.len(), "Quantized vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512Vnni => i8_xany_avx512vnni_squared_euclidean(x, y),
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512 => i8_xany_avx512_squared_euclidean(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Avx2 => i8_xany_avx2_squared_euclidean(x, y),
+                Self::Fallback => i8_xany_fallback_squared_euclidean(x, y),
+            }
+        }
+    }
+
+    #[inline]
+    fn f32_dot(&self, x: &[f32], y: &[i8]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Quantized vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512Vnni | Self::Avx512 => f32_i8_xany_avx512_fma_dot(x, y),
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Avx2 => f32_i8_xany_avx2_fma_dot(x, y),
+                Self::Fallback => f32_i8_xany_fallback_nofma_dot(x, y),
+            }
+        }
+    }
+
+    #[inline]
+    fn f32_squared_euclidean(&self, x: &[f32], scale: &[f32], y: &[i8]) -> f32 {
+        assert_eq!(x.len(), y.len(), "Quantized vectors must match in size");
+        assert_eq!(x.len(), scale.len(), "Quantized vectors must match in size");
+
+        unsafe {
+            match self {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                Self::Avx512Vnni | Self::Avx512 => {
+                    f32_i8_xany_avx512_fma_squared_euclidean(x, scale, y)
+                },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Self::Avx2 => f32_i8_xany_avx2_fma_squared_euclidean(x, scale, y),
+                Self::Fallback => {
+                    f32_i8_xany_fallback_nofma_squared_euclidean(x, scale, y)
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_relative_close,
+        get_i8_sample_codes,
+        get_sample_vectors,
+        simple_cosine,
+        simple_dot,
+        simple_euclidean,
+    };
+    use crate::{Auto, X1024};
+
+    fn sample_vectors(n: usize) -> Vec<Vector<X1024, Auto>> {
+        (0..n)
+            .map(|i| {
+                let (x, y) = get_sample_vectors::<f32>(1024);
+                // Shift each dimension differently so the per-dimension ranges vary.
+                let values = x
+                    .iter()
+                    .zip(y.iter())
+                    .enumerate()
+                    .map(|(d, (&x, &y))| (x + y * i as f32) * (1.0 + d as f32 / 256.0))
+                    .collect();
+                Vector::try_from_vec(values).unwrap()
+            })
+            .collect()
+    }
+
+    fn all_ops() -> Vec<SelectedInt8> {
+        #[allow(unused_mut)]
+        let mut ops = vec![SelectedInt8::Fallback];
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            ops.push(SelectedInt8::Avx2);
+        }
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "nightly"
+        ))]
+        if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+            ops.push(SelectedInt8::Avx512);
+
+            if is_x86_feature_detected!("avx512vnni") {
+                ops.push(SelectedInt8::Avx512Vnni);
+            }
+        }
+
+        ops
+    }
+
+    #[test]
+    fn test_int8_kernels() {
+        for len in [0, 1, 31, 64, 537, 1024] {
+            let (x, y) = get_i8_sample_codes(len);
+            let (query, scale) = get_sample_vectors::<f32>(len);
+
+            let expected = SelectedInt8::Fallback;
+            for ops in all_ops() {
+                assert_eq!(ops.dot(&x, &y), expected.dot(&x, &y), "{ops:?}");
+                assert_eq!(
+                    ops.squared_euclidean(&x, &y),
+                    expected.squared_euclidean(&x, &y),
+                    "{ops:?}"
+                );
+                assert_is_relative_close(
+                    ops.f32_dot(&query, &y),
+                    expected.f32_dot(&query, &y),
+                );
+                assert_is_relative_close(
+                    ops.f32_squared_euclidean(&query, &scale, &y),
+                    expected.f32_squared_euclidean(&query, &scale, &y),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_train_errors() {
+        let err = ScalarQuantizer::<XAny>::train::<Auto>(&[], QuantizerRange::Global)
+            .unwrap_err();
+        assert!(matches!(err, QuantizerTrainError::EmptySample));
+
+        let sample = vec![
+            Vector::<XAny, Auto>::try_from_vec(vec![1.0; 4]).unwrap(),
+            Vector::<XAny, Auto>::try_from_vec(vec![1.0; 3]).unwrap(),
+        ];
+        let err = ScalarQuantizer::train(&sample, QuantizerRange::Global).unwrap_err();
+        assert!(matches!(
+            err,
+            QuantizerTrainError::BadDimensions {
+                expected: 4,
+                got: 3
+            }
+        ));
+
+        let sample =
+            vec![Vector::<XAny, Auto>::try_from_vec(vec![f32::NAN; 4]).unwrap()];
+        let err =
+            ScalarQuantizer::train(&sample, QuantizerRange::PerDimension).unwrap_err();
+        assert!(matches!(err, QuantizerTrainError::NonFinite));
+    }
+
+    #[test]
+    fn test_encode_bad_dimensions() {
+        let sample = vec![Vector::<XAny, Auto>::try_from_vec(vec![1.0, 2.0]).unwrap()];
+        let quantizer = ScalarQuantizer::train(&sample, QuantizerRange::Global).unwrap();
+
+        let vector = Vector::<XAny, Auto>::try_from_vec(vec![1.0; 3]).unwrap();
+        assert!(matches!(
+            quantizer.encode(&vector),
+            Err(VectorCreateError::BadDimensions {
+                expected: 2,
+                got: 3
+            })
+        ));
+        assert!(quantizer.query(&vector).is_err());
+    }
+
+    #[test]
+    fn test_constant_dimensions() {
+        let sample = vec![
+            Vector::<XAny, Auto>::try_from_vec(vec![3.0, 0.0]).unwrap(),
+            Vector::<XAny, Auto>::try_from_vec(vec![3.0, 1.0]).unwrap(),
+        ];
+        let quantizer =
+            ScalarQuantizer::train(&sample, QuantizerRange::PerDimension).unwrap();
+
+        let codes = quantizer.encode(&sample[1]).unwrap();
+        let decoded: Vector<XAny, Auto> = quantizer.decode(&codes);
+        assert_eq!(decoded.as_ref(), &[3.0, 1.0]);
+        assert_eq!(codes.codes(), &[0, 127]);
+        assert_eq!(codes.unsigned_codes().collect::<Vec<_>>(), vec![128, 255]);
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let sample = sample_vectors(8);
+
+        for range in [QuantizerRange::Global, QuantizerRange::PerDimension] {
+            let quantizer = ScalarQuantizer::train(&sample, range).unwrap();
+            assert_eq!(quantizer.range(), range);
+            assert_eq!(quantizer.dims(), 1024);
+
+            for vector in &sample {
+                let codes = quantizer.encode(vector).unwrap();
+                assert_eq!(codes.len(), 1024);
+                assert!(codes.codes().iter().all(|&c| c != i8::MIN));
+
+                let decoded: Vector<X1024, Auto> = quantizer.decode(&codes);
+                for i in 0..1024 {
+                    let error = (decoded[i] - vector[i]).abs();
+                    assert!(error <= quantizer.scale[i] / 2.0 + 1e-5, "{range:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantized_distances() {
+        let sample = sample_vectors(8);
+
+        for range in [QuantizerRange::Global, QuantizerRange::PerDimension] {
+            let mut quantizer = ScalarQuantizer::train(&sample, range).unwrap();
+
+            for ops in all_ops() {
+                quantizer.ops = ops;
+
+                let x = quantizer.encode(&sample[1]).unwrap();
+                let y = quantizer.encode(&sample[5]).unwrap();
+                let x_decoded: Vector<X1024, Auto> = quantizer.decode(&x);
+                let y_decoded: Vector<X1024, Auto> = quantizer.decode(&y);
+
+                assert_is_relative_close(
+                    quantizer.dot(&x, &y),
+                    simple_dot(&x_decoded, &y_decoded),
+                );
+                assert_is_relative_close(
+                    quantizer.dist_squared_euclidean(&x, &y),
+                    simple_euclidean(&x_decoded, &y_decoded),
+                );
+                assert_is_relative_close(
+                    quantizer.dist_cosine(&x, &y),
+                    simple_cosine(&x_decoded, &y_decoded),
+                );
+                assert_eq!(
+                    quantizer.dist_dot(&x, &y),
+                    dot_distance(quantizer.dot(&x, &y))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_asymmetric_distances() {
+        let sample = sample_vectors(8);
+        let query_vector = &sample[3];
+
+        for range in [QuantizerRange::Global, QuantizerRange::PerDimension] {
+            let mut quantizer = ScalarQuantizer::train(&sample, range).unwrap();
+
+            for ops in all_ops() {
+                quantizer.ops = ops;
+
+                let y = quantizer.encode(&sample[6]).unwrap();
+                let y_decoded: Vector<X1024, Auto> = quantizer.decode(&y);
+                let query = quantizer.query(query_vector).unwrap();
+
+                assert_is_relative_close(
+                    query.dot(&y),
+                    simple_dot(query_vector, &y_decoded),
+                );
+                assert_is_relative_close(
+                    query.dist_squared_euclidean(&y),
+                    simple_euclidean(query_vector, &y_decoded),
+                );
+                assert_is_relative_close(
+                    query.dist_cosine(&y),
+                    simple_cosine(query_vector, &y_decoded),
+                );
+            }
+        }
+    }
+}
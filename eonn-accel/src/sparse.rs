@@ -0,0 +1,632 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::danger::*;
+use crate::math::AutoMath;
+use crate::ops::SpacialOps;
+
+#[derive(Debug)]
+/// An error that occurs while attempting to safely create a new [SparseVector].
+pub enum SparseVectorCreateError {
+    /// The number of indices does not match the number of values.
+    MismatchedLengths { indices: usize, values: usize },
+    /// The indices are not sorted in strictly ascending order.
+    UnsortedIndices,
+    /// An index is outside of the vector's dimensions.
+    IndexOutOfBounds { index: u32, dims: usize },
+    /// The provided vector contains some non-finite values or Nan.
+    NonFinite,
+    /// The CSR row pointers do not describe a valid set of rows.
+    MalformedIndptr,
+}
+
+impl Display for SparseVectorCreateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MismatchedLengths { indices, values } => {
+                write!(
+                    f,
+                    "Mismatched Lengths: got {indices} indices but {values} values"
+                )
+            },
+            Self::UnsortedIndices => {
+                write!(
+                    f,
+                    "Unsorted Indices: indices must be strictly ascending and unique"
+                )
+            },
+            Self::IndexOutOfBounds { index, dims } => {
+                write!(
+                    f,
+                    "Index Out Of Bounds: index {index} is outside of {dims} dimensions"
+                )
+            },
+            Self::NonFinite => {
+                write!(f, "Non-Finite Value: values in vector must be finite")
+            },
+            Self::MalformedIndptr => {
+                write!(
+                    f,
+                    "Malformed Indptr: row pointers must start at 0, never decrease \
+                    and end at the number of values"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for SparseVectorCreateError {}
+
+#[derive(Clone)]
+/// A sparse vector of `f32` values stored as its sorted non-zero indices and the
+/// matching values.
+///
+/// Unlike [Vector](crate::Vector) the dimensions are only known at runtime and
+/// every distance is computed by walking the non-zero entries of both vectors,
+/// so the cost scales with the number of non-zero values rather than the
+/// dimensions.
+///
+/// The hyperplanes produced when splitting sparse vectors only contain the
+/// union of the non-zero indices of the two points, so they remain sparse.
+pub struct SparseVector {
+    indices: Vec<u32>,
+    values: Vec<f32>,
+    dims: usize,
+}
+
+impl Debug for SparseVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SparseVector(dims={}, nnz={}, {:?})",
+            self.dims,
+            self.values.len(),
+            self.indices.iter().zip(&self.values).collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl SparseVector {
+    /// Attempt to create a new sparse vector with `dims` dimensions from its
+    /// non-zero `indices` and the matching `values`.
+    ///
+    /// The indices must be strictly ascending and less than `dims`, any values
+    /// which are explicitly `0.0` are dropped.
+    pub fn try_from_parts(
+        dims: usize,
+        mut indices: Vec<u32>,
+        mut values: Vec<f32>,
+    ) -> Result<Self, SparseVectorCreateError> {
+        if indices.len() != values.len() {
+            return Err(SparseVectorCreateError::MismatchedLengths {
+                indices: indices.len(),
+                values: values.len(),
+            });
+        }
+
+        if indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(SparseVectorCreateError::UnsortedIndices);
+        }
+
+        if let Some(&index) = indices.last() {
+            if index as usize >= dims {
+                return Err(SparseVectorCreateError::IndexOutOfBounds { index, dims });
+            }
+        }
+
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(SparseVectorCreateError::NonFinite);
+        }
+
+        if values.contains(&0.0) {
+            let mut n = 0;
+            for i in 0..values.len() {
+                if values[i] != 0.0 {
+                    indices[n] = indices[i];
+                    values[n] = values[i];
+                    n += 1;
+                }
+            }
+            indices.truncate(n);
+            values.truncate(n);
+        }
+
+        Ok(Self {
+            indices,
+            values,
+            dims,
+        })
+    }
+
+    /// Attempt to create a new sparse vector from the non-zero values of a dense
+    /// vector.
+    pub fn try_from_dense(data: &[f32]) -> Result<Self, SparseVectorCreateError> {
+        let (indices, values) = data
+            .iter()
+            .enumerate()
+            .filter(|(_, &v)| v != 0.0)
+            .map(|(i, &v)| (i as u32, v))
+            .unzip();
+
+        Self::try_from_parts(data.len(), indices, values)
+    }
+
+    /// Attempt to create one sparse vector per row of a matrix in compressed sparse
+    /// row (CSR) format with `dims` columns.
+    ///
+    /// Row `i` is made up of the `indices` and `values` within
+    /// `indptr[i]..indptr[i + 1]`, so `indptr` must have one more entry than the
+    /// number of rows.
+    pub fn try_from_csr(
+        dims: usize,
+        indptr: &[usize],
+        indices: &[u32],
+        values: &[f32],
+    ) -> Result<Vec<Self>, SparseVectorCreateError> {
+        if indices.len() != values.len() {
+            return Err(SparseVectorCreateError::MismatchedLengths {
+                indices: indices.len(),
+                values: values.len(),
+            });
+        }
+
+        if indptr.first() != Some(&0)
+            || indptr.last() != Some(&values.len())
+            || indptr.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(SparseVectorCreateError::MalformedIndptr);
+        }
+
+        indptr
+            .windows(2)
+            .map(|row| {
+                Self::try_from_parts(
+                    dims,
+                    indices[row[0]..row[1]].to_vec(),
+                    values[row[0]..row[1]].to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    #[inline]
+    /// Returns the sorted indices of the non-zero values.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    #[inline]
+    /// Returns the non-zero values, matching the order of the indices.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    #[inline]
+    /// Returns the number of non-zero values.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    fn sum(&self) -> f32 {
+        self.values.iter().sum()
+    }
+
+    /// Iterates over the union of the non-zero indices of self and another vector,
+    /// yielding the pair of values at each index.
+    fn union<'a>(&'a self, other: &'a Self) -> Union<'a> {
+        Union {
+            x: self,
+            y: other,
+            i: 0,
+            j: 0,
+        }
+    }
+
+    /// Builds a new vector from the values yielded at each index, skipping any
+    /// which are `0.0`.
+    fn from_union(dims: usize, entries: impl Iterator<Item = (u32, f32)>) -> Self {
+        let (indices, values) = entries.filter(|(_, v)| *v != 0.0).unzip();
+        Self {
+            indices,
+            values,
+            dims,
+        }
+    }
+}
+
+/// A merge-join over the non-zero indices of two sparse vectors.
+struct Union<'a> {
+    x: &'a SparseVector,
+    y: &'a SparseVector,
+    i: usize,
+    j: usize,
+}
+
+impl<'a> Iterator for Union<'a> {
+    type Item = (u32, f32, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x_idx = self.x.indices.get(self.i).copied();
+        let y_idx = self.y.indices.get(self.j).copied();
+
+        match (x_idx, y_idx) {
+            (Some(x_idx), Some(y_idx)) if x_idx == y_idx => {
+                let item = (x_idx, self.x.values[self.i], self.y.values[self.j]);
+                self.i += 1;
+                self.j += 1;
+                Some(item)
+            },
+            (Some(x_idx), y_idx) if y_idx.is_none_or(|y_idx| x_idx < y_idx) => {
+                self.i += 1;
+                Some((x_idx, self.x.values[self.i - 1], 0.0))
+            },
+            (_, Some(y_idx)) => {
+                self.j += 1;
+                Some((y_idx, 0.0, self.y.values[self.j - 1]))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl SpacialOps for SparseVector {
+    fn len(&self) -> usize {
+        self.dims
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        unsafe {
+            f32_sparse_fallback_nofma_dot(
+                &self.indices,
+                &self.values,
+                &other.indices,
+                &other.values,
+            )
+        }
+    }
+
+    fn squared_norm(&self) -> f32 {
+        self.values.iter().map(|v| v * v).sum()
+    }
+
+    fn normalize(&mut self) {
+        let norm_self = self.squared_norm().sqrt();
+        for value in self.values.iter_mut() {
+            *value /= norm_self;
+        }
+    }
+
+    fn dist_dot(&self, other: &Self) -> f32 {
+        let product = self.dot(other);
+
+        if product <= 0.0 {
+            1.0
+        } else {
+            1.0 - product
+        }
+    }
+
+    fn dist_cosine(&self, other: &Self) -> f32 {
+        cosine::<f32, AutoMath>(
+            self.dot(other),
+            self.squared_norm(),
+            other.squared_norm(),
+        )
+    }
+
+    fn dist_squared_euclidean(&self, other: &Self) -> f32 {
+        unsafe {
+            f32_sparse_fallback_nofma_euclidean(
+                &self.indices,
+                &self.values,
+                &other.indices,
+                &other.values,
+            )
+        }
+    }
+
+    fn dist_manhattan(&self, other: &Self) -> f32 {
+        self.union(other).map(|(_, x, y)| (x - y).abs()).sum()
+    }
+
+    fn dist_chebyshev(&self, other: &Self) -> f32 {
+        self.union(other)
+            .map(|(_, x, y)| (x - y).abs())
+            .fold(0.0, f32::max)
+    }
+
+    fn dist_hamming(&self, other: &Self) -> f32 {
+        self.union(other).filter(|(_, x, y)| x != y).count() as f32
+    }
+
+    fn dist_jaccard(&self, other: &Self) -> f32 {
+        unsafe { f32_sparse_fallback_nofma_jaccard(&self.indices, &other.indices) }
+    }
+
+    fn dist_minkowski(&self, other: &Self, p: f32) -> f32 {
+        if p == 1.0 {
+            self.dist_manhattan(other)
+        } else if p == 2.0 {
+            self.dist_squared_euclidean(other).sqrt()
+        } else if p == f32::INFINITY {
+            self.dist_chebyshev(other)
+        } else {
+            self.union(other)
+                .map(|(_, x, y)| (x - y).abs().powf(p))
+                .sum::<f32>()
+                .powf(p.recip())
+        }
+    }
+
+    fn dist_weighted_squared_euclidean(&self, other: &Self, weights: &[f32]) -> f32 {
+        assert_eq!(
+            weights.len(),
+            self.dims,
+            "Lengths of the vector and weights must be equal"
+        );
+
+        self.union(other)
+            .map(|(i, x, y)| weights[i as usize] * (x - y) * (x - y))
+            .sum()
+    }
+
+    fn dist_haversine(&self, other: &Self) -> f32 {
+        assert_eq!(self.dims, 2, "Haversine requires 2 dimensional vectors");
+        assert_eq!(other.dims, 2, "Haversine requires 2 dimensional vectors");
+
+        let to_dense = |v: &Self| {
+            let mut dense = [0.0; 2];
+            for (&i, &value) in v.indices.iter().zip(&v.values) {
+                dense[i as usize] = value;
+            }
+            dense
+        };
+
+        unsafe { f32_x2_fallback_nofma_haversine(&to_dense(self), &to_dense(other)) }
+    }
+
+    /// The zero entries still contribute to the means, so the centred vectors are
+    /// never built and the covariance is derived from the dot product instead.
+    fn dist_correlation(&self, other: &Self) -> f32 {
+        let n = self.dims as f32;
+        let mean_x = self.sum() / n;
+        let mean_y = other.sum() / n;
+
+        let covariance = self.dot(other) - (n * mean_x * mean_y);
+        let variance_x = self.squared_norm() - (n * mean_x * mean_x);
+        let variance_y = other.squared_norm() - (n * mean_y * mean_y);
+
+        cosine::<f32, AutoMath>(covariance, variance_x, variance_y)
+    }
+
+    fn dist_hellinger(&self, other: &Self) -> f32 {
+        let sqrt_product_sum = self
+            .union(other)
+            .map(|(_, x, y)| (x * y).sqrt())
+            .sum::<f32>();
+        hellinger::<f32, AutoMath>(sqrt_product_sum, self.sum(), other.sum())
+    }
+
+    /// Every index outside of the union of the non-zero entries contributes the
+    /// same divergence, so it is computed once and weighted by their count.
+    fn dist_jensen_shannon(&self, other: &Self) -> f32 {
+        let n = self.dims as f32;
+        let l1_x = self.sum() + (f32::EPSILON * n);
+        let l1_y = other.sum() + (f32::EPSILON * n);
+        let divergence = |x: f32, y: f32| {
+            let px = (x + f32::EPSILON) / l1_x;
+            let py = (y + f32::EPSILON) / l1_y;
+            let m = 0.5 * (px + py);
+            0.5 * (px * (px / m).ln() + py * (py / m).ln())
+        };
+
+        let mut n_union = 0;
+        let mut result = 0.0;
+        for (_, x, y) in self.union(other) {
+            result += divergence(x, y);
+            n_union += 1;
+        }
+
+        result + ((self.dims - n_union) as f32 * divergence(0.0, 0.0))
+    }
+
+    fn angular_hyperplane(&self, other: &Self) -> Self {
+        let mut norm_x = self.squared_norm().sqrt();
+        if norm_x.abs() < f32::EPSILON {
+            norm_x = 1.0;
+        }
+
+        let mut norm_y = other.squared_norm().sqrt();
+        if norm_y.abs() < f32::EPSILON {
+            norm_y = 1.0;
+        }
+
+        let mut hyperplane = Self::from_union(
+            self.dims,
+            self.union(other)
+                .map(|(i, x, y)| (i, (x / norm_x) - (y / norm_y))),
+        );
+
+        let mut norm_hyperplane = hyperplane.squared_norm().sqrt();
+        if norm_hyperplane.abs() < f32::EPSILON {
+            norm_hyperplane = 1.0;
+        }
+
+        for value in hyperplane.values.iter_mut() {
+            *value /= norm_hyperplane;
+        }
+
+        hyperplane
+    }
+
+    fn euclidean_hyperplane(&self, other: &Self) -> (Self, f32) {
+        let mut offset = 0.0;
+        let hyperplane = Self::from_union(
+            self.dims,
+            self.union(other).map(|(i, x, y)| {
+                let diff = x - y;
+                offset -= diff * ((x + y) * 0.5);
+                (i, diff)
+            }),
+        );
+
+        (hyperplane, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sparse_sample_vectors, SparseSample};
+    use crate::{Fallback, Vector, XAny};
+
+    /// A sparse vector and the equivalent dense vector.
+    type Pair = (SparseVector, Vector<XAny, Fallback>);
+
+    fn sample_vectors() -> (Pair, Pair) {
+        let (x, y) = get_sparse_sample_vectors(537, 0.1);
+        let to_pair = |(indices, values, dense): SparseSample| {
+            let sparse = SparseVector::try_from_parts(537, indices, values).unwrap();
+            let dense = Vector::<XAny, Fallback>::try_from_vec(dense).unwrap();
+            (sparse, dense)
+        };
+        (to_pair(x), to_pair(y))
+    }
+
+    #[test]
+    fn test_sparse_vector_create() {
+        let vector =
+            SparseVector::try_from_parts(10, vec![1, 4, 7], vec![1.0, 0.0, 2.0])
+                .unwrap();
+        assert_eq!(vector.len(), 10);
+        assert_eq!(vector.indices(), &[1, 7]);
+        assert_eq!(vector.values(), &[1.0, 2.0]);
+
+        let vector = SparseVector::try_from_dense(&[0.0, 3.0, 0.0, 4.0]).unwrap();
+        assert_eq!(vector.len(), 4);
+        assert_eq!(vector.indices(), &[1, 3]);
+        assert_eq!(vector.nnz(), 2);
+
+        assert!(matches!(
+            SparseVector::try_from_parts(10, vec![1, 2], vec![1.0]),
+            Err(SparseVectorCreateError::MismatchedLengths { .. })
+        ));
+        assert!(matches!(
+            SparseVector::try_from_parts(10, vec![2, 2], vec![1.0, 1.0]),
+            Err(SparseVectorCreateError::UnsortedIndices)
+        ));
+        assert!(matches!(
+            SparseVector::try_from_parts(10, vec![3, 1], vec![1.0, 1.0]),
+            Err(SparseVectorCreateError::UnsortedIndices)
+        ));
+        assert!(matches!(
+            SparseVector::try_from_parts(10, vec![10], vec![1.0]),
+            Err(SparseVectorCreateError::IndexOutOfBounds {
+                index: 10,
+                dims: 10
+            })
+        ));
+        assert!(matches!(
+            SparseVector::try_from_parts(10, vec![1], vec![f32::NAN]),
+            Err(SparseVectorCreateError::NonFinite)
+        ));
+    }
+
+    #[test]
+    fn test_sparse_vector_from_csr() {
+        let rows =
+            SparseVector::try_from_csr(5, &[0, 2, 2, 3], &[0, 4, 1], &[1.0, 2.0, 3.0])
+                .unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].indices(), &[0, 4]);
+        assert_eq!(rows[1].nnz(), 0);
+        assert_eq!(rows[2].values(), &[3.0]);
+
+        for indptr in [&[1, 3][..], &[0, 2], &[0, 3, 1, 3], &[]] {
+            assert!(matches!(
+                SparseVector::try_from_csr(5, indptr, &[0, 4, 1], &[1.0, 2.0, 3.0]),
+                Err(SparseVectorCreateError::MalformedIndptr)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sparse_vector_distances() {
+        let ((x, x_dense), (y, y_dense)) = sample_vectors();
+
+        assert_is_close(x.dot(&y), x_dense.dot(&y_dense));
+        assert_is_close(x.squared_norm(), x_dense.squared_norm());
+        assert_is_close(x.dist_dot(&y), x_dense.dist_dot(&y_dense));
+        assert_is_close(x.dist_cosine(&y), x_dense.dist_cosine(&y_dense));
+        assert_is_close(
+            x.dist_squared_euclidean(&y),
+            x_dense.dist_squared_euclidean(&y_dense),
+        );
+        assert_is_close(x.dist_manhattan(&y), x_dense.dist_manhattan(&y_dense));
+        assert_is_close(x.dist_chebyshev(&y), x_dense.dist_chebyshev(&y_dense));
+        assert_eq!(x.dist_hamming(&y), x_dense.dist_hamming(&y_dense));
+        assert_is_close(x.dist_jaccard(&y), x_dense.dist_jaccard(&y_dense));
+        for p in [0.5, 1.0, 2.0, 3.0, f32::INFINITY] {
+            assert_is_close(
+                x.dist_minkowski(&y, p),
+                x_dense.dist_minkowski(&y_dense, p),
+            );
+        }
+        let weights = (0..537).map(|i| (i % 7) as f32 * 0.5).collect::<Vec<_>>();
+        assert_is_close(
+            x.dist_weighted_squared_euclidean(&y, &weights),
+            x_dense.dist_weighted_squared_euclidean(&y_dense, &weights),
+        );
+        assert_is_close(x.dist_correlation(&y), x_dense.dist_correlation(&y_dense));
+        assert_is_close(x.dist_hellinger(&y), x_dense.dist_hellinger(&y_dense));
+        assert_is_close(
+            x.dist_jensen_shannon(&y),
+            x_dense.dist_jensen_shannon(&y_dense),
+        );
+    }
+
+    #[test]
+    fn test_sparse_vector_haversine() {
+        let x = SparseVector::try_from_dense(&[0.5, 0.0]).unwrap();
+        let y = SparseVector::try_from_dense(&[0.1, -1.2]).unwrap();
+        let x_dense = Vector::<XAny, Fallback>::try_from_vec(vec![0.5, 0.0]).unwrap();
+        let y_dense = Vector::<XAny, Fallback>::try_from_vec(vec![0.1, -1.2]).unwrap();
+        assert_is_close(x.dist_haversine(&y), x_dense.dist_haversine(&y_dense));
+    }
+
+    #[test]
+    #[should_panic(expected = "Haversine requires 2 dimensional vectors")]
+    fn test_sparse_vector_haversine_bad_dimensions() {
+        let x = SparseVector::try_from_dense(&[0.5, 0.0, 1.0]).unwrap();
+        x.dist_haversine(&x);
+    }
+
+    #[test]
+    fn test_sparse_vector_hyperplanes() {
+        let ((x, x_dense), (y, y_dense)) = sample_vectors();
+
+        let hyperplane = x.angular_hyperplane(&y);
+        let expected = x_dense.angular_hyperplane(&y_dense);
+        // The hyperplane only covers the non-zero entries of either point.
+        assert!(hyperplane.nnz() <= x.nnz() + y.nnz());
+        assert_is_close(hyperplane.dot(&x), expected.dot(&x_dense));
+        assert_is_close(hyperplane.dot(&y), expected.dot(&y_dense));
+        assert_is_close(hyperplane.squared_norm(), expected.squared_norm());
+
+        let (hyperplane, offset) = x.euclidean_hyperplane(&y);
+        let (expected, expected_offset) = x_dense.euclidean_hyperplane(&y_dense);
+        assert!(hyperplane.nnz() <= x.nnz() + y.nnz());
+        assert_is_close(offset, expected_offset);
+        assert_is_close(hyperplane.dot(&x), expected.dot(&x_dense));
+        assert_is_close(hyperplane.squared_norm(), expected.squared_norm());
+    }
+
+    #[test]
+    fn test_sparse_vector_normalize() {
+        let ((mut x, mut x_dense), _) = sample_vectors();
+        x.normalize();
+        x_dense.normalize();
+        assert_is_close(x.squared_norm(), 1.0);
+        assert_is_close(x.dot(&x), x_dense.dot(&x_dense));
+    }
+}
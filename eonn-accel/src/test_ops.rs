@@ -4,13 +4,25 @@ use crate::ops::*;
 use crate::test_utils::{
     assert_is_close,
     assert_is_close_vector,
+    assert_is_close_vector_f64,
+    get_half_sample_vectors,
     get_sample_vectors,
     simple_angular_hyperplane,
+    simple_chebyshev,
+    simple_correlation,
     simple_cosine,
     simple_dot,
     simple_euclidean,
     simple_euclidean_hyperplane,
+    simple_haversine,
+    simple_hellinger,
+    simple_jensen_shannon,
+    simple_manhattan,
+    simple_minkowski,
+    simple_weighted_euclidean,
+    to_f32_vector,
 };
+use crate::types::{bf16, f16, widen};
 use crate::vector::{Vector, VectorCreateError};
 
 #[test]
@@ -30,6 +42,34 @@ fn test_vector_length_miss_match() {
     assert!(matches!(x, VectorCreateError::BadDimensions { .. }));
 }
 
+#[test]
+fn test_vector_dist_haversine() {
+    let points = [[0.0, 0.0], [0.5, -1.2], [-0.9, 3.1], [1.4, -3.0]];
+
+    for x in points.iter() {
+        for y in points.iter() {
+            let expected = simple_haversine(x, y);
+
+            let x2 = Vector::<X2, Auto, f32>::try_from_vec(x.to_vec()).unwrap();
+            let y2 = Vector::<X2, Auto, f32>::try_from_vec(y.to_vec()).unwrap();
+            assert_is_close(x2.dist_haversine(&y2), expected);
+
+            let x_any = Vector::<XAny, Auto, f32>::try_from_vec(x.to_vec()).unwrap();
+            let y_any = Vector::<XAny, Auto, f32>::try_from_vec(y.to_vec()).unwrap();
+            assert_is_close(x_any.dist_haversine(&y_any), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Haversine requires 2 dimensional vectors")]
+fn test_vector_dist_haversine_bad_dimensions() {
+    let (x, y) = get_sample_vectors(3);
+    let x = Vector::<XAny, Auto, f32>::try_from_vec(x).unwrap();
+    let y = Vector::<XAny, Auto, f32>::try_from_vec(y).unwrap();
+    x.dist_haversine(&y);
+}
+
 macro_rules! define_vector_op_test_suite {
     (
         suite_name = $name:ident,
@@ -84,6 +124,84 @@ macro_rules! define_vector_op_test_suite {
                     assert_is_close(res, simple_euclidean(x.as_ref(), y.as_ref()));
                 }
 
+                #[test]
+                fn [<test_vector_ $name _dist_manhattan>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_manhattan(&y);
+                    assert_is_close(res, simple_manhattan(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_chebyshev>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_chebyshev(&y);
+                    assert_is_close(res, simple_chebyshev(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_minkowski>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_minkowski(&y, 3.0);
+                    assert_is_close(res, simple_minkowski(x.as_ref(), y.as_ref(), 3.0));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_weighted_squared_euclidean>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let (w, _) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_weighted_squared_euclidean(&y, &w);
+                    assert_is_close(res, simple_weighted_euclidean(x.as_ref(), y.as_ref(), &w));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_correlation>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_correlation(&y);
+                    assert_is_close(res, simple_correlation(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_hellinger>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_hellinger(&y);
+                    assert_is_close(res, simple_hellinger(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_jensen_shannon>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_jensen_shannon(&y);
+                    assert_is_close(res, simple_jensen_shannon(x.as_ref(), y.as_ref()));
+                }
+
                 #[test]
                 fn [<test_vector_ $name _squared_norm>]() {
                     let (x, _) = get_sample_vectors($len);
@@ -273,8 +391,16 @@ define_vector_op_test_suite!(
     arch = Fallback,
     tp = f32,
 );
+define_vector_op_test_suite!(
+    suite_name = f32_x2_fallback_nofma,
+    dim = X2,
+    len = 2,
+    arch = Fallback,
+    tp = f32,
+);
 
 // AVX2 routines wo/fma
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 define_vector_op_test_suite!(
     suite_name = f32_x1024_avx2_nofma,
     dim = X1024,
@@ -282,6 +408,7 @@ define_vector_op_test_suite!(
     arch = Avx2,
     tp = f32,
 );
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 define_vector_op_test_suite!(
     suite_name = f32_x768_avx2_nofma,
     dim = X768,
@@ -289,6 +416,7 @@ define_vector_op_test_suite!(
     arch = Avx2,
     tp = f32,
 );
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 define_vector_op_test_suite!(
     suite_name = f32_x512_avx2_nofma,
     dim = X512,
@@ -296,6 +424,7 @@ define_vector_op_test_suite!(
     arch = Avx2,
     tp = f32,
 );
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 define_vector_op_test_suite!(
     suite_name = f32_xany_avx2_nofma,
     dim = XAny,
@@ -388,6 +517,58 @@ define_vector_op_test_suite!(
     tp = f32,
 );
 
+// NEON routines
+#[cfg(target_arch = "aarch64")]
+define_vector_op_test_suite!(
+    suite_name = f32_x1024_neon,
+    dim = X1024,
+    len = 1024,
+    arch = Neon,
+    tp = f32,
+);
+#[cfg(target_arch = "aarch64")]
+define_vector_op_test_suite!(
+    suite_name = f32_x768_neon,
+    dim = X768,
+    len = 768,
+    arch = Neon,
+    tp = f32,
+);
+#[cfg(target_arch = "aarch64")]
+define_vector_op_test_suite!(
+    suite_name = f32_x512_neon,
+    dim = X512,
+    len = 512,
+    arch = Neon,
+    tp = f32,
+);
+#[cfg(target_arch = "aarch64")]
+define_vector_op_test_suite!(
+    suite_name = f32_xany_neon,
+    dim = XAny,
+    len = 537,
+    arch = Neon,
+    tp = f32,
+);
+
+// SVE routines
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_vector_op_test_suite!(
+    suite_name = f32_x1024_sve,
+    dim = X1024,
+    len = 1024,
+    arch = Sve,
+    tp = f32,
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_vector_op_test_suite!(
+    suite_name = f32_xany_sve,
+    dim = XAny,
+    len = 537,
+    arch = Sve,
+    tp = f32,
+);
+
 macro_rules! define_vector_op_auto_arch_test_suite {
     (
         suite_name = $name:ident,
@@ -450,6 +631,98 @@ macro_rules! define_vector_op_auto_arch_test_suite {
                     assert_is_close(res, simple_euclidean(x.as_ref(), y.as_ref()));
                 }
 
+                #[test]
+                fn [<test_vector_ $name _dist_manhattan>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_manhattan(&y);
+                    assert_is_close(res, simple_manhattan(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_chebyshev>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_chebyshev(&y);
+                    assert_is_close(res, simple_chebyshev(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_minkowski>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_minkowski(&y, 3.0);
+                    assert_is_close(res, simple_minkowski(x.as_ref(), y.as_ref(), 3.0));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_weighted_squared_euclidean>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let (w, _) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_weighted_squared_euclidean(&y, &w);
+                    assert_is_close(res, simple_weighted_euclidean(x.as_ref(), y.as_ref(), &w));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_correlation>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_correlation(&y);
+                    assert_is_close(res, simple_correlation(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_hellinger>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_hellinger(&y);
+                    assert_is_close(res, simple_hellinger(x.as_ref(), y.as_ref()));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_jensen_shannon>]() {
+                    let (x, y) = get_sample_vectors($len);
+                    let mut x = Vector::<$dim, Auto, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    x.set_ops(Auto(SelectedArch::$variant));
+                    let mut y = Vector::<$dim, Auto, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    y.set_ops(Auto(SelectedArch::$variant));
+                    let res = x.dist_jensen_shannon(&y);
+                    assert_is_close(res, simple_jensen_shannon(x.as_ref(), y.as_ref()));
+                }
+
                 #[test]
                 fn [<test_vector_ $name _squared_norm>]() {
                     let (x, _) = get_sample_vectors($len);
@@ -601,6 +874,13 @@ define_vector_op_auto_arch_test_suite!(
     variant = Fallback,
     tp = f32,
 );
+define_vector_op_auto_arch_test_suite!(
+    suite_name = f32_x2_auto_fallback_variant,
+    dim = X2,
+    len = 2,
+    variant = Fallback,
+    tp = f32,
+);
 
 // Auto select routines w/ AVX2 enabled
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -719,3 +999,592 @@ define_vector_op_auto_arch_test_suite!(
     variant = Avx512,
     tp = f32,
 );
+
+// Auto select routines w/ NEON enabled
+#[cfg(target_arch = "aarch64")]
+define_vector_op_auto_arch_test_suite!(
+    suite_name = f32_x1024_auto_neon_variant,
+    dim = X1024,
+    len = 1024,
+    variant = Neon,
+    tp = f32,
+);
+#[cfg(target_arch = "aarch64")]
+define_vector_op_auto_arch_test_suite!(
+    suite_name = f32_xauto_auto_neon_variant,
+    dim = XAny,
+    len = 537,
+    variant = Neon,
+    tp = f32,
+);
+
+// Auto select routines w/ SVE enabled
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_vector_op_auto_arch_test_suite!(
+    suite_name = f32_x1024_auto_sve_variant,
+    dim = X1024,
+    len = 1024,
+    variant = Sve,
+    tp = f32,
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_vector_op_auto_arch_test_suite!(
+    suite_name = f32_xauto_auto_sve_variant,
+    dim = XAny,
+    len = 537,
+    variant = Sve,
+    tp = f32,
+);
+
+macro_rules! define_f64_vector_op_test_suite {
+    (
+        suite_name = $name:ident,
+        dim = $dim:ident,
+        len = $len:expr,
+        arch = $arch:ident,
+    ) => {
+            paste::paste! {
+                #[test]
+                fn [<test_vector_ $name _dot>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dot(&y);
+                    assert_is_close(res, simple_dot(x.as_ref(), y.as_ref()) as f32);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_cosine>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_cosine(&y);
+                    assert_is_close(res, simple_cosine(x.as_ref(), y.as_ref()) as f32);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_squared_euclidean>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_squared_euclidean(&y);
+                    assert_is_close(res, simple_euclidean(x.as_ref(), y.as_ref()) as f32);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_manhattan>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_manhattan(&y);
+                    assert_is_close(res, simple_manhattan(x.as_ref(), y.as_ref()) as f32);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_chebyshev>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_chebyshev(&y);
+                    assert_is_close(res, simple_chebyshev(x.as_ref(), y.as_ref()) as f32);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_minkowski>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_minkowski(&y, 3.0);
+                    let expected = simple_minkowski(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                        3.0,
+                    );
+                    assert_is_close(res, expected);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_correlation>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_correlation(&y);
+                    let expected = simple_correlation(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                    );
+                    assert_is_close(res, expected);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_hellinger>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_hellinger(&y);
+                    let expected = simple_hellinger(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                    );
+                    assert_is_close(res, expected);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_jensen_shannon>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_jensen_shannon(&y);
+                    let expected = simple_jensen_shannon(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                    );
+                    assert_is_close(res, expected);
+                }
+
+                #[test]
+                fn [<test_vector_ $name _normalize>]() {
+                    let (mut expected, _) = get_sample_vectors::<f64>($len);
+                    let mut sample = Vector::<$dim, $arch, f64>::try_from_vec(expected.clone())
+                        .expect("Create vector");
+                    sample.normalize();
+
+                    let norm = simple_dot(&expected, &expected).sqrt();
+                    for v in expected.iter_mut() {
+                        *v /= norm;
+                    }
+
+                    assert_is_close_vector_f64(sample.as_ref(), expected.as_ref());
+                }
+
+                #[test]
+                fn [<test_vector_ $name _angular_hyperplane>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+
+                    let hyperplane = x.angular_hyperplane(&y);
+                    let expected_hyperplane = simple_angular_hyperplane(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                    );
+
+                    assert_is_close_vector(
+                        &to_f32_vector(hyperplane.as_ref()),
+                        expected_hyperplane.as_ref(),
+                    );
+                }
+
+                #[test]
+                fn [<test_vector_ $name _euclidean_hyperplane>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+
+                    let (hyperplane, offset) = x.euclidean_hyperplane(&y);
+                    let (expected_hyperplane, expected_offset) = simple_euclidean_hyperplane(
+                        &to_f32_vector(x.as_ref()),
+                        &to_f32_vector(y.as_ref()),
+                    );
+
+                    assert_is_close(offset, expected_offset);
+                    assert_is_close_vector(
+                        &to_f32_vector(hyperplane.as_ref()),
+                        expected_hyperplane.as_ref(),
+                    );
+                }
+
+                #[test]
+                fn [<test_vector_ $name _add_vector>]() {
+                    let (x, y) = get_sample_vectors::<f64>($len);
+                    let expected = x.iter()
+                        .zip(y.iter())
+                        .map(|(x, y)| x + y)
+                        .collect::<Vec<f64>>();
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, f64>::try_from_vec(y)
+                        .expect("Create vector");
+
+                    let sample = x + y;
+                    assert_is_close_vector_f64(sample.as_ref(), expected.as_ref());
+                }
+
+                #[test]
+                fn [<test_vector_ $name _mul_value>]() {
+                    let (mut expected, _) = get_sample_vectors::<f64>($len);
+                    let mut sample = Vector::<$dim, $arch, f64>::try_from_vec(expected.clone())
+                        .expect("Create vector");
+                    sample *= 2.0;
+
+                    for v in expected.iter_mut() {
+                        *v *= 2.0;
+                    }
+
+                    assert_is_close_vector_f64(sample.as_ref(), expected.as_ref());
+                }
+
+                #[test]
+                fn [<test_vector_ $name _min_max_sum>]() {
+                    let (x, _) = get_sample_vectors::<f64>($len);
+                    let x = Vector::<$dim, $arch, f64>::try_from_vec(x)
+                        .expect("Create vector");
+
+                    let expected_min = x.as_ref()
+                        .iter()
+                        .fold(f64::INFINITY, |acc, v| acc.min(*v));
+                    let expected_max = x.as_ref()
+                        .iter()
+                        .fold(f64::NEG_INFINITY, |acc, v| acc.max(*v));
+                    let expected_sum = x.as_ref()
+                        .iter()
+                        .fold(0.0, |acc, v| acc + *v);
+                    assert_eq!(x.min(), expected_min);
+                    assert_eq!(x.max(), expected_max);
+                    assert_is_close(x.sum() as f32, expected_sum as f32);
+                }
+        }
+    };
+}
+
+// f64 routines
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_fallback_nofma,
+    dim = X1024,
+    len = 1024,
+    arch = Fallback,
+);
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_fallback_nofma,
+    dim = XAny,
+    len = 537,
+    arch = Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_avx2_nofma,
+    dim = X1024,
+    len = 1024,
+    arch = Avx2,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x768_avx2_nofma,
+    dim = X768,
+    len = 768,
+    arch = Avx2,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_avx2_nofma,
+    dim = XAny,
+    len = 537,
+    arch = Avx2,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_avx2_fma,
+    dim = X1024,
+    len = 1024,
+    arch = Avx2Fma,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_avx2_fma,
+    dim = XAny,
+    len = 537,
+    arch = Avx2Fma,
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    target_feature = "avx512f"
+))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_avx512_nofma,
+    dim = X1024,
+    len = 1024,
+    arch = Avx512,
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    target_feature = "avx512f"
+))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_avx512_nofma,
+    dim = XAny,
+    len = 537,
+    arch = Avx512,
+);
+#[cfg(target_arch = "aarch64")]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_neon,
+    dim = X1024,
+    len = 1024,
+    arch = Neon,
+);
+#[cfg(target_arch = "aarch64")]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_neon,
+    dim = XAny,
+    len = 537,
+    arch = Neon,
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_sve,
+    dim = XAny,
+    len = 537,
+    arch = Sve,
+);
+define_f64_vector_op_test_suite!(
+    suite_name = f64_x1024_auto,
+    dim = X1024,
+    len = 1024,
+    arch = Auto,
+);
+define_f64_vector_op_test_suite!(
+    suite_name = f64_xany_auto,
+    dim = XAny,
+    len = 537,
+    arch = Auto,
+);
+
+macro_rules! define_half_vector_op_test_suite {
+    (
+        suite_name = $name:ident,
+        dim = $dim:ident,
+        len = $len:expr,
+        arch = $arch:ident,
+        tp = $tp:ident,
+    ) => {
+            paste::paste! {
+                #[test]
+                fn [<test_vector_ $name _dot>]() {
+                    let (x, y) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dot(&y);
+                    assert_is_close(res, simple_dot(&widen(x.as_ref()), &widen(y.as_ref())));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_cosine>]() {
+                    let (x, y) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_cosine(&y);
+                    assert_is_close(res, simple_cosine(&widen(x.as_ref()), &widen(y.as_ref())));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_squared_euclidean>]() {
+                    let (x, y) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_squared_euclidean(&y);
+                    assert_is_close(res, simple_euclidean(&widen(x.as_ref()), &widen(y.as_ref())));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_manhattan>]() {
+                    let (x, y) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_manhattan(&y);
+                    assert_is_close(res, simple_manhattan(&widen(x.as_ref()), &widen(y.as_ref())));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _dist_correlation>]() {
+                    let (x, y) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let y = Vector::<$dim, $arch, $tp>::try_from_vec(y)
+                        .expect("Create vector");
+                    let res = x.dist_correlation(&y);
+                    // The centred vectors are rounded back down to half precision.
+                    let expected = simple_correlation(&widen(x.as_ref()), &widen(y.as_ref()));
+                    assert!((res - expected).abs() < 0.01, "{res} vs {expected}");
+                }
+
+                #[test]
+                fn [<test_vector_ $name _squared_norm>]() {
+                    let (x, _) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let res = x.squared_norm();
+                    assert_is_close(res, simple_dot(&widen(x.as_ref()), &widen(x.as_ref())));
+                }
+
+                #[test]
+                fn [<test_vector_ $name _metric_ops>]() {
+                    let (x, _) = get_half_sample_vectors::<$tp>($len);
+                    let x = Vector::<$dim, $arch, $tp>::try_from_vec(x)
+                        .expect("Create vector");
+                    let widened = widen(x.as_ref());
+
+                    let expected_min = widened.iter().fold(f32::INFINITY, |acc, v| acc.min(*v));
+                    let expected_max = widened.iter().fold(f32::NEG_INFINITY, |acc, v| acc.max(*v));
+                    assert_eq!(x.min(), expected_min);
+                    assert_eq!(x.max(), expected_max);
+                    assert_is_close(x.sum(), widened.iter().sum());
+                }
+
+                #[test]
+                fn [<test_vector_ $name _mul_value>]() {
+                    let (x, _) = get_half_sample_vectors::<$tp>($len);
+                    let mut sample = Vector::<$dim, $arch, $tp>::try_from_vec(x.clone())
+                        .expect("Create vector");
+                    sample *= $tp::from_f32(2.0);
+
+                    // Doubling only changes the exponent so is exact.
+                    let expected = x.iter()
+                        .map(|v| *v * $tp::from_f32(2.0))
+                        .collect::<Vec<$tp>>();
+                    assert_eq!(sample.as_ref(), expected.as_slice());
+                }
+        }
+    };
+}
+
+// Half precision routines
+define_half_vector_op_test_suite!(
+    suite_name = f16_x768_fallback_nofma,
+    dim = X768,
+    len = 768,
+    arch = Fallback,
+    tp = f16,
+);
+define_half_vector_op_test_suite!(
+    suite_name = bf16_xany_fallback_nofma,
+    dim = XAny,
+    len = 537,
+    arch = Fallback,
+    tp = bf16,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_half_vector_op_test_suite!(
+    suite_name = f16_x768_avx2_nofma,
+    dim = X768,
+    len = 768,
+    arch = Avx2,
+    tp = f16,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_half_vector_op_test_suite!(
+    suite_name = bf16_xany_avx2_nofma,
+    dim = XAny,
+    len = 537,
+    arch = Avx2,
+    tp = bf16,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_half_vector_op_test_suite!(
+    suite_name = f16_xany_avx2_fma,
+    dim = XAny,
+    len = 537,
+    arch = Avx2Fma,
+    tp = f16,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_half_vector_op_test_suite!(
+    suite_name = bf16_x768_avx2_fma,
+    dim = X768,
+    len = 768,
+    arch = Avx2Fma,
+    tp = bf16,
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    target_feature = "avx512f"
+))]
+define_half_vector_op_test_suite!(
+    suite_name = f16_xany_avx512,
+    dim = XAny,
+    len = 537,
+    arch = Avx512,
+    tp = f16,
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    target_feature = "avx512f"
+))]
+define_half_vector_op_test_suite!(
+    suite_name = bf16_x768_avx512,
+    dim = X768,
+    len = 768,
+    arch = Avx512,
+    tp = bf16,
+);
+#[cfg(target_arch = "aarch64")]
+define_half_vector_op_test_suite!(
+    suite_name = f16_xany_neon,
+    dim = XAny,
+    len = 537,
+    arch = Neon,
+    tp = f16,
+);
+#[cfg(target_arch = "aarch64")]
+define_half_vector_op_test_suite!(
+    suite_name = bf16_x768_neon,
+    dim = X768,
+    len = 768,
+    arch = Neon,
+    tp = bf16,
+);
+#[cfg(all(target_arch = "aarch64", feature = "nightly", target_feature = "sve"))]
+define_half_vector_op_test_suite!(
+    suite_name = bf16_xany_sve,
+    dim = XAny,
+    len = 537,
+    arch = Sve,
+    tp = bf16,
+);
+define_half_vector_op_test_suite!(
+    suite_name = f16_x768_auto,
+    dim = X768,
+    len = 768,
+    arch = Auto,
+    tp = f16,
+);
+define_half_vector_op_test_suite!(
+    suite_name = bf16_xany_auto,
+    dim = XAny,
+    len = 537,
+    arch = Auto,
+    tp = bf16,
+);
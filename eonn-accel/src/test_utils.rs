@@ -4,6 +4,7 @@ use rand_chacha::ChaCha8Rng;
 
 use crate::danger::cosine;
 use crate::math::{AutoMath, Math};
+use crate::VectorType;
 
 const SEED: u64 = 34535345353;
 
@@ -23,6 +24,59 @@ where
     (x, y)
 }
 
+/// Samples vectors as `f32` and rounds them down to the half precision type `T`.
+pub fn get_half_sample_vectors<T>(size: usize) -> (Vec<T>, Vec<T>)
+where
+    T: VectorType<Accumulator = f32>,
+{
+    let (x, y) = get_sample_vectors::<f32>(size);
+    (
+        x.into_iter().map(T::from_accumulator).collect(),
+        y.into_iter().map(T::from_accumulator).collect(),
+    )
+}
+
+/// Samples scalar quantized codes in the range `-127..=127`.
+pub fn get_i8_sample_codes(size: usize) -> (Vec<i8>, Vec<i8>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+
+    let mut x = Vec::new();
+    let mut y = Vec::new();
+    for _ in 0..size {
+        x.push(rng.gen_range(-127..=127));
+        y.push(rng.gen_range(-127..=127));
+    }
+
+    (x, y)
+}
+
+/// The sorted non-zero indices, their values and the equivalent dense vector.
+pub type SparseSample = (Vec<u32>, Vec<f32>, Vec<f32>);
+
+pub fn get_sparse_sample_vectors(
+    size: usize,
+    density: f64,
+) -> (SparseSample, SparseSample) {
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+
+    let mut sample = || {
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut dense = vec![0.0; size];
+        for (i, dense) in dense.iter_mut().enumerate() {
+            if rng.gen_bool(density) {
+                let value = rng.gen::<f32>() + 0.1;
+                indices.push(i as u32);
+                values.push(value);
+                *dense = value;
+            }
+        }
+        (indices, values, dense)
+    };
+
+    (sample(), sample())
+}
+
 /// Checks if x is within a certain threshold distance of each other.
 pub fn is_close(x: f32, y: f32) -> bool {
     let max = x.max(y);
@@ -78,6 +132,113 @@ where
     dist
 }
 
+pub fn simple_manhattan<T>(x: &[T], y: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let mut dist = AutoMath::zero();
+
+    for i in 0..x.len() {
+        let diff = AutoMath::sub(x[i], y[i]);
+        dist = AutoMath::add(dist, AutoMath::abs(diff));
+    }
+
+    dist
+}
+
+pub fn simple_chebyshev<T>(x: &[T], y: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let mut dist = AutoMath::zero();
+
+    for i in 0..x.len() {
+        let diff = AutoMath::sub(x[i], y[i]);
+        dist = AutoMath::cmp_max(dist, AutoMath::abs(diff));
+    }
+
+    dist
+}
+
+pub fn simple_weighted_euclidean(x: &[f32], y: &[f32], w: &[f32]) -> f32 {
+    let mut dist = 0.0;
+
+    for i in 0..x.len() {
+        let diff = x[i] - y[i];
+        dist += w[i] * diff * diff;
+    }
+
+    dist
+}
+
+pub fn simple_minkowski(x: &[f32], y: &[f32], p: f32) -> f32 {
+    let mut dist = 0.0;
+
+    for i in 0..x.len() {
+        dist += (x[i] - y[i]).abs().powf(p);
+    }
+
+    dist.powf(1.0 / p)
+}
+
+pub fn simple_haversine(x: &[f32], y: &[f32]) -> f32 {
+    let (x_lat, x_long) = (x[0] as f64, x[1] as f64);
+    let (y_lat, y_long) = (y[0] as f64, y[1] as f64);
+
+    let a = ((x_lat - y_lat) / 2.0).sin().powi(2)
+        + x_lat.cos() * y_lat.cos() * ((x_long - y_long) / 2.0).sin().powi(2);
+
+    (2.0 * a.sqrt().min(1.0).asin()) as f32
+}
+
+pub fn simple_correlation(x: &[f32], y: &[f32]) -> f32 {
+    let mean_x = x.iter().sum::<f32>() / x.len() as f32;
+    let mean_y = y.iter().sum::<f32>() / y.len() as f32;
+
+    let x = x.iter().map(|v| v - mean_x).collect::<Vec<_>>();
+    let y = y.iter().map(|v| v - mean_y).collect::<Vec<_>>();
+
+    simple_cosine(&x, &y)
+}
+
+pub fn simple_hellinger(x: &[f32], y: &[f32]) -> f32 {
+    let mut product_sum = 0.0;
+    let mut l1_x = 0.0;
+    let mut l1_y = 0.0;
+
+    for i in 0..x.len() {
+        product_sum += (x[i] * y[i]).sqrt();
+        l1_x += x[i];
+        l1_y += y[i];
+    }
+
+    if l1_x == 0.0 && l1_y == 0.0 {
+        0.0
+    } else if l1_x == 0.0 || l1_y == 0.0 {
+        1.0
+    } else {
+        (1.0 - product_sum / (l1_x * l1_y).sqrt()).max(0.0).sqrt()
+    }
+}
+
+pub fn simple_jensen_shannon(x: &[f32], y: &[f32]) -> f32 {
+    let eps = f32::EPSILON;
+    let l1_x = x.iter().sum::<f32>() + eps * x.len() as f32;
+    let l1_y = y.iter().sum::<f32>() + eps * y.len() as f32;
+
+    let mut result = 0.0;
+    for i in 0..x.len() {
+        let px = (x[i] + eps) / l1_x;
+        let py = (y[i] + eps) / l1_y;
+        let m = (px + py) / 2.0;
+        result += (px * (px / m).ln() + py * (py / m).ln()) / 2.0;
+    }
+
+    result
+}
+
 pub fn simple_angular_hyperplane(x: &[f32], y: &[f32]) -> Vec<f32> {
     let mut norm_x = simple_dot(x, x).sqrt();
     if norm_x.abs() < f32::EPSILON {
@@ -119,16 +280,53 @@ pub fn simple_euclidean_hyperplane(x: &[f32], y: &[f32]) -> (Vec<f32>, f32) {
     (hyperplane_vector, hyperplane_offset)
 }
 
+pub fn simple_i8_dot(x: &[i8], y: &[i8]) -> i32 {
+    x.iter().zip(y).map(|(&x, &y)| x as i32 * y as i32).sum()
+}
+
+pub fn simple_i8_euclidean(x: &[i8], y: &[i8]) -> u32 {
+    x.iter()
+        .zip(y)
+        .map(|(&x, &y)| (x as i32 - y as i32).pow(2) as u32)
+        .sum()
+}
+
+pub fn simple_f32_i8_dot(x: &[f32], y: &[i8]) -> f32 {
+    x.iter().zip(y).map(|(&x, &y)| x * y as f32).sum()
+}
+
+pub fn simple_f32_i8_euclidean(x: &[f32], scale: &[f32], y: &[i8]) -> f32 {
+    let mut dist = 0.0;
+    for i in 0..x.len() {
+        let diff = x[i] - scale[i] * y[i] as f32;
+        dist += diff * diff;
+    }
+    dist
+}
+
 pub fn assert_is_close(x: f32, y: f32) {
     assert!(is_close(x, y), "{x} vs {y}")
 }
 
+/// Checks the values are close relative to their magnitude, for routines which
+/// accumulate large values in a different order to the reference.
+pub fn assert_is_relative_close(x: f32, y: f32) {
+    let diff = (x - y).abs();
+    assert!(diff <= x.abs().max(y.abs()) * 1e-5 + 0.00015, "{x} vs {y}")
+}
+
 pub fn assert_is_close_vector(x: &[f32], y: &[f32]) {
     for i in 0..x.len() {
         assert!(is_close(x[i], y[i]), "x[{i}]={} vs y[{i}]={}", x[i], y[i]);
     }
 }
 
+/// Narrows a `f64` vector down to `f32`, for comparing against the `f32` only
+/// reference implementations.
+pub fn to_f32_vector(x: &[f64]) -> Vec<f32> {
+    x.iter().map(|v| *v as f32).collect()
+}
+
 pub fn assert_is_close_vector_f64(x: &[f64], y: &[f64]) {
     for i in 0..x.len() {
         assert!(
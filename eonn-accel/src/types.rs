@@ -1,9 +1,27 @@
-pub trait VectorType {
+pub use half::{bf16, f16};
+
+pub(crate) use self::sealed::Sealed;
+
+/// A scalar element type which vectors can be built from.
+///
+/// This trait is sealed and implemented for `f32`, `f64` and the half precision
+/// `f16` and `bf16` types.
+pub trait VectorType: Sealed {
+    /// The type distances and reductions over the elements are computed in.
+    ///
+    /// Half precision types are widened and accumulated in `f32`, so the native
+    /// AVX512-FP16 arithmetic is never used for `f16`. AVX512-BF16 is used for the
+    /// `bf16` dot product and cosine distance where available as it accumulates
+    /// in `f32`.
+    type Accumulator: VectorType;
+
     fn is_finite(&self) -> bool;
     fn is_nan(&self) -> bool;
 }
 
 impl VectorType for f32 {
+    type Accumulator = f32;
+
     fn is_finite(&self) -> bool {
         f32::is_finite(*self)
     }
@@ -12,3 +30,219 @@ impl VectorType for f32 {
         f32::is_nan(*self)
     }
 }
+
+impl VectorType for f64 {
+    type Accumulator = f64;
+
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+}
+
+impl VectorType for f16 {
+    type Accumulator = f32;
+
+    fn is_finite(&self) -> bool {
+        f16::is_finite(*self)
+    }
+
+    fn is_nan(&self) -> bool {
+        f16::is_nan(*self)
+    }
+}
+
+impl VectorType for bf16 {
+    type Accumulator = f32;
+
+    fn is_finite(&self) -> bool {
+        bf16::is_finite(*self)
+    }
+
+    fn is_nan(&self) -> bool {
+        bf16::is_nan(*self)
+    }
+}
+
+/// Widens a half precision vector to `f32`.
+pub(crate) fn widen<T: VectorType<Accumulator = f32>>(x: &[T]) -> Vec<f32> {
+    x.iter().map(|v| v.to_accumulator()).collect()
+}
+
+mod sealed {
+    use super::{bf16, f16, VectorType};
+
+    /// The element specific values and fallback routines used by the default
+    /// [DangerousOps](crate::DangerousOps) methods.
+    pub trait Sealed: Copy + PartialEq {
+        const ONE: Self;
+        const TWO: Self;
+        const INFINITY: Self;
+
+        fn sqrt(self) -> Self;
+
+        /// Returns the mean of `len` values which sum to `sum`.
+        fn mean(sum: Self, len: usize) -> Self;
+
+        /// Widens the value to its accumulator type.
+        fn to_accumulator(self) -> <Self as VectorType>::Accumulator
+        where
+            Self: VectorType;
+
+        /// Narrows an accumulated value back down to the element type.
+        fn from_accumulator(value: <Self as VectorType>::Accumulator) -> Self
+        where
+            Self: VectorType;
+
+        unsafe fn fallback_minkowski(
+            x: &[Self],
+            y: &[Self],
+            p: <Self as VectorType>::Accumulator,
+        ) -> <Self as VectorType>::Accumulator
+        where
+            Self: VectorType;
+
+        unsafe fn fallback_haversine(
+            x: &[Self],
+            y: &[Self],
+        ) -> <Self as VectorType>::Accumulator
+        where
+            Self: VectorType;
+
+        unsafe fn fallback_jensen_shannon(
+            x: &[Self],
+            y: &[Self],
+            l1_x: <Self as VectorType>::Accumulator,
+            l1_y: <Self as VectorType>::Accumulator,
+        ) -> <Self as VectorType>::Accumulator
+        where
+            Self: VectorType;
+    }
+
+    macro_rules! impl_sealed {
+        ($t:ident, $minkowski:ident, $haversine:ident, $jensen_shannon:ident) => {
+            impl Sealed for $t {
+                const ONE: Self = 1.0;
+                const TWO: Self = 2.0;
+                const INFINITY: Self = $t::INFINITY;
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                #[inline]
+                fn mean(sum: Self, len: usize) -> Self {
+                    sum / len as $t
+                }
+
+                #[inline]
+                fn to_accumulator(self) -> Self {
+                    self
+                }
+
+                #[inline]
+                fn from_accumulator(value: Self) -> Self {
+                    value
+                }
+
+                #[inline]
+                unsafe fn fallback_minkowski(x: &[Self], y: &[Self], p: Self) -> Self {
+                    crate::danger::$minkowski(x, y, p)
+                }
+
+                #[inline]
+                unsafe fn fallback_haversine(x: &[Self], y: &[Self]) -> Self {
+                    crate::danger::$haversine(x, y)
+                }
+
+                #[inline]
+                unsafe fn fallback_jensen_shannon(
+                    x: &[Self],
+                    y: &[Self],
+                    l1_x: Self,
+                    l1_y: Self,
+                ) -> Self {
+                    crate::danger::$jensen_shannon(x, y, l1_x, l1_y)
+                }
+            }
+        };
+    }
+
+    impl_sealed!(
+        f32,
+        f32_xany_fallback_nofma_minkowski,
+        f32_x2_fallback_nofma_haversine,
+        f32_xany_fallback_nofma_jensen_shannon
+    );
+    impl_sealed!(
+        f64,
+        f64_xany_fallback_nofma_minkowski,
+        f64_x2_fallback_nofma_haversine,
+        f64_xany_fallback_nofma_jensen_shannon
+    );
+
+    // The half precision types have no routines of their own beyond the
+    // accelerated dot, cosine and squared Euclidean kernels, everything else is
+    // widened to `f32` first.
+    macro_rules! impl_half_sealed {
+        ($t:ident) => {
+            impl Sealed for $t {
+                const ONE: Self = $t::ONE;
+                const TWO: Self = $t::from_f32_const(2.0);
+                const INFINITY: Self = $t::INFINITY;
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    $t::from_f32(self.to_f32().sqrt())
+                }
+
+                #[inline]
+                fn mean(sum: Self, len: usize) -> Self {
+                    $t::from_f32(sum.to_f32() / len as f32)
+                }
+
+                #[inline]
+                fn to_accumulator(self) -> f32 {
+                    self.to_f32()
+                }
+
+                #[inline]
+                fn from_accumulator(value: f32) -> Self {
+                    $t::from_f32(value)
+                }
+
+                #[inline]
+                unsafe fn fallback_minkowski(x: &[Self], y: &[Self], p: f32) -> f32 {
+                    f32::fallback_minkowski(&super::widen(x), &super::widen(y), p)
+                }
+
+                #[inline]
+                unsafe fn fallback_haversine(x: &[Self], y: &[Self]) -> f32 {
+                    f32::fallback_haversine(&super::widen(x), &super::widen(y))
+                }
+
+                #[inline]
+                unsafe fn fallback_jensen_shannon(
+                    x: &[Self],
+                    y: &[Self],
+                    l1_x: f32,
+                    l1_y: f32,
+                ) -> f32 {
+                    f32::fallback_jensen_shannon(
+                        &super::widen(x),
+                        &super::widen(y),
+                        l1_x,
+                        l1_y,
+                    )
+                }
+            }
+        };
+    }
+
+    impl_half_sealed!(f16);
+    impl_half_sealed!(bf16);
+}
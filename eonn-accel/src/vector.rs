@@ -2,8 +2,15 @@ use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::arch::Arch;
+use crate::danger::{
+    f32_xany_fallback_nofma_hamming,
+    f32_xany_fallback_nofma_jaccard,
+    f64_xany_fallback_nofma_hamming,
+    f64_xany_fallback_nofma_jaccard,
+};
 use crate::ops::{DangerousOps, MetricOps, SpacialOps};
-use crate::{Dim, VectorType};
+use crate::types::{bf16, f16, widen, Sealed};
+use crate::{Dim, VectorType, VectorView};
 
 #[derive(Debug)]
 /// An error that occurs while attempting to safely create a new `Vector` type
@@ -36,7 +43,7 @@ impl std::error::Error for VectorCreateError {}
 /// both at runtime or at compile time depending on application.
 pub struct Vector<D: Dim, A: Arch, T: VectorType = f32>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     buffer: Vec<T>,
     ops: (D, A),
@@ -44,7 +51,7 @@ where
 
 impl<D: Dim + Debug, A: Arch + Debug, T: VectorType + Debug> Debug for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if !self.buffer.is_empty() {
@@ -60,7 +67,7 @@ where
 
 impl<D: Dim, A: Arch, T: VectorType> AsRef<[T]> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     fn as_ref(&self) -> &[T] {
         &self.buffer
@@ -69,7 +76,7 @@ where
 
 impl<D: Dim, A: Arch, T: VectorType> Deref for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     type Target = [T];
 
@@ -80,7 +87,7 @@ where
 
 impl<D: Dim, A: Arch, T: VectorType> Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     #[inline]
     /// Attempt to create a new vector using the given input data.
@@ -126,6 +133,12 @@ where
         }
     }
 
+    #[inline]
+    /// Returns a borrowed, read-only view of the vector.
+    pub fn as_view(&self) -> VectorView<'_, D, A, T> {
+        unsafe { VectorView::from_slice_unchecked(&self.buffer) }
+    }
+
     #[cfg(test)]
     // WARNING: Never expose this function, as it allows users to accidentally bypass
     //          the runtime CPU feature detection and can possibly try using instructions
@@ -135,24 +148,24 @@ where
     }
 }
 
-impl<D: Dim, A: Arch> MetricOps for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> MetricOps<T::Accumulator> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
-    fn min(&self) -> f32 {
-        unsafe { self.ops.min(&self.buffer) }
+    fn min(&self) -> T::Accumulator {
+        unsafe { self.ops.min(&self.buffer) }.to_accumulator()
     }
 
-    fn max(&self) -> f32 {
-        unsafe { self.ops.max(&self.buffer) }
+    fn max(&self) -> T::Accumulator {
+        unsafe { self.ops.max(&self.buffer) }.to_accumulator()
     }
 
-    fn sum(&self) -> f32 {
+    fn sum(&self) -> T::Accumulator {
         unsafe { self.ops.sum(&self.buffer) }
     }
 
-    fn mean(&self) -> f32 {
-        self.sum() / self.buffer.len() as f32
+    fn mean(&self) -> T::Accumulator {
+        T::Accumulator::mean(self.sum(), self.buffer.len())
     }
 }
 
@@ -195,6 +208,64 @@ where
         unsafe { self.ops.squared_euclidean(&self.buffer, &other.buffer) }
     }
 
+    fn dist_manhattan(&self, other: &Self) -> f32 {
+        unsafe { self.ops.manhattan(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_chebyshev(&self, other: &Self) -> f32 {
+        unsafe { self.ops.chebyshev(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_hamming(&self, other: &Self) -> f32 {
+        unsafe { f32_xany_fallback_nofma_hamming(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_jaccard(&self, other: &Self) -> f32 {
+        unsafe { f32_xany_fallback_nofma_jaccard(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_minkowski(&self, other: &Self, p: f32) -> f32 {
+        unsafe { self.ops.minkowski(&self.buffer, &other.buffer, p) }
+    }
+
+    fn dist_weighted_squared_euclidean(&self, other: &Self, weights: &[f32]) -> f32 {
+        assert_eq!(
+            weights.len(),
+            self.buffer.len(),
+            "Lengths of the vector and weights must be equal"
+        );
+        unsafe {
+            self.ops
+                .weighted_squared_euclidean(&self.buffer, &other.buffer, weights)
+        }
+    }
+
+    fn dist_haversine(&self, other: &Self) -> f32 {
+        assert_eq!(
+            self.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        assert_eq!(
+            other.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        unsafe { self.ops.haversine(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_correlation(&self, other: &Self) -> f32 {
+        unsafe { self.ops.correlation(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_hellinger(&self, other: &Self) -> f32 {
+        unsafe { self.ops.hellinger(&self.buffer, &other.buffer) }
+    }
+
+    fn dist_jensen_shannon(&self, other: &Self) -> f32 {
+        unsafe { self.ops.jensen_shannon(&self.buffer, &other.buffer) }
+    }
+
     fn angular_hyperplane(&self, other: &Self) -> Self {
         unsafe {
             let data = self.ops.angular_hyperplane(&self.buffer, &other.buffer);
@@ -211,25 +282,280 @@ where
     }
 }
 
-impl<D: Dim, A: Arch> Add<f32> for Vector<D, A, f32>
+/// The distances between `f64` vectors are computed at full precision and only
+/// narrowed to `f32` for the result.
+impl<D: Dim, A: Arch> SpacialOps for Vector<D, A, f64>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<f64>,
+{
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        unsafe { self.ops.dot(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn squared_norm(&self) -> f32 {
+        unsafe { self.ops.squared_norm(&self.buffer) as f32 }
+    }
+
+    fn normalize(&mut self) {
+        let norm_self = unsafe { self.ops.squared_norm(&self.buffer) }.sqrt();
+        self.div_assign(norm_self);
+    }
+
+    fn dist_dot(&self, other: &Self) -> f32 {
+        let product = unsafe { self.ops.dot(&self.buffer, &other.buffer) };
+
+        if product <= 0.0 {
+            1.0
+        } else {
+            (1.0 - product) as f32
+        }
+    }
+
+    fn dist_cosine(&self, other: &Self) -> f32 {
+        unsafe { self.ops.cosine(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_squared_euclidean(&self, other: &Self) -> f32 {
+        unsafe { self.ops.squared_euclidean(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_manhattan(&self, other: &Self) -> f32 {
+        unsafe { self.ops.manhattan(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_chebyshev(&self, other: &Self) -> f32 {
+        unsafe { self.ops.chebyshev(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_hamming(&self, other: &Self) -> f32 {
+        unsafe { f64_xany_fallback_nofma_hamming(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_jaccard(&self, other: &Self) -> f32 {
+        unsafe { f64_xany_fallback_nofma_jaccard(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_minkowski(&self, other: &Self, p: f32) -> f32 {
+        unsafe { self.ops.minkowski(&self.buffer, &other.buffer, p as f64) as f32 }
+    }
+
+    fn dist_weighted_squared_euclidean(&self, other: &Self, weights: &[f32]) -> f32 {
+        assert_eq!(
+            weights.len(),
+            self.buffer.len(),
+            "Lengths of the vector and weights must be equal"
+        );
+        let weights: Vec<f64> = weights.iter().map(|w| *w as f64).collect();
+        unsafe {
+            self.ops
+                .weighted_squared_euclidean(&self.buffer, &other.buffer, &weights)
+                as f32
+        }
+    }
+
+    fn dist_haversine(&self, other: &Self) -> f32 {
+        assert_eq!(
+            self.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        assert_eq!(
+            other.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        unsafe { self.ops.haversine(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_correlation(&self, other: &Self) -> f32 {
+        unsafe { self.ops.correlation(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_hellinger(&self, other: &Self) -> f32 {
+        unsafe { self.ops.hellinger(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn dist_jensen_shannon(&self, other: &Self) -> f32 {
+        unsafe { self.ops.jensen_shannon(&self.buffer, &other.buffer) as f32 }
+    }
+
+    fn angular_hyperplane(&self, other: &Self) -> Self {
+        unsafe {
+            let data = self.ops.angular_hyperplane(&self.buffer, &other.buffer);
+            Self::from_vec_unchecked(data)
+        }
+    }
+
+    fn euclidean_hyperplane(&self, other: &Self) -> (Self, f32) {
+        unsafe {
+            let (data, offset) =
+                self.ops.euclidean_hyperplane(&self.buffer, &other.buffer);
+            (Self::from_vec_unchecked(data), offset as f32)
+        }
+    }
+}
+
+// The distances between half precision vectors are accumulated in `f32`.
+macro_rules! impl_half_spacial_ops {
+    ($t:ident) => {
+        impl<D: Dim, A: Arch> SpacialOps for Vector<D, A, $t>
+        where
+            (D, A): DangerousOps<$t>,
+        {
+            fn len(&self) -> usize {
+                self.buffer.len()
+            }
+
+            fn dot(&self, other: &Self) -> f32 {
+                unsafe { self.ops.dot(&self.buffer, &other.buffer) }
+            }
+
+            fn squared_norm(&self) -> f32 {
+                unsafe { self.ops.squared_norm(&self.buffer) }
+            }
+
+            fn normalize(&mut self) {
+                let norm_self = unsafe { self.ops.squared_norm(&self.buffer) }.sqrt();
+                self.div_assign($t::from_f32(norm_self));
+            }
+
+            fn dist_dot(&self, other: &Self) -> f32 {
+                let product = unsafe { self.ops.dot(&self.buffer, &other.buffer) };
+
+                if product <= 0.0 {
+                    1.0
+                } else {
+                    1.0 - product
+                }
+            }
+
+            fn dist_cosine(&self, other: &Self) -> f32 {
+                unsafe { self.ops.cosine(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_squared_euclidean(&self, other: &Self) -> f32 {
+                unsafe { self.ops.squared_euclidean(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_manhattan(&self, other: &Self) -> f32 {
+                unsafe { self.ops.manhattan(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_chebyshev(&self, other: &Self) -> f32 {
+                unsafe { self.ops.chebyshev(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_hamming(&self, other: &Self) -> f32 {
+                unsafe {
+                    f32_xany_fallback_nofma_hamming(
+                        &widen(&self.buffer),
+                        &widen(&other.buffer),
+                    )
+                }
+            }
+
+            fn dist_jaccard(&self, other: &Self) -> f32 {
+                unsafe {
+                    f32_xany_fallback_nofma_jaccard(
+                        &widen(&self.buffer),
+                        &widen(&other.buffer),
+                    )
+                }
+            }
+
+            fn dist_minkowski(&self, other: &Self, p: f32) -> f32 {
+                unsafe { self.ops.minkowski(&self.buffer, &other.buffer, p) }
+            }
+
+            fn dist_weighted_squared_euclidean(
+                &self,
+                other: &Self,
+                weights: &[f32],
+            ) -> f32 {
+                assert_eq!(
+                    weights.len(),
+                    self.buffer.len(),
+                    "Lengths of the vector and weights must be equal"
+                );
+                unsafe {
+                    self.ops.weighted_squared_euclidean(
+                        &self.buffer,
+                        &other.buffer,
+                        weights,
+                    )
+                }
+            }
+
+            fn dist_haversine(&self, other: &Self) -> f32 {
+                assert_eq!(
+                    self.buffer.len(),
+                    2,
+                    "Haversine requires 2 dimensional vectors"
+                );
+                assert_eq!(
+                    other.buffer.len(),
+                    2,
+                    "Haversine requires 2 dimensional vectors"
+                );
+                unsafe { self.ops.haversine(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_correlation(&self, other: &Self) -> f32 {
+                unsafe { self.ops.correlation(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_hellinger(&self, other: &Self) -> f32 {
+                unsafe { self.ops.hellinger(&self.buffer, &other.buffer) }
+            }
+
+            fn dist_jensen_shannon(&self, other: &Self) -> f32 {
+                unsafe { self.ops.jensen_shannon(&self.buffer, &other.buffer) }
+            }
+
+            fn angular_hyperplane(&self, other: &Self) -> Self {
+                unsafe {
+                    let data = self.ops.angular_hyperplane(&self.buffer, &other.buffer);
+                    Self::from_vec_unchecked(data)
+                }
+            }
+
+            fn euclidean_hyperplane(&self, other: &Self) -> (Self, f32) {
+                unsafe {
+                    let (data, offset) =
+                        self.ops.euclidean_hyperplane(&self.buffer, &other.buffer);
+                    (Self::from_vec_unchecked(data), offset)
+                }
+            }
+        }
+    };
+}
+
+impl_half_spacial_ops!(f16);
+impl_half_spacial_ops!(bf16);
+
+impl<D: Dim, A: Arch, T: VectorType> Add<T> for Vector<D, A, T>
+where
+    (D, A): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn add(mut self, rhs: f32) -> Self::Output {
+    fn add(mut self, rhs: T) -> Self::Output {
         self += rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch> AddAssign<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> AddAssign<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     #[inline]
-    fn add_assign(&mut self, rhs: f32) {
+    fn add_assign(&mut self, rhs: T) {
         // We cannot avoid these asserts, because it is out of control of what gets
         // passed in here.
         assert!(rhs.is_finite() && !rhs.is_nan());
@@ -237,25 +563,25 @@ where
     }
 }
 
-impl<D: Dim, A: Arch> Sub<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> Sub<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn sub(mut self, rhs: f32) -> Self::Output {
+    fn sub(mut self, rhs: T) -> Self::Output {
         self -= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch> SubAssign<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> SubAssign<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     #[inline]
-    fn sub_assign(&mut self, rhs: f32) {
+    fn sub_assign(&mut self, rhs: T) {
         // We cannot avoid these asserts, because it is out of control of what gets
         // passed in here.
         assert!(rhs.is_finite() && !rhs.is_nan());
@@ -263,25 +589,25 @@ where
     }
 }
 
-impl<D: Dim, A: Arch> Mul<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> Mul<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn mul(mut self, rhs: f32) -> Self::Output {
+    fn mul(mut self, rhs: T) -> Self::Output {
         self *= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch> MulAssign<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> MulAssign<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     #[inline]
-    fn mul_assign(&mut self, rhs: f32) {
+    fn mul_assign(&mut self, rhs: T) {
         // We cannot avoid these asserts, because it is out of control of what gets
         // passed in here.
         assert!(rhs.is_finite() && !rhs.is_nan());
@@ -289,25 +615,25 @@ where
     }
 }
 
-impl<D: Dim, A: Arch> Div<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> Div<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn div(mut self, rhs: f32) -> Self::Output {
+    fn div(mut self, rhs: T) -> Self::Output {
         self /= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch> DivAssign<f32> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, T: VectorType> DivAssign<T> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
+    (D, A): DangerousOps<T>,
 {
     #[inline]
-    fn div_assign(&mut self, rhs: f32) {
+    fn div_assign(&mut self, rhs: T) {
         // We cannot avoid these asserts, because it is out of control of what gets
         // passed in here.
         assert!(rhs.is_finite() && !rhs.is_nan());
@@ -315,102 +641,106 @@ where
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> Add<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> Add<Vector<D, A2, T>> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn add(mut self, rhs: Vector<D, A2, f32>) -> Self::Output {
+    fn add(mut self, rhs: Vector<D, A2, T>) -> Self::Output {
         self += rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> AddAssign<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> AddAssign<Vector<D, A2, T>>
+    for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     #[inline]
-    fn add_assign(&mut self, rhs: Vector<D, A2, f32>) {
+    fn add_assign(&mut self, rhs: Vector<D, A2, T>) {
         unsafe { self.ops.add_vertical(&mut self.buffer, &rhs.buffer) }
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> Sub<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> Sub<Vector<D, A2, T>> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn sub(mut self, rhs: Vector<D, A2, f32>) -> Self::Output {
+    fn sub(mut self, rhs: Vector<D, A2, T>) -> Self::Output {
         self -= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> SubAssign<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> SubAssign<Vector<D, A2, T>>
+    for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     #[inline]
-    fn sub_assign(&mut self, rhs: Vector<D, A2, f32>) {
+    fn sub_assign(&mut self, rhs: Vector<D, A2, T>) {
         unsafe { self.ops.sub_vertical(&mut self.buffer, &rhs.buffer) }
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> Mul<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> Mul<Vector<D, A2, T>> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn mul(mut self, rhs: Vector<D, A2, f32>) -> Self::Output {
+    fn mul(mut self, rhs: Vector<D, A2, T>) -> Self::Output {
         self *= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> MulAssign<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> MulAssign<Vector<D, A2, T>>
+    for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     #[inline]
-    fn mul_assign(&mut self, rhs: Vector<D, A2, f32>) {
+    fn mul_assign(&mut self, rhs: Vector<D, A2, T>) {
         unsafe { self.ops.mul_vertical(&mut self.buffer, &rhs.buffer) }
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> Div<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> Div<Vector<D, A2, T>> for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     type Output = Self;
 
     #[inline]
-    fn div(mut self, rhs: Vector<D, A2, f32>) -> Self::Output {
+    fn div(mut self, rhs: Vector<D, A2, T>) -> Self::Output {
         self /= rhs;
         self
     }
 }
 
-impl<D: Dim, A: Arch, A2: Arch> DivAssign<Vector<D, A2, f32>> for Vector<D, A, f32>
+impl<D: Dim, A: Arch, A2: Arch, T: VectorType> DivAssign<Vector<D, A2, T>>
+    for Vector<D, A, T>
 where
-    (D, A): DangerousOps,
-    (D, A2): DangerousOps,
+    (D, A): DangerousOps<T>,
+    (D, A2): DangerousOps<T>,
 {
     #[inline]
-    fn div_assign(&mut self, rhs: Vector<D, A2, f32>) {
+    fn div_assign(&mut self, rhs: Vector<D, A2, T>) {
         unsafe { self.ops.div_vertical(&mut self.buffer, &rhs.buffer) }
     }
 }
@@ -0,0 +1,336 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+
+use crate::arch::Arch;
+use crate::danger::{f32_xany_fallback_nofma_hamming, f32_xany_fallback_nofma_jaccard};
+use crate::ops::{DangerousOps, MetricOps};
+use crate::types::Sealed;
+use crate::{Dim, Vector, VectorCreateError, VectorType};
+
+/// A borrowed, read-only view of a vector of a given type and dimensions.
+///
+/// Unlike [Vector] this type does not own its data, which allows the distance ops
+/// to be ran directly against slices living in external memory, i.e. a memory
+/// mapped file, without copying them.
+pub struct VectorView<'a, D: Dim, A: Arch, T: VectorType = f32>
+where
+    (D, A): DangerousOps<T>,
+{
+    buffer: &'a [T],
+    ops: (D, A),
+}
+
+impl<'a, D: Dim + Debug, A: Arch + Debug, T: VectorType + Debug> Debug
+    for VectorView<'a, D, A, T>
+where
+    (D, A): DangerousOps<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.buffer.is_empty() {
+            let first = self.buffer.first().unwrap();
+            let last = self.buffer.last().unwrap();
+
+            write!(
+                f,
+                "VectorView(ops={:?}, [ {first:?} ... {last:?} ])",
+                self.ops
+            )
+        } else {
+            write!(f, "VectorView(ops={:?}, [])", self.ops)
+        }
+    }
+}
+
+impl<'a, D: Dim, A: Arch, T: VectorType> AsRef<[T]> for VectorView<'a, D, A, T>
+where
+    (D, A): DangerousOps<T>,
+{
+    fn as_ref(&self) -> &[T] {
+        self.buffer
+    }
+}
+
+impl<'a, D: Dim, A: Arch, T: VectorType> Deref for VectorView<'a, D, A, T>
+where
+    (D, A): DangerousOps<T>,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+    }
+}
+
+impl<'a, D: Dim, A: Arch, T: VectorType> VectorView<'a, D, A, T>
+where
+    (D, A): DangerousOps<T>,
+{
+    #[inline]
+    /// Attempt to create a new view over the given input data.
+    ///
+    /// This method will verify that the data is valid dimensions.
+    pub fn try_from_slice(data: &'a [T]) -> Result<Self, VectorCreateError> {
+        if let Some(expected_dim) = D::const_size() {
+            if data.len() != expected_dim {
+                return Err(VectorCreateError::BadDimensions {
+                    expected: expected_dim,
+                    got: data.len(),
+                });
+            }
+        }
+
+        Ok(unsafe { Self::from_slice_unchecked(data) })
+    }
+
+    #[inline]
+    /// Creates a new view over the given data without checking any values or dimensions.
+    ///
+    /// # Safety
+    /// You **must** ensure the provided data matches the dimensions/length specified
+    /// by `D`.
+    ///
+    /// If any of these checks are not performed or invalid, this creates immediate UB.
+    pub unsafe fn from_slice_unchecked(data: &'a [T]) -> Self {
+        #[cfg(debug_assertions)]
+        if let Some(expected_dim) = D::const_size() {
+            assert_eq!(
+                data.len(),
+                expected_dim,
+                "Dimensions of const size must match"
+            );
+        }
+
+        Self {
+            buffer: data,
+            ops: <(D, A) as Default>::default(),
+        }
+    }
+
+    #[inline]
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    /// Returns if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    #[inline]
+    /// Copies the view into a new owned [Vector].
+    pub fn to_vector(&self) -> Vector<D, A, T> {
+        unsafe { Vector::from_vec_unchecked(self.buffer.to_vec()) }
+    }
+}
+
+impl<'a, D: Dim, A: Arch> VectorView<'a, D, A, f32>
+where
+    (D, A): DangerousOps,
+{
+    #[inline]
+    /// Computes the dot product between self and another vector.
+    pub fn dot(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.dot(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the squared norm of the vector.
+    pub fn squared_norm(&self) -> f32 {
+        unsafe { self.ops.squared_norm(self.buffer) }
+    }
+
+    #[inline]
+    /// Computes the dot product distance between self and another vector.
+    pub fn dist_dot(&self, other: &VectorView<D, A, f32>) -> f32 {
+        let product = self.dot(other);
+
+        if product <= 0.0 {
+            1.0
+        } else {
+            1.0 - product
+        }
+    }
+
+    #[inline]
+    /// Computes the cosine distance between self and another vector.
+    pub fn dist_cosine(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.cosine(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the squared Euclidean distance between self and another vector.
+    pub fn dist_squared_euclidean(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.squared_euclidean(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Manhattan (L1) distance between self and another vector.
+    pub fn dist_manhattan(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.manhattan(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Chebyshev (L∞) distance between self and another vector.
+    pub fn dist_chebyshev(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.chebyshev(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Hamming distance between self and another vector.
+    pub fn dist_hamming(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { f32_xany_fallback_nofma_hamming(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Jaccard distance between self and another vector.
+    pub fn dist_jaccard(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { f32_xany_fallback_nofma_jaccard(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Minkowski distance between self and another vector with the
+    /// power `p`.
+    pub fn dist_minkowski(&self, other: &VectorView<D, A, f32>, p: f32) -> f32 {
+        unsafe { self.ops.minkowski(self.buffer, other.buffer, p) }
+    }
+
+    #[inline]
+    /// Computes the squared Euclidean distance between self and another vector,
+    /// scaling the squared difference of each dimension by the matching weight.
+    pub fn dist_weighted_squared_euclidean(
+        &self,
+        other: &VectorView<D, A, f32>,
+        weights: &[f32],
+    ) -> f32 {
+        assert_eq!(
+            weights.len(),
+            self.buffer.len(),
+            "Lengths of the vector and weights must be equal"
+        );
+        unsafe {
+            self.ops
+                .weighted_squared_euclidean(self.buffer, other.buffer, weights)
+        }
+    }
+
+    #[inline]
+    /// Computes the Haversine (great-circle) distance between self and another
+    /// `[latitude, longitude]` point in radians.
+    pub fn dist_haversine(&self, other: &VectorView<D, A, f32>) -> f32 {
+        assert_eq!(
+            self.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        assert_eq!(
+            other.buffer.len(),
+            2,
+            "Haversine requires 2 dimensional vectors"
+        );
+        unsafe { self.ops.haversine(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Pearson correlation distance between self and another vector.
+    pub fn dist_correlation(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.correlation(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Hellinger distance between self and another vector.
+    pub fn dist_hellinger(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.hellinger(self.buffer, other.buffer) }
+    }
+
+    #[inline]
+    /// Computes the Jensen-Shannon divergence between self and another vector.
+    pub fn dist_jensen_shannon(&self, other: &VectorView<D, A, f32>) -> f32 {
+        unsafe { self.ops.jensen_shannon(self.buffer, other.buffer) }
+    }
+}
+
+impl<'a, D: Dim, A: Arch, T: VectorType> MetricOps<T::Accumulator>
+    for VectorView<'a, D, A, T>
+where
+    (D, A): DangerousOps<T>,
+{
+    fn min(&self) -> T::Accumulator {
+        unsafe { self.ops.min(self.buffer) }.to_accumulator()
+    }
+
+    fn max(&self) -> T::Accumulator {
+        unsafe { self.ops.max(self.buffer) }.to_accumulator()
+    }
+
+    fn sum(&self) -> T::Accumulator {
+        unsafe { self.ops.sum(self.buffer) }
+    }
+
+    fn mean(&self) -> T::Accumulator {
+        T::Accumulator::mean(self.sum(), self.buffer.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+    use crate::{Auto, SpacialOps, X1024};
+
+    #[test]
+    fn test_view_matches_vector() {
+        let (x, y) = get_sample_vectors(1024);
+        let x_vec = Vector::<X1024, Auto>::try_from_vec(x.clone()).unwrap();
+        let y_vec = Vector::<X1024, Auto>::try_from_vec(y.clone()).unwrap();
+
+        let x_view = VectorView::<X1024, Auto>::try_from_slice(&x).unwrap();
+        let y_view = y_vec.as_view();
+
+        assert_eq!(x_view.dot(&y_view), x_vec.dot(&y_vec));
+        assert_eq!(x_view.squared_norm(), x_vec.squared_norm());
+        assert_eq!(x_view.dist_dot(&y_view), x_vec.dist_dot(&y_vec));
+        assert_eq!(x_view.dist_cosine(&y_view), x_vec.dist_cosine(&y_vec));
+        assert_eq!(
+            x_view.dist_squared_euclidean(&y_view),
+            x_vec.dist_squared_euclidean(&y_vec)
+        );
+        assert_eq!(x_view.dist_manhattan(&y_view), x_vec.dist_manhattan(&y_vec));
+        assert_eq!(x_view.dist_chebyshev(&y_view), x_vec.dist_chebyshev(&y_vec));
+        assert_eq!(x_view.dist_hamming(&y_view), x_vec.dist_hamming(&y_vec));
+        assert_eq!(x_view.dist_jaccard(&y_view), x_vec.dist_jaccard(&y_vec));
+        assert_eq!(
+            x_view.dist_minkowski(&y_view, 3.0),
+            x_vec.dist_minkowski(&y_vec, 3.0)
+        );
+        assert_eq!(
+            x_view.dist_weighted_squared_euclidean(&y_view, &y),
+            x_vec.dist_weighted_squared_euclidean(&y_vec, &y)
+        );
+        assert_eq!(
+            x_view.dist_correlation(&y_view),
+            x_vec.dist_correlation(&y_vec)
+        );
+        assert_eq!(x_view.dist_hellinger(&y_view), x_vec.dist_hellinger(&y_vec));
+        assert_eq!(
+            x_view.dist_jensen_shannon(&y_view),
+            x_vec.dist_jensen_shannon(&y_vec)
+        );
+        assert_eq!(x_view.sum(), x_vec.sum());
+        assert_eq!(x_view.to_vector().as_ref(), x_vec.as_ref());
+    }
+
+    #[test]
+    fn test_view_bad_dimensions() {
+        let data = vec![0.0; 12];
+        let err = VectorView::<X1024, Auto>::try_from_slice(&data).unwrap_err();
+        assert!(matches!(
+            err,
+            VectorCreateError::BadDimensions {
+                expected: 1024,
+                got: 12
+            }
+        ));
+    }
+}
@@ -29,7 +29,7 @@ fn main() -> anyhow::Result<()> {
 
     info!("Starting graph build");
     let start = Instant::now();
-    let graph = NNDescentBuilder::new()
+    let index = NNDescentBuilder::new()
         .with_data(embeddings)
         .with_metric(Metric::SquaredEuclidean)
         .with_n_neighbors(30)
@@ -38,5 +38,9 @@ fn main() -> anyhow::Result<()> {
         .build();
     info!("Building took {:?}", start.elapsed());
 
+    let start = Instant::now();
+    let results = index.query(&index.data()[0], 10, 0.1);
+    info!("Query took {:?}, results: {results:?}", start.elapsed());
+
     Ok(())
 }
@@ -0,0 +1,402 @@
+use std::cmp;
+use std::fmt::{Display, Formatter};
+
+use bitvec::vec::BitVec;
+use eonn_accel::{
+    Arch,
+    DangerousOps,
+    Dim,
+    PqCodeSize,
+    PqCodes,
+    PqTrainError,
+    ProductQuantizer,
+    SpacialOps,
+    Vector,
+};
+
+use crate::graph::SearchGraph;
+use crate::metric::Metric;
+use crate::nndescent::beam_search;
+use crate::rp_trees::Tree;
+
+/// The maximum number of points used to train the codebooks.
+const MAX_TRAIN_SAMPLES: usize = 65_536;
+
+#[derive(Debug)]
+/// An error that occurs while compressing an [NNDescent](crate::NNDescent) index.
+pub enum CompressError {
+    /// The metric can not be computed from the product quantization lookup tables.
+    UnsupportedMetric(Metric),
+    /// The product quantizer could not be trained on the indexed points.
+    Train(PqTrainError),
+}
+
+impl Display for CompressError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedMetric(metric) => write!(
+                f,
+                "Unsupported Metric: {metric:?} can not be computed from compressed \
+                vectors, only dot, cosine, inner product and squared Euclidean are \
+                supported"
+            ),
+            Self::Train(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for CompressError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Train(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<PqTrainError> for CompressError {
+    fn from(e: PqTrainError) -> Self {
+        Self::Train(e)
+    }
+}
+
+/// A read-only [NNDescent](crate::NNDescent) index with the vectors held as
+/// product quantization codes, created with
+/// [NNDescent::compress](crate::NNDescent::compress).
+///
+/// Each vector is stored in `n_subspaces` bytes rather than `4 * dims` so very large
+/// datasets can be searched in memory, at the cost of the distances being an
+/// approximation computed from a per-query lookup table. The search graph and
+/// RP forest of the original index are kept as is.
+pub struct CompressedIndex<D: Dim, A: Arch>
+where
+    (D, A): DangerousOps,
+{
+    quantizer: ProductQuantizer<D>,
+    codes: PqCodes,
+    /// The squared norms of the decoded vectors, only populated for [Metric::Cosine].
+    norms: Vec<f32>,
+    metric: Metric,
+    search_graph: SearchGraph,
+    search_forest: Vec<Tree<Vector<D, A>>>,
+    removed: BitVec,
+}
+
+impl<D: Dim, A: Arch> CompressedIndex<D, A>
+where
+    (D, A): DangerousOps,
+{
+    /// Trains a product quantizer over the live points and encodes every point.
+    pub(crate) fn new(
+        data: &[Vector<D, A>],
+        metric: Metric,
+        search_graph: SearchGraph,
+        search_forest: Vec<Tree<Vector<D, A>>>,
+        removed: BitVec,
+        n_subspaces: usize,
+        code_size: PqCodeSize,
+    ) -> Result<Self, CompressError> {
+        if !matches!(
+            metric,
+            Metric::Dot
+                | Metric::Cosine
+                | Metric::InnerProduct
+                | Metric::SquaredEuclidean
+        ) {
+            return Err(CompressError::UnsupportedMetric(metric));
+        }
+
+        let live = data
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !removed[*idx])
+            .map(|(_, vector)| vector)
+            .collect::<Vec<_>>();
+
+        // Evenly spaced points are used so the sample covers the whole dataset.
+        let n_samples = cmp::min(live.len(), MAX_TRAIN_SAMPLES);
+        let sample = (0..n_samples)
+            .map(|i| live[i * live.len() / n_samples].as_view().to_vector())
+            .collect::<Vec<_>>();
+
+        let quantizer = ProductQuantizer::train(&sample, n_subspaces, code_size)?;
+        let codes = quantizer
+            .encode_all(data)
+            .expect("Indexed vectors must match the dimensions of the sample");
+
+        let norms = if metric == Metric::Cosine {
+            (0..codes.len())
+                .map(|idx| quantizer.decode::<A>(codes.get(idx)).squared_norm())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            quantizer,
+            codes,
+            norms,
+            metric,
+            search_graph,
+            search_forest,
+            removed,
+        })
+    }
+
+    #[inline]
+    /// The distance metric used by the graph.
+    pub fn metric(&self) -> &Metric {
+        &self.metric
+    }
+
+    #[inline]
+    /// The product quantizer the vectors were encoded with.
+    pub fn quantizer(&self) -> &ProductQuantizer<D> {
+        &self.quantizer
+    }
+
+    #[inline]
+    /// The product quantization codes of every point.
+    pub fn codes(&self) -> &PqCodes {
+        &self.codes
+    }
+
+    #[inline]
+    /// The pruned and diversified graph used when searching.
+    pub fn search_graph(&self) -> &SearchGraph {
+        &self.search_graph
+    }
+
+    #[inline]
+    /// The number of points in the index, including removed points.
+    pub fn n_vertices(&self) -> usize {
+        self.codes.len()
+    }
+
+    #[inline]
+    /// Returns if point `idx` was removed from the index before it was compressed.
+    pub fn is_removed(&self, idx: usize) -> bool {
+        self.removed[idx]
+    }
+
+    #[inline]
+    /// Returns the approximation of the vector for point `idx` decoded from its code.
+    pub fn vector(&self, idx: usize) -> Vector<D, A> {
+        self.quantizer.decode(self.codes.get(idx))
+    }
+
+    /// Searches the graph for the approximate `k` nearest neighbors of `query`.
+    ///
+    /// This behaves like [NNDescent::query](crate::NNDescent::query), but the
+    /// distances are computed between the query and the decoded vectors, so results
+    /// should be re-ranked against the original vectors if exact ordering matters.
+    ///
+    /// NOTE:
+    /// If the metric requires normalizing, the `query` vector must already be normalized.
+    pub fn query(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let seeds = self
+            .search_forest
+            .iter()
+            .flat_map(|tree| tree.search_leaf(query).iter().copied());
+
+        let table = match self.metric {
+            Metric::SquaredEuclidean => self.quantizer.lookup_table(query),
+            _ => self.quantizer.inner_product_table(query),
+        }
+        .expect("Query must match the dimensions of the index");
+        let query_norm = query.squared_norm();
+
+        beam_search(
+            self.codes.len(),
+            k,
+            epsilon,
+            seeds,
+            |idx| self.search_graph.neighbors(idx),
+            |idx| {
+                let value = self.codes.lookup(&table, idx);
+                match self.metric {
+                    Metric::Dot if value <= 0.0 => 1.0,
+                    Metric::Dot => 1.0 - value,
+                    Metric::Cosine => cosine(value, query_norm, self.norms[idx]),
+                    Metric::InnerProduct => -value,
+                    _ => value,
+                }
+            },
+            |idx| !self.is_removed(idx),
+        )
+    }
+
+    /// Searches the graph like [CompressedIndex::query], reporting the true distance
+    /// of each neighbor, see [Metric::true_distance].
+    pub fn query_true_distances(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.true_distance(*dist);
+        }
+        results
+    }
+
+    /// Searches the graph like [CompressedIndex::query], reporting the similarity
+    /// score of each neighbor, see [Metric::to_similarity].
+    pub fn query_similarities(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.to_similarity(*dist);
+        }
+        results
+    }
+}
+
+#[inline]
+/// Computes the cosine distance from the dot product and squared norms, matching
+/// the zero norm handling of the vector ops.
+fn cosine(dot_product: f32, norm_x: f32, norm_y: f32) -> f32 {
+    if norm_x == 0.0 || norm_y == 0.0 {
+        0.0
+    } else {
+        1.0 - dot_product / (norm_x * norm_y).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eonn_accel::{Auto, X512};
+
+    use super::*;
+    use crate::{NNDescent, NNDescentBuilder};
+
+    fn test_data(n: usize) -> Vec<Vector<X512, Auto>> {
+        (0..n)
+            .map(|_| {
+                let v = std::iter::repeat_with(fastrand::f32).take(512).collect();
+                Vector::try_from_vec(v).expect("Load vec")
+            })
+            .collect()
+    }
+
+    fn build_index(metric: Metric) -> NNDescent<Vector<X512, Auto>> {
+        NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_metric(metric)
+            .with_n_neighbors(10)
+            .build()
+    }
+
+    #[test]
+    fn test_compressed_query() {
+        for code_size in [PqCodeSize::Bits4, PqCodeSize::Bits8] {
+            let index = build_index(Metric::SquaredEuclidean);
+            let queries = index
+                .data()
+                .iter()
+                .take(20)
+                .map(|v| v.as_view().to_vector())
+                .collect::<Vec<_>>();
+
+            let compressed = index.compress(128, code_size).expect("Compress index");
+            assert_eq!(compressed.n_vertices(), 300);
+            assert_eq!(compressed.codes().n_subspaces(), 128);
+
+            let mut n_found = 0;
+            for (p, query) in queries.iter().enumerate() {
+                let results = compressed.query(query, 5, 0.1);
+                assert_eq!(results.len(), 5);
+                assert!(results.windows(2).all(|w| w[0].1 <= w[1].1));
+
+                let expected = compressed
+                    .vector(results[0].0)
+                    .dist_squared_euclidean(query);
+                assert!((results[0].1 - expected).abs() <= expected * 1e-4 + 1e-3);
+
+                if results.iter().any(|&(idx, _)| idx == p) {
+                    n_found += 1;
+                }
+            }
+            assert!(n_found >= 18, "{code_size:?} found {n_found}");
+
+            let true_distances = compressed.query_true_distances(&queries[0], 5, 0.1);
+            let similarities = compressed.query_similarities(&queries[0], 5, 0.1);
+            for ((_, dist), (_, sim)) in true_distances.iter().zip(&similarities) {
+                assert!((1.0 / (1.0 + dist) - sim).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_metrics() {
+        for metric in [Metric::Dot, Metric::Cosine, Metric::InnerProduct] {
+            let index = build_index(metric.clone());
+            let query = index.data()[7].as_view().to_vector();
+            let compressed = index
+                .compress(64, PqCodeSize::Bits8)
+                .expect("Compress index");
+
+            let results = compressed.query(&query, 5, 0.1);
+            assert_eq!(results.len(), 5);
+            // The point itself is not necessarily the best inner product match.
+            if metric != Metric::InnerProduct {
+                assert!(results.iter().any(|&(idx, _)| idx == 7), "{metric:?}");
+            }
+
+            for (idx, dist) in results {
+                let expected = metric.distance(&query, &compressed.vector(idx));
+                assert!(
+                    (dist - expected).abs() < 1e-3,
+                    "{metric:?} {dist} {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_removed() {
+        let mut index = build_index(Metric::SquaredEuclidean);
+        let query = index.data()[3].as_view().to_vector();
+        index.remove(3);
+
+        let compressed = index
+            .compress(64, PqCodeSize::Bits4)
+            .expect("Compress index");
+        assert!(compressed.is_removed(3));
+
+        let results = compressed.query(&query, 10, 0.1);
+        assert!(results.iter().all(|&(idx, _)| idx != 3));
+    }
+
+    #[test]
+    fn test_compress_errors() {
+        let err = build_index(Metric::Manhattan)
+            .compress(64, PqCodeSize::Bits4)
+            .err()
+            .expect("Compress should fail");
+        assert!(matches!(
+            err,
+            CompressError::UnsupportedMetric(Metric::Manhattan)
+        ));
+
+        let err = build_index(Metric::SquaredEuclidean)
+            .compress(7, PqCodeSize::Bits4)
+            .err()
+            .expect("Compress should fail");
+        assert!(matches!(
+            err,
+            CompressError::Train(PqTrainError::BadSubspaces { .. })
+        ));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
@@ -0,0 +1,449 @@
+use std::fmt::{Display, Formatter};
+
+use eonn_accel::{
+    Auto,
+    DangerousOps,
+    Dim,
+    SpacialOps,
+    Vector,
+    VectorCreateError,
+    XAny,
+    X1024,
+    X2,
+    X512,
+    X768,
+};
+
+use crate::metric::{Metric, ParseMetricError};
+use crate::nndescent::{BuildError, NNDescent, NNDescentBuilder};
+
+#[derive(Debug, Clone, PartialEq)]
+/// An error that occurs while building or using a [DynIndex].
+pub enum DynIndexError {
+    /// The metric name is not one of the built-in metrics.
+    UnknownMetric(ParseMetricError),
+    /// The index could not be built from the given rows and configuration.
+    Build(BuildError),
+    /// A query or inserted row has a different number of dimensions to the index.
+    BadDimensions { expected: usize, got: usize },
+    /// A row or query contains some non-finite values or Nan.
+    NonFinite,
+}
+
+impl Display for DynIndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownMetric(e) => write!(f, "{e}"),
+            Self::Build(e) => write!(f, "{e}"),
+            Self::BadDimensions { expected, got } => {
+                write!(f, "Bad Dimensions: expected {expected} but got {got}")
+            },
+            Self::NonFinite => {
+                write!(f, "Non-Finite Value: values in rows must be finite")
+            },
+        }
+    }
+}
+
+impl std::error::Error for DynIndexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnknownMetric(e) => Some(e),
+            Self::Build(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseMetricError> for DynIndexError {
+    fn from(e: ParseMetricError) -> Self {
+        Self::UnknownMetric(e)
+    }
+}
+
+impl From<BuildError> for DynIndexError {
+    fn from(e: BuildError) -> Self {
+        Self::Build(e)
+    }
+}
+
+impl From<VectorCreateError> for DynIndexError {
+    fn from(e: VectorCreateError) -> Self {
+        match e {
+            VectorCreateError::BadDimensions { expected, got } => {
+                Self::BadDimensions { expected, got }
+            },
+            VectorCreateError::NonFinite => Self::NonFinite,
+        }
+    }
+}
+
+/// The index for each supported vector type, selected from the number of
+/// dimensions of the data.
+enum Inner {
+    X2(NNDescent<Vector<X2, Auto>>),
+    X512(NNDescent<Vector<X512, Auto>>),
+    X768(NNDescent<Vector<X768, Auto>>),
+    X1024(NNDescent<Vector<X1024, Auto>>),
+    XAny(NNDescent<Vector<XAny, Auto>>),
+}
+
+/// Runs the same expression against whichever index is selected.
+macro_rules! dispatch {
+    ($inner:expr, $index:ident => $body:expr) => {
+        match $inner {
+            Inner::X2($index) => $body,
+            Inner::X512($index) => $body,
+            Inner::X768($index) => $body,
+            Inner::X1024($index) => $body,
+            Inner::XAny($index) => $body,
+        }
+    };
+}
+
+/// A type-erased [NNDescent] index over rows of `f32` values.
+///
+/// The vector dimensions and metric are picked at runtime, which makes this
+/// suitable for FFI bindings or services which read their configuration from a
+/// file. Data with `2`, `512`, `768` or `1024` dimensions uses the matching
+/// fixed-size vectors, any other size falls back to [XAny]. The CPU features
+/// are always selected at runtime with [Auto].
+///
+/// ```ignore
+/// let index = DynIndex::build(rows, "cosine")?;
+/// let neighbors = index.query(&query, 10, 0.1)?;
+/// ```
+pub struct DynIndex {
+    inner: Inner,
+    dims: usize,
+}
+
+impl DynIndex {
+    /// Builds an index from the given rows with the default configuration and the
+    /// metric with the given name, see [Metric::from_str](std::str::FromStr) for
+    /// the accepted names.
+    pub fn build(rows: Vec<Vec<f32>>, metric: &str) -> Result<Self, DynIndexError> {
+        let metric = metric.parse::<Metric>()?;
+        Self::build_with(NNDescentBuilder::new().with_metric(metric), rows)
+    }
+
+    /// Builds an index from the given rows using the configuration of `builder`.
+    ///
+    /// Any data already set on the builder is replaced by the rows.
+    pub fn build_with<V: SpacialOps>(
+        builder: NNDescentBuilder<V, Metric>,
+        rows: Vec<Vec<f32>>,
+    ) -> Result<Self, DynIndexError> {
+        let dims = rows.first().ok_or(BuildError::EmptyData)?.len();
+
+        if let Some((idx, row)) = rows.iter().enumerate().find(|(_, r)| r.len() != dims)
+        {
+            return Err(BuildError::MismatchedDimensions {
+                idx,
+                expected: dims,
+                got: row.len(),
+            }
+            .into());
+        }
+
+        if rows.iter().flatten().any(|v| !v.is_finite()) {
+            return Err(DynIndexError::NonFinite);
+        }
+
+        fn build<D: Dim + Send + Sync + 'static, V: SpacialOps>(
+            builder: NNDescentBuilder<V, Metric>,
+            rows: Vec<Vec<f32>>,
+        ) -> Result<NNDescent<Vector<D, Auto>>, DynIndexError>
+        where
+            (D, Auto): DangerousOps,
+        {
+            let data = rows
+                .into_iter()
+                .map(Vector::try_from_vec)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(builder.with_data(data).try_build()?)
+        }
+
+        let inner = match dims {
+            2 => Inner::X2(build(builder, rows)?),
+            512 => Inner::X512(build(builder, rows)?),
+            768 => Inner::X768(build(builder, rows)?),
+            1024 => Inner::X1024(build(builder, rows)?),
+            _ => Inner::XAny(build(builder, rows)?),
+        };
+
+        Ok(Self { inner, dims })
+    }
+
+    #[inline]
+    /// The number of dimensions of each row.
+    pub fn dims(&self) -> usize {
+        self.dims
+    }
+
+    #[inline]
+    /// The number of points in the index, including any which have been removed.
+    pub fn len(&self) -> usize {
+        dispatch!(&self.inner, index => index.data().len())
+    }
+
+    #[inline]
+    /// Returns if the index contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    /// The distance metric the index was built with.
+    pub fn metric(&self) -> &Metric {
+        dispatch!(&self.inner, index => index.metric())
+    }
+
+    #[inline]
+    /// Returns the values of the point at `idx`, after any normalization applied
+    /// by the metric.
+    ///
+    /// # Panics
+    /// If `idx` is out of bounds.
+    pub fn row(&self, idx: usize) -> &[f32] {
+        dispatch!(&self.inner, index => &index.data()[idx])
+    }
+
+    /// Searches the index for the `k` nearest neighbors of `query`, see
+    /// [NNDescent::query].
+    ///
+    /// The query is normalized first if the metric requires it.
+    pub fn query(
+        &self,
+        query: &[f32],
+        k: usize,
+        epsilon: f32,
+    ) -> Result<Vec<(usize, f32)>, DynIndexError> {
+        self.check_row(query)?;
+
+        dispatch!(&self.inner, index => {
+            let mut query = Vector::try_from_vec(query.to_vec())?;
+            if index.metric().requires_normalizing() {
+                query.normalize();
+            }
+            Ok(index.query(&query, k, epsilon))
+        })
+    }
+
+    /// Searches the index like [DynIndex::query], reporting the true distance of
+    /// each neighbor, see [Metric::true_distance].
+    pub fn query_true_distances(
+        &self,
+        query: &[f32],
+        k: usize,
+        epsilon: f32,
+    ) -> Result<Vec<(usize, f32)>, DynIndexError> {
+        let mut results = self.query(query, k, epsilon)?;
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric().true_distance(*dist);
+        }
+        Ok(results)
+    }
+
+    /// Searches the index like [DynIndex::query], reporting the similarity score of
+    /// each neighbor, see [Metric::to_similarity].
+    ///
+    /// Returns `(idx, similarity)` pairs sorted by descending similarity.
+    pub fn query_similarities(
+        &self,
+        query: &[f32],
+        k: usize,
+        epsilon: f32,
+    ) -> Result<Vec<(usize, f32)>, DynIndexError> {
+        let mut results = self.query(query, k, epsilon)?;
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric().to_similarity(*dist);
+        }
+        Ok(results)
+    }
+
+    /// Inserts a new row into the index, returning its index, see
+    /// [NNDescent::insert].
+    pub fn insert(&mut self, row: Vec<f32>) -> Result<usize, DynIndexError> {
+        self.check_row(&row)?;
+
+        dispatch!(&mut self.inner, index => {
+            Ok(index.insert(Vector::try_from_vec(row)?))
+        })
+    }
+
+    /// Marks the point at `idx` as removed, returning `false` if it was
+    /// already removed, see [NNDescent::remove].
+    ///
+    /// # Panics
+    /// If `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> bool {
+        dispatch!(&mut self.inner, index => index.remove(idx))
+    }
+
+    #[inline]
+    /// Returns if the point at `idx` has been removed.
+    pub fn is_removed(&self, idx: usize) -> bool {
+        dispatch!(&self.inner, index => index.is_removed(idx))
+    }
+
+    fn check_row(&self, row: &[f32]) -> Result<(), DynIndexError> {
+        if row.len() != self.dims {
+            return Err(DynIndexError::BadDimensions {
+                expected: self.dims,
+                got: row.len(),
+            });
+        }
+
+        if row.iter().any(|v| !v.is_finite()) {
+            return Err(DynIndexError::NonFinite);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_rows(n: usize, dims: usize) -> Vec<Vec<f32>> {
+        (0..n)
+            .map(|_| (0..dims).map(|_| fastrand::f32()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_metric_from_str() {
+        assert_eq!("cosine".parse(), Ok(Metric::Cosine));
+        assert_eq!("Cosine".parse(), Ok(Metric::Cosine));
+        assert_eq!("l2".parse(), Ok(Metric::SquaredEuclidean));
+        assert_eq!("euclidean".parse(), Ok(Metric::SquaredEuclidean));
+        assert_eq!("l1".parse(), Ok(Metric::Manhattan));
+        assert_eq!("linf".parse(), Ok(Metric::Chebyshev));
+        assert_eq!("ip".parse(), Ok(Metric::InnerProduct));
+        assert_eq!("jensen_shannon".parse(), Ok(Metric::JensenShannon));
+
+        let e = "minkowski".parse::<Metric>().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Unknown Metric: \"minkowski\" is not a known metric name"
+        );
+    }
+
+    #[test]
+    fn test_dyn_index_selects_dims() {
+        for dims in [2, 37, 512, 768] {
+            let index = DynIndex::build(random_rows(100, dims), "l2").unwrap();
+            assert_eq!(index.dims(), dims);
+            assert_eq!(index.len(), 100);
+
+            let selected = match &index.inner {
+                Inner::X2(_) => 2,
+                Inner::X512(_) => 512,
+                Inner::X768(_) => 768,
+                Inner::X1024(_) => 1024,
+                Inner::XAny(_) => 0,
+            };
+            assert_eq!(selected, if dims == 37 { 0 } else { dims });
+
+            for idx in [0, 42, 99] {
+                let query = index.row(idx).to_vec();
+                let results = index.query(&query, 5, 0.1).unwrap();
+                assert_eq!(results[0].0, idx);
+
+                let true_distances = index.query_true_distances(&query, 5, 0.1).unwrap();
+                for ((_, dist), (_, true_dist)) in results.iter().zip(&true_distances) {
+                    assert_eq!(dist.sqrt(), *true_dist);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dyn_index_normalizes_queries() {
+        let index = DynIndex::build(random_rows(200, 64), "dot").unwrap();
+        assert_eq!(index.metric(), &Metric::Dot);
+
+        let query = index.row(17).iter().map(|v| v * 3.0).collect::<Vec<_>>();
+        let results = index.query(&query, 5, 0.1).unwrap();
+        assert_eq!(results[0].0, 17);
+
+        // The normalized query is identical to the point, so it has a product of `1.0`.
+        let similarities = index.query_similarities(&query, 5, 0.1).unwrap();
+        assert_eq!(similarities[0].0, 17);
+        assert!((similarities[0].1 - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_dyn_index_build_with() {
+        let builder = NNDescentBuilder::new()
+            .with_metric("cosine".parse().unwrap())
+            .with_n_neighbors(8)
+            .with_seed(7);
+        let index = DynIndex::build_with(builder, random_rows(100, 24)).unwrap();
+        assert_eq!(index.metric(), &Metric::Cosine);
+        dispatch!(&index.inner, inner => {
+            assert_eq!(inner.graph().n_neighbors(), 8);
+        });
+    }
+
+    #[test]
+    fn test_dyn_index_insert_remove() {
+        let mut index = DynIndex::build(random_rows(100, 16), "l2").unwrap();
+
+        let row = random_rows(1, 16).remove(0);
+        let idx = index.insert(row.clone()).unwrap();
+        assert_eq!(idx, 100);
+        assert_eq!(index.len(), 101);
+        assert_eq!(index.query(&row, 1, 0.1).unwrap()[0].0, idx);
+
+        assert!(index.remove(idx));
+        assert!(index.is_removed(idx));
+        assert!(!index.remove(idx));
+        assert_ne!(index.query(&row, 1, 0.1).unwrap()[0].0, idx);
+    }
+
+    #[test]
+    fn test_dyn_index_errors() {
+        assert!(matches!(
+            DynIndex::build(random_rows(10, 4), "bogus"),
+            Err(DynIndexError::UnknownMetric(_))
+        ));
+        assert!(matches!(
+            DynIndex::build(Vec::new(), "l2"),
+            Err(DynIndexError::Build(BuildError::EmptyData))
+        ));
+
+        let mut rows = random_rows(10, 4);
+        rows[3].push(1.0);
+        assert!(matches!(
+            DynIndex::build(rows, "l2"),
+            Err(DynIndexError::Build(BuildError::MismatchedDimensions {
+                idx: 3,
+                ..
+            }))
+        ));
+
+        let mut rows = random_rows(10, 4);
+        rows[5][1] = f32::NAN;
+        assert_eq!(
+            DynIndex::build(rows, "l2").err(),
+            Some(DynIndexError::NonFinite)
+        );
+
+        let mut index = DynIndex::build(random_rows(50, 4), "l2").unwrap();
+        assert_eq!(
+            index.query(&[1.0; 5], 5, 0.1).err(),
+            Some(DynIndexError::BadDimensions {
+                expected: 4,
+                got: 5
+            })
+        );
+        assert_eq!(
+            index.insert(vec![f32::INFINITY; 4]).err(),
+            Some(DynIndexError::NonFinite)
+        );
+        assert_eq!(index.len(), 50);
+    }
+}
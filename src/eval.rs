@@ -0,0 +1,343 @@
+//! Tools for measuring the quality of an approximate nearest neighbor graph.
+//!
+//! The exact nearest neighbors are computed by brute force, which is `O(n * m)`
+//! distance calculations, so these are intended for tuning the builder parameters
+//! on a sample of the data rather than for use at query time.
+//!
+//! Neighbors are represented as `(index, distance)` pairs sorted by ascending
+//! distance, the same as the results returned by [NNDescent::query](crate::NNDescent::query).
+use eonn_accel::SpacialOps;
+
+use crate::graph::{DynamicGraph, SortedNeighbors};
+use crate::Distance;
+
+/// The quality of a single query's results against the ground truth.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct QueryStats {
+    /// The fraction of the true `k` nearest neighbors which were found.
+    pub recall: f32,
+    /// The relative error of the sum of the result distances compared to the
+    /// sum of the true distances.
+    ///
+    /// This is `0.0` when the results are exact.
+    pub relative_distance_error: f32,
+    /// The number of results that were evaluated.
+    pub n_results: usize,
+}
+
+/// The quality of a set of results against the ground truth.
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    /// The number of neighbors evaluated per query.
+    pub k: usize,
+    /// The mean recall@k over all queries.
+    pub recall: f32,
+    /// The lowest recall@k of any query.
+    pub min_recall: f32,
+    /// The mean relative distance error over all queries.
+    pub mean_relative_distance_error: f32,
+    /// The statistics of each individual query.
+    pub queries: Vec<QueryStats>,
+}
+
+/// Computes the exact `k` nearest neighbors of each query vector in `data`.
+pub fn brute_force_knn<V: SpacialOps, M: Distance<V>>(
+    data: &[V],
+    queries: &[V],
+    metric: M,
+    k: usize,
+) -> Vec<Vec<(usize, f32)>> {
+    queries
+        .iter()
+        .map(|query| exact_neighbors(data, query, &metric, k))
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+/// Computes the exact `k` nearest neighbors of each query vector in `data`,
+/// spreading the queries across the given thread pool.
+pub fn brute_force_knn_parallel<V, M>(
+    data: &[V],
+    queries: &[V],
+    metric: M,
+    k: usize,
+    pool: &rayon::ThreadPool,
+) -> Vec<Vec<(usize, f32)>>
+where
+    V: SpacialOps + Send + Sync,
+    M: Distance<V> + Sync,
+{
+    use rayon::prelude::*;
+
+    pool.install(|| {
+        queries
+            .par_iter()
+            .map(|query| exact_neighbors(data, query, &metric, k))
+            .collect()
+    })
+}
+
+/// Evaluates the first `k` neighbors of each point in the graph against the
+/// ground truth.
+///
+/// The graph contains each point as its own nearest neighbor, so the ground truth
+/// should be the result of [brute_force_knn] using `data` as the queries.
+///
+/// # Panics
+/// If the graph and ground truth contain a different number of points.
+pub fn evaluate_graph(
+    graph: &DynamicGraph,
+    ground_truth: &[Vec<(usize, f32)>],
+    k: usize,
+) -> Evaluation {
+    assert_eq!(
+        graph.n_vertices(),
+        ground_truth.len(),
+        "Graph and ground truth must contain the same number of points"
+    );
+
+    let queries = ground_truth
+        .iter()
+        .enumerate()
+        .map(|(idx, truth)| {
+            let results = graph
+                .point(idx)
+                .iter_neighbors()
+                .map(|p| (p.idx() as usize, p.dist()))
+                .collect::<Vec<_>>();
+            query_stats(&results, truth, k)
+        })
+        .collect();
+
+    Evaluation::from_queries(k, queries)
+}
+
+/// Evaluates the first `k` results of each query against the ground truth,
+/// i.e. from [brute_force_knn].
+///
+/// # Panics
+/// If the results and ground truth contain a different number of queries.
+pub fn evaluate_results(
+    results: &[Vec<(usize, f32)>],
+    ground_truth: &[Vec<(usize, f32)>],
+    k: usize,
+) -> Evaluation {
+    assert_eq!(
+        results.len(),
+        ground_truth.len(),
+        "Results and ground truth must contain the same number of queries"
+    );
+
+    let queries = results
+        .iter()
+        .zip(ground_truth)
+        .map(|(results, truth)| query_stats(results, truth, k))
+        .collect();
+
+    Evaluation::from_queries(k, queries)
+}
+
+/// Computes the fraction of the first `k` true neighbors that are present
+/// in the first `k` results.
+pub fn recall_at_k(
+    results: &[(usize, f32)],
+    ground_truth: &[(usize, f32)],
+    k: usize,
+) -> f32 {
+    let truth = &ground_truth[..k.min(ground_truth.len())];
+    if truth.is_empty() {
+        return 1.0;
+    }
+
+    let results = &results[..k.min(results.len())];
+    let n_found = truth
+        .iter()
+        .filter(|(idx, _)| results.iter().any(|(r, _)| r == idx))
+        .count();
+
+    n_found as f32 / truth.len() as f32
+}
+
+/// Computes the relative error of the sum of the first `k` result distances
+/// compared to the first `k` true distances.
+///
+/// If the true distances sum to zero the error is `0.0` when the results also
+/// sum to zero and infinite otherwise.
+pub fn relative_distance_error(
+    results: &[(usize, f32)],
+    ground_truth: &[(usize, f32)],
+    k: usize,
+) -> f32 {
+    let n = k.min(results.len()).min(ground_truth.len());
+
+    let result_sum = results[..n].iter().map(|(_, d)| *d as f64).sum::<f64>();
+    let truth_sum = ground_truth[..n]
+        .iter()
+        .map(|(_, d)| *d as f64)
+        .sum::<f64>();
+
+    if truth_sum.abs() <= f64::EPSILON {
+        return if (result_sum - truth_sum).abs() <= f64::EPSILON {
+            0.0
+        } else {
+            f32::INFINITY
+        };
+    }
+
+    ((result_sum - truth_sum) / truth_sum.abs()) as f32
+}
+
+impl Evaluation {
+    fn from_queries(k: usize, queries: Vec<QueryStats>) -> Self {
+        let n = queries.len().max(1) as f32;
+
+        let recall = queries.iter().map(|q| q.recall).sum::<f32>() / n;
+        let min_recall = queries.iter().map(|q| q.recall).reduce(f32::min);
+        let mean_relative_distance_error = queries
+            .iter()
+            .map(|q| q.relative_distance_error)
+            .sum::<f32>()
+            / n;
+
+        Self {
+            k,
+            recall,
+            min_recall: min_recall.unwrap_or(1.0),
+            mean_relative_distance_error,
+            queries,
+        }
+    }
+}
+
+fn query_stats(
+    results: &[(usize, f32)],
+    ground_truth: &[(usize, f32)],
+    k: usize,
+) -> QueryStats {
+    QueryStats {
+        recall: recall_at_k(results, ground_truth, k),
+        relative_distance_error: relative_distance_error(results, ground_truth, k),
+        n_results: k.min(results.len()),
+    }
+}
+
+fn exact_neighbors<V: SpacialOps, M: Distance<V>>(
+    data: &[V],
+    query: &V,
+    metric: &M,
+    k: usize,
+) -> Vec<(usize, f32)> {
+    let mut neighbors = SortedNeighbors::new(k);
+
+    for (idx, point) in data.iter().enumerate() {
+        neighbors.checked_push(metric.distance(query, point), idx);
+    }
+
+    neighbors
+        .iter_neighbors()
+        .map(|p| (p.idx() as usize, p.dist()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use eonn_accel::{Auto, Vector, X512};
+
+    use super::*;
+    use crate::{Metric, NNDescentBuilder};
+
+    fn test_data(n: usize) -> Vec<Vector<X512, Auto>> {
+        (0..n)
+            .map(|_| {
+                let v = Vec::from_iter(
+                    std::iter::from_fn(|| Some(fastrand::f32())).take(512),
+                );
+                Vector::try_from_vec(v).expect("Load vec")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_brute_force_knn() {
+        let data = test_data(50);
+        let truth = brute_force_knn(&data, &data[..5], Metric::SquaredEuclidean, 4);
+
+        assert_eq!(truth.len(), 5);
+        for (idx, neighbors) in truth.iter().enumerate() {
+            assert_eq!(neighbors.len(), 4);
+            assert_eq!(neighbors[0], (idx, 0.0));
+            assert!(neighbors.windows(2).all(|w| w[0].1 <= w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_recall_and_distance_error() {
+        let truth = [(0, 1.0), (1, 2.0), (2, 3.0), (3, 4.0)];
+
+        assert_eq!(recall_at_k(&truth, &truth, 4), 1.0);
+        assert_eq!(relative_distance_error(&truth, &truth, 4), 0.0);
+
+        let results = [(0, 1.0), (2, 3.0), (4, 4.0), (5, 6.0)];
+        assert_eq!(recall_at_k(&results, &truth, 4), 0.5);
+        assert_eq!(recall_at_k(&results, &truth, 1), 1.0);
+        assert_eq!(relative_distance_error(&results, &truth, 4), 0.4);
+
+        assert_eq!(recall_at_k(&[], &truth, 4), 0.0);
+        assert_eq!(recall_at_k(&[], &[], 4), 1.0);
+        assert_eq!(
+            relative_distance_error(&[(0, 1.0)], &[(1, 0.0)], 1),
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_evaluate_results() {
+        let truth = vec![vec![(0, 1.0), (1, 2.0)], vec![(2, 1.0), (3, 1.0)]];
+        let results = vec![vec![(0, 1.0), (1, 2.0)], vec![(2, 1.0), (4, 3.0)]];
+
+        let eval = evaluate_results(&results, &truth, 2);
+        assert_eq!(eval.k, 2);
+        assert_eq!(eval.recall, 0.75);
+        assert_eq!(eval.min_recall, 0.5);
+        assert_eq!(eval.mean_relative_distance_error, 0.5);
+        assert_eq!(eval.queries[1].recall, 0.5);
+        assert_eq!(eval.queries[1].n_results, 2);
+    }
+
+    #[test]
+    fn test_evaluate_graph() {
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .with_seed(1)
+            .build();
+
+        let truth = brute_force_knn(index.data(), index.data(), index.metric(), 10);
+        let eval = evaluate_graph(index.graph(), &truth, 10);
+
+        assert_eq!(eval.queries.len(), 300);
+        assert!(eval.recall >= 0.6, "Recall too low: {}", eval.recall);
+        assert!(eval.min_recall <= eval.recall);
+        assert!(eval.mean_relative_distance_error >= 0.0);
+        assert!(eval.mean_relative_distance_error < 0.1);
+
+        let exact = evaluate_results(&truth, &truth, 10);
+        assert_eq!(exact.recall, 1.0);
+        assert_eq!(exact.mean_relative_distance_error, 0.0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_brute_force_knn_parallel() {
+        let data = test_data(100);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        let expected = brute_force_knn(&data, &data[..20], Metric::Cosine, 5);
+        let truth =
+            brute_force_knn_parallel(&data, &data[..20], Metric::Cosine, 5, &pool);
+        assert_eq!(truth, expected);
+    }
+}
@@ -50,6 +50,26 @@ impl DynamicGraph {
         &mut self.points[p]
     }
 
+    /// Adds a new point with no neighbors to the graph, returning its index.
+    pub fn add_point(&mut self) -> usize {
+        self.points.push(SortedNeighbors::new(self.n_neighbors));
+        self.points.len() - 1
+    }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    /// Get all points in the graph.
+    pub(crate) fn points(&self) -> &[SortedNeighbors] {
+        &self.points
+    }
+
+    #[cfg(feature = "rayon")]
+    #[inline]
+    /// Get all points in the graph.
+    pub(crate) fn points_mut(&mut self) -> &mut [SortedNeighbors] {
+        &mut self.points
+    }
+
     #[inline]
     /// The minimum threshold to be within the point's nearest neighbors.
     pub fn threshold(&self, p: usize) -> f32 {
@@ -57,6 +77,293 @@ impl DynamicGraph {
     }
 }
 
+/// A compact, read-only graph used for searching.
+///
+/// The neighbors of each vertex are stored contiguously in CSR format
+/// sorted by ascending distance from the vertex.
+pub struct SearchGraph {
+    indptr: Vec<usize>,
+    indices: Vec<u32>,
+    /// The number of edges pointing to each vertex.
+    ///
+    /// This is only populated once the graph is modified.
+    in_degrees: Vec<u32>,
+}
+
+impl SearchGraph {
+    /// Creates a new [SearchGraph] from the neighbor lists of each vertex.
+    pub fn from_neighbor_lists(neighbor_lists: Vec<Vec<Point>>) -> Self {
+        Self::from_adjacency_lists(
+            neighbor_lists
+                .into_iter()
+                .map(|neighbors| neighbors.into_iter().map(|p| p.idx).collect())
+                .collect(),
+        )
+    }
+
+    /// Creates a new [SearchGraph] from the neighbor indices of each vertex.
+    pub fn from_adjacency_lists(adjacency_lists: Vec<Vec<u32>>) -> Self {
+        let mut indptr = Vec::with_capacity(adjacency_lists.len() + 1);
+        let mut indices =
+            Vec::with_capacity(adjacency_lists.iter().map(|n| n.len()).sum());
+
+        indptr.push(0);
+        for neighbors in adjacency_lists {
+            indices.extend(neighbors);
+            indptr.push(indices.len());
+        }
+
+        Self {
+            indptr,
+            indices,
+            in_degrees: Vec::new(),
+        }
+    }
+
+    /// Creates a new [SearchGraph] from its raw CSR components.
+    ///
+    /// The caller must ensure `indptr` is non-decreasing, starts at `0` and
+    /// ends at `indices.len()`.
+    pub(crate) fn from_raw_parts(indptr: Vec<usize>, indices: Vec<u32>) -> Self {
+        debug_assert_eq!(indptr.first(), Some(&0));
+        debug_assert_eq!(indptr.last(), Some(&indices.len()));
+        Self {
+            indptr,
+            indices,
+            in_degrees: Vec::new(),
+        }
+    }
+
+    #[inline]
+    /// The offsets of each vertex's neighbors within the [SearchGraph::indices].
+    pub(crate) fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    #[inline]
+    /// The neighbors of every vertex in the graph.
+    pub(crate) fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    #[inline]
+    pub fn n_vertices(&self) -> usize {
+        self.indptr.len() - 1
+    }
+
+    #[inline]
+    pub fn n_edges(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    /// Get the neighbors of point `p`.
+    pub fn neighbors(&self, p: usize) -> &[u32] {
+        &self.indices[self.indptr[p]..self.indptr[p + 1]]
+    }
+
+    /// The number of edges pointing to vertex `p`.
+    pub(crate) fn in_degree(&mut self, p: usize) -> u32 {
+        self.ensure_in_degrees();
+        self.in_degrees[p]
+    }
+
+    /// Replaces the `slot`th edge of vertex `p` with an edge to `target`.
+    ///
+    /// This should be preferred over [SearchGraph::push_edge] which has to shift
+    /// the edges of every later vertex.
+    pub(crate) fn replace_edge(&mut self, p: usize, slot: usize, target: u32) {
+        self.ensure_in_degrees();
+        let edge = &mut self.indices[self.indptr[p] + slot];
+        self.in_degrees[*edge as usize] -= 1;
+        self.in_degrees[target as usize] += 1;
+        *edge = target;
+    }
+
+    /// Adds an edge from vertex `p` to `target`.
+    ///
+    /// The edges of every later vertex are shifted, so this is `O(n_edges)`.
+    pub(crate) fn push_edge(&mut self, p: usize, target: u32) {
+        self.ensure_in_degrees();
+        self.indices.insert(self.indptr[p + 1], target);
+        for offset in self.indptr[p + 1..].iter_mut() {
+            *offset += 1;
+        }
+        self.in_degrees[target as usize] += 1;
+    }
+
+    /// Appends a new vertex with the given neighbors to the graph, returning its index.
+    pub(crate) fn push_vertex(
+        &mut self,
+        neighbors: impl IntoIterator<Item = u32>,
+    ) -> usize {
+        self.ensure_in_degrees();
+        self.in_degrees.push(0);
+
+        let start = self.indices.len();
+        self.indices.extend(neighbors);
+        self.indptr.push(self.indices.len());
+        for &idx in self.indices[start..].iter() {
+            self.in_degrees[idx as usize] += 1;
+        }
+
+        self.n_vertices() - 1
+    }
+
+    fn ensure_in_degrees(&mut self) {
+        if self.in_degrees.len() == self.n_vertices() {
+            return;
+        }
+
+        self.in_degrees = vec![0; self.n_vertices()];
+        for &idx in self.indices.iter() {
+            self.in_degrees[idx as usize] += 1;
+        }
+    }
+}
+
+impl Debug for SearchGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SearchGraph(n_vertices={}, n_edges={})",
+            self.n_vertices(),
+            self.n_edges(),
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+/// A read-only view of a nearest neighbor graph.
+///
+/// This mirrors [DynamicGraph] but borrows the neighbors of each point from flat
+/// slices, i.e. a memory mapped index, rather than owning them. Each point has
+/// exactly `n_neighbors` slots sorted by ascending distance, empty slots have an
+/// index of `u32::MAX`.
+pub struct GraphView<'a> {
+    n_neighbors: usize,
+    indices: &'a [u32],
+    distances: &'a [f32],
+}
+
+impl<'a> GraphView<'a> {
+    /// Creates a new [GraphView] from the flattened neighbor indices and distances.
+    pub(crate) fn new(
+        n_neighbors: usize,
+        indices: &'a [u32],
+        distances: &'a [f32],
+    ) -> Self {
+        debug_assert_eq!(indices.len(), distances.len());
+        debug_assert!(n_neighbors == 0 || indices.len().is_multiple_of(n_neighbors));
+        Self {
+            n_neighbors,
+            indices,
+            distances,
+        }
+    }
+
+    #[inline]
+    /// The number of neighbors each point has.
+    pub fn n_neighbors(&self) -> usize {
+        self.n_neighbors
+    }
+
+    #[inline]
+    /// The number of vertices in the graph.
+    pub fn n_vertices(&self) -> usize {
+        self.indices
+            .len()
+            .checked_div(self.n_neighbors)
+            .unwrap_or(0)
+    }
+
+    #[inline]
+    /// Get the neighbor indices of point `p`, including any empty slots.
+    pub fn neighbors(&self, p: usize) -> &'a [u32] {
+        &self.indices[p * self.n_neighbors..(p + 1) * self.n_neighbors]
+    }
+
+    #[inline]
+    /// Get the neighbor distances of point `p`, including any empty slots.
+    pub fn distances(&self, p: usize) -> &'a [f32] {
+        &self.distances[p * self.n_neighbors..(p + 1) * self.n_neighbors]
+    }
+
+    /// Iterates over the populated neighbors of point `p`.
+    pub fn iter_neighbors(&self, p: usize) -> impl Iterator<Item = Point> + 'a {
+        self.neighbors(p)
+            .iter()
+            .zip(self.distances(p))
+            .filter(|(&idx, _)| idx != u32::MAX)
+            .map(|(&idx, &dist)| Point {
+                idx,
+                dist,
+                flag: false,
+            })
+    }
+
+    #[inline]
+    /// The minimum threshold to be within the point's nearest neighbors.
+    pub fn threshold(&self, p: usize) -> f32 {
+        self.distances(p).last().copied().unwrap_or(f32::INFINITY)
+    }
+}
+
+impl<'a> Debug for GraphView<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GraphView(n_vertices={}, n_neighbors={})",
+            self.n_vertices(),
+            self.n_neighbors,
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+/// A read-only view of a [SearchGraph] borrowing its CSR components.
+pub struct SearchGraphView<'a> {
+    indptr: &'a [u64],
+    indices: &'a [u32],
+}
+
+impl<'a> SearchGraphView<'a> {
+    /// Creates a new [SearchGraphView] from its raw CSR components.
+    pub(crate) fn new(indptr: &'a [u64], indices: &'a [u32]) -> Self {
+        debug_assert!(!indptr.is_empty());
+        Self { indptr, indices }
+    }
+
+    #[inline]
+    pub fn n_vertices(&self) -> usize {
+        self.indptr.len() - 1
+    }
+
+    #[inline]
+    pub fn n_edges(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    /// Get the neighbors of point `p`.
+    pub fn neighbors(&self, p: usize) -> &'a [u32] {
+        let start = self.indptr[p] as usize;
+        let end = self.indptr[p + 1] as usize;
+        &self.indices[start..end]
+    }
+}
+
+impl<'a> Debug for SearchGraphView<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SearchGraphView(n_vertices={}, n_edges={})",
+            self.n_vertices(),
+            self.n_edges(),
+        )
+    }
+}
+
 /// A BinaryHeap-like structure maintaining a fixed size.
 ///
 /// Internally this is actually a set of sorted structures
@@ -285,6 +592,15 @@ impl Default for Point {
 }
 
 impl Point {
+    #[inline]
+    pub(crate) fn new(idx: usize, dist: f32) -> Self {
+        Self {
+            idx: idx as u32,
+            dist,
+            flag: false,
+        }
+    }
+
     #[inline]
     pub fn dist(&self) -> f32 {
         self.dist
@@ -356,6 +672,45 @@ mod tests {
         assert!(!heap.furthest().flag);
     }
 
+    #[test]
+    fn test_search_graph() {
+        let graph = SearchGraph::from_neighbor_lists(vec![
+            vec![Point::new(1, 0.5), Point::new(2, 1.0)],
+            vec![],
+            vec![Point::new(0, 1.0)],
+        ]);
+        assert_eq!(graph.n_vertices(), 3);
+        assert_eq!(graph.n_edges(), 3);
+        assert_eq!(graph.neighbors(0), [1, 2]);
+        assert!(graph.neighbors(1).is_empty());
+        assert_eq!(graph.neighbors(2), [0]);
+    }
+
+    #[test]
+    fn test_graph_views() {
+        let indices = [1, u32::MAX, 0, 2, 1, u32::MAX];
+        let distances = [0.5, f32::INFINITY, 0.5, 1.0, 1.0, f32::INFINITY];
+        let graph = GraphView::new(2, &indices, &distances);
+        assert_eq!(graph.n_vertices(), 3);
+        assert_eq!(graph.neighbors(1), [0, 2]);
+        assert_eq!(graph.threshold(1), 1.0);
+        assert_eq!(graph.threshold(0), f32::INFINITY);
+
+        let points = graph.iter_neighbors(0).collect::<Vec<_>>();
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].idx(), 1);
+        assert_eq!(points[0].dist(), 0.5);
+
+        let indptr = [0, 2, 2, 3];
+        let indices = [1, 2, 0];
+        let graph = SearchGraphView::new(&indptr, &indices);
+        assert_eq!(graph.n_vertices(), 3);
+        assert_eq!(graph.n_edges(), 3);
+        assert_eq!(graph.neighbors(0), [1, 2]);
+        assert!(graph.neighbors(1).is_empty());
+        assert_eq!(graph.neighbors(2), [0]);
+    }
+
     #[test]
     fn test_checked_push() {
         let mut heap = SortedNeighbors::new(1);
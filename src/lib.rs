@@ -1,7 +1,16 @@
+mod compressed;
+mod dyn_index;
+pub mod eval;
 pub mod graph;
 mod metric;
+mod mmap;
 mod nndescent;
+pub mod persist;
 pub mod rp_trees;
 
-pub use metric::Metric;
-pub use nndescent::{NNDescent, NNDescentBuilder};
+pub use compressed::{CompressError, CompressedIndex};
+pub use dyn_index::{DynIndex, DynIndexError};
+pub use metric::{Distance, Metric, ParseMetricError};
+pub use mmap::MmapIndex;
+pub use nndescent::{BuildError, NNDescent, NNDescentBuilder};
+pub use persist::LoadError;
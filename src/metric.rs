@@ -1,6 +1,116 @@
-use eonn_accel::SpacialOps;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+use eonn_accel::{Arch, DangerousOps, Dim, SpacialOps, VectorView};
+
+use crate::nndescent::BuildError;
+use crate::rp_trees::SplitStrategy;
+
+/// A distance measure between two vectors of type `V`.
+///
+/// The built-in [Metric]s implement this for any [SpacialOps] vector, domain
+/// specific distances, i.e. a weighted Euclidean or learned Mahalanobis distance,
+/// can be used by implementing this trait and passing it to
+/// [NNDescentBuilder::with_metric](crate::NNDescentBuilder::with_metric).
+pub trait Distance<V> {
+    /// Calculates the distance between two vectors.
+    ///
+    /// Smaller distances are closer, the distance may be negative but the
+    /// distance of any vector to itself should be the smallest it can have.
+    fn distance(&self, x: &V, y: &V) -> f32;
+
+    #[inline]
+    /// Returns if the vectors should be normalized before building the graph.
+    ///
+    /// Defaults to `false`.
+    fn requires_normalizing(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    /// Returns the hyperplane splitting strategy used to build the random
+    /// projection trees for this distance.
+    ///
+    /// Defaults to [SplitStrategy::Euclidean].
+    fn split_strategy(&self) -> SplitStrategy {
+        SplitStrategy::Euclidean
+    }
+
+    #[inline]
+    /// Validates the distance can be used with vectors of `dims` dimensions
+    /// before the graph is built.
+    ///
+    /// Defaults to always being valid.
+    fn validate(&self, dims: usize) -> Result<(), BuildError> {
+        let _ = dims;
+        Ok(())
+    }
+
+    #[inline]
+    /// Converts a distance returned by [Distance::distance] into the true distance
+    /// reported to users, i.e. the square root of a squared distance.
+    ///
+    /// This must preserve the ordering of distances. Defaults to the distance itself.
+    fn true_distance(&self, distance: f32) -> f32 {
+        distance
+    }
+}
+
+impl<V, M: Distance<V> + ?Sized> Distance<V> for &M {
+    #[inline]
+    fn distance(&self, x: &V, y: &V) -> f32 {
+        (**self).distance(x, y)
+    }
+
+    #[inline]
+    fn requires_normalizing(&self) -> bool {
+        (**self).requires_normalizing()
+    }
+
+    #[inline]
+    fn split_strategy(&self) -> SplitStrategy {
+        (**self).split_strategy()
+    }
+
+    #[inline]
+    fn validate(&self, dims: usize) -> Result<(), BuildError> {
+        (**self).validate(dims)
+    }
+
+    #[inline]
+    fn true_distance(&self, distance: f32) -> f32 {
+        (**self).true_distance(distance)
+    }
+}
+
+impl<V: SpacialOps> Distance<V> for Metric {
+    #[inline]
+    fn distance(&self, x: &V, y: &V) -> f32 {
+        Metric::distance(self, x, y)
+    }
+
+    #[inline]
+    fn requires_normalizing(&self) -> bool {
+        Metric::requires_normalizing(self)
+    }
+
+    #[inline]
+    fn split_strategy(&self) -> SplitStrategy {
+        Metric::split_strategy(self)
+    }
+
+    #[inline]
+    fn validate(&self, dims: usize) -> Result<(), BuildError> {
+        Metric::validate(self, dims)
+    }
+
+    #[inline]
+    fn true_distance(&self, distance: f32) -> f32 {
+        Metric::true_distance(self, distance)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 /// The distance metrics available.
 pub enum Metric {
     /// The dot product distance.
@@ -12,6 +122,56 @@ pub enum Metric {
     SquaredEuclidean,
     /// The cosine distance.
     Cosine,
+    /// The Manhattan (L1) distance, the sum of the absolute differences.
+    Manhattan,
+    /// The Chebyshev (L∞) distance, the largest absolute difference.
+    Chebyshev,
+    /// The raw inner product distance, `-x·y`, for maximum inner product search.
+    ///
+    /// Unlike [Metric::Dot] the vectors are not normalized, so the norm of each
+    /// vector contributes to its ranking and distances may be negative.
+    InnerProduct,
+    /// The Hamming distance, the number of elements which differ.
+    ///
+    /// This is intended for bit-packed [BinaryVector](eonn_accel::BinaryVector)s.
+    Hamming,
+    /// The Jaccard distance, treating each vector as the set of its non-zero
+    /// elements.
+    ///
+    /// This is intended for bit-packed [BinaryVector](eonn_accel::BinaryVector)s.
+    Jaccard,
+    /// The Pearson correlation distance, the cosine distance of the vectors
+    /// after centring each on its mean.
+    Correlation,
+    /// The Hellinger distance between two vectors of non-negative weights.
+    ///
+    /// Each vector is treated as an unnormalized probability distribution.
+    Hellinger,
+    /// The Jensen-Shannon divergence between two vectors of non-negative weights.
+    ///
+    /// Each vector is treated as an unnormalized probability distribution.
+    JensenShannon,
+    /// The Haversine (great-circle) distance between `[latitude, longitude]` points
+    /// in radians, on a sphere with a radius of `1`.
+    ///
+    /// This requires 2 dimensional vectors, i.e. `Vector<X2, _>`.
+    ///
+    /// The random projection trees split the raw coordinates with Euclidean
+    /// hyperplanes, over short distances the great-circle distance is close to the
+    /// Euclidean distance of the coordinates so nearby locations end up in the same
+    /// leaves. Neighbors which are only close across the antimeridian or the poles
+    /// are found by the NNDescent refinement instead.
+    Haversine,
+    /// The Minkowski distance with the power `p`, `(Σ|x - y|^p)^(1/p)`.
+    ///
+    /// `p` must be positive, a `p` of `1.0`, `2.0` or infinity uses the Manhattan,
+    /// Euclidean or Chebyshev kernels respectively.
+    Minkowski { p: f32 },
+    /// The squared Euclidean distance with the squared difference of each dimension
+    /// scaled by its weight.
+    ///
+    /// The number of weights must match the number of dimensions of the data.
+    WeightedSquaredEuclidean { weights: Vec<f32> },
 }
 
 impl Metric {
@@ -22,6 +182,49 @@ impl Metric {
             Metric::Dot => x.dist_dot(y),
             Metric::SquaredEuclidean => x.dist_squared_euclidean(y),
             Metric::Cosine => x.dist_cosine(y),
+            Metric::Manhattan => x.dist_manhattan(y),
+            Metric::Chebyshev => x.dist_chebyshev(y),
+            Metric::InnerProduct => -x.dot(y),
+            Metric::Hamming => x.dist_hamming(y),
+            Metric::Jaccard => x.dist_jaccard(y),
+            Metric::Correlation => x.dist_correlation(y),
+            Metric::Hellinger => x.dist_hellinger(y),
+            Metric::JensenShannon => x.dist_jensen_shannon(y),
+            Metric::Haversine => x.dist_haversine(y),
+            Metric::Minkowski { p } => x.dist_minkowski(y, *p),
+            Metric::WeightedSquaredEuclidean { weights } => {
+                x.dist_weighted_squared_euclidean(y, weights)
+            },
+        }
+    }
+
+    #[inline]
+    /// Calculates the distance metric of two borrowed vector views.
+    pub fn distance_view<D: Dim, A: Arch>(
+        &self,
+        x: &VectorView<D, A>,
+        y: &VectorView<D, A>,
+    ) -> f32
+    where
+        (D, A): DangerousOps,
+    {
+        match self {
+            Metric::Dot => x.dist_dot(y),
+            Metric::SquaredEuclidean => x.dist_squared_euclidean(y),
+            Metric::Cosine => x.dist_cosine(y),
+            Metric::Manhattan => x.dist_manhattan(y),
+            Metric::Chebyshev => x.dist_chebyshev(y),
+            Metric::InnerProduct => -x.dot(y),
+            Metric::Hamming => x.dist_hamming(y),
+            Metric::Jaccard => x.dist_jaccard(y),
+            Metric::Correlation => x.dist_correlation(y),
+            Metric::Hellinger => x.dist_hellinger(y),
+            Metric::JensenShannon => x.dist_jensen_shannon(y),
+            Metric::Haversine => x.dist_haversine(y),
+            Metric::Minkowski { p } => x.dist_minkowski(y, *p),
+            Metric::WeightedSquaredEuclidean { weights } => {
+                x.dist_weighted_squared_euclidean(y, weights)
+            },
         }
     }
 
@@ -32,8 +235,282 @@ impl Metric {
     }
 
     #[inline]
-    /// Returns if the vectors should be normalized.
+    /// Returns if the random projection trees should use angular splits.
     pub fn requires_angular_trees(&self) -> bool {
-        matches!(self, Self::Dot | Self::Cosine)
+        self.split_strategy() == SplitStrategy::Angular
+    }
+
+    #[inline]
+    /// Returns the hyperplane splitting strategy used to build the random
+    /// projection trees for this metric.
+    pub fn split_strategy(&self) -> SplitStrategy {
+        match self {
+            Self::Dot | Self::Cosine | Self::Jaccard | Self::Correlation => {
+                SplitStrategy::Angular
+            },
+            Self::SquaredEuclidean
+            | Self::Manhattan
+            | Self::Chebyshev
+            | Self::Hamming
+            | Self::Hellinger
+            | Self::JensenShannon
+            | Self::Haversine
+            | Self::Minkowski { .. }
+            | Self::WeightedSquaredEuclidean { .. } => SplitStrategy::Euclidean,
+            Self::InnerProduct => SplitStrategy::InnerProduct,
+        }
+    }
+
+    #[inline]
+    /// Returns if the distances of the metric are squared, these are ranked the same
+    /// as the true distance but are cheaper to compute.
+    pub fn is_squared(&self) -> bool {
+        matches!(
+            self,
+            Self::SquaredEuclidean | Self::WeightedSquaredEuclidean { .. }
+        )
+    }
+
+    #[inline]
+    /// Converts a distance returned by [Metric::distance] into the true distance,
+    /// taking the square root of the squared Euclidean distances.
+    ///
+    /// Every other metric already returns its true distance.
+    pub fn true_distance(&self, distance: f32) -> f32 {
+        if self.is_squared() {
+            // Rounding can produce tiny negative squared distances.
+            distance.max(0.0).sqrt()
+        } else {
+            distance
+        }
+    }
+
+    /// Converts a distance returned by [Metric::distance] into a similarity score,
+    /// where larger scores are more similar.
+    ///
+    /// The metrics with a natural similarity are converted to it directly:
+    ///
+    /// - `dot`: the dot product, negative products are clamped to `0.0` the same
+    ///   way [SpacialOps::dist_dot] clamps the distance to `1.0`.
+    /// - `cosine` and `correlation`: the cosine similarity or Pearson correlation
+    ///   between `-1.0` and `1.0`.
+    /// - `inner_product`: the raw inner product.
+    /// - `jaccard`: the Jaccard index between `0.0` and `1.0`.
+    /// - `hellinger`: the Bhattacharyya coefficient between `0.0` and `1.0`.
+    ///
+    /// Any other metric is converted to `1 / (1 + d)` of its true distance `d`, see
+    /// [Metric::true_distance], giving a score between `0.0` and `1.0`.
+    pub fn to_similarity(&self, distance: f32) -> f32 {
+        match self {
+            Self::Dot | Self::Cosine | Self::Correlation | Self::Jaccard => {
+                1.0 - distance
+            },
+            Self::InnerProduct => -distance,
+            Self::Hellinger => 1.0 - (distance * distance),
+            _ => (1.0 + self.true_distance(distance)).recip(),
+        }
+    }
+
+    /// Converts a similarity score back into the distance returned by
+    /// [Metric::distance], the inverse of [Metric::to_similarity].
+    ///
+    /// This allows a similarity threshold to be compared against the raw distances
+    /// of query results. Similarities outside of the range the metric can produce
+    /// are clamped, i.e. a negative `dot` similarity is the distance `1.0`.
+    pub fn from_similarity(&self, similarity: f32) -> f32 {
+        match self {
+            Self::Dot => 1.0 - similarity.max(0.0),
+            Self::Cosine | Self::Correlation | Self::Jaccard => 1.0 - similarity,
+            Self::InnerProduct => -similarity,
+            Self::Hellinger => (1.0 - similarity).max(0.0).sqrt(),
+            _ => {
+                let distance = similarity.clamp(0.0, 1.0).recip() - 1.0;
+                if self.is_squared() {
+                    distance * distance
+                } else {
+                    distance
+                }
+            },
+        }
+    }
+
+    /// Validates the parameters of the metric against vectors of `dims` dimensions.
+    pub fn validate(&self, dims: usize) -> Result<(), BuildError> {
+        let invalid = |name, reason| Err(BuildError::InvalidParameter { name, reason });
+
+        match self {
+            Self::Haversine if dims != 2 => {
+                invalid("metric", "haversine requires 2 dimensional vectors")
+            },
+            Self::Minkowski { p } if p.is_nan() || *p <= 0.0 => {
+                invalid("p", "must be positive")
+            },
+            Self::WeightedSquaredEuclidean { weights } if weights.len() != dims => {
+                Err(BuildError::MismatchedWeights {
+                    expected: dims,
+                    got: weights.len(),
+                })
+            },
+            Self::WeightedSquaredEuclidean { weights }
+                if !weights.iter().all(|w| w.is_finite() && *w >= 0.0) =>
+            {
+                invalid("weights", "must be finite and not negative")
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An error that occurs when parsing a [Metric] from an unknown name.
+pub struct ParseMetricError {
+    name: String,
+}
+
+impl Display for ParseMetricError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown Metric: {:?} is not a known metric name",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for ParseMetricError {}
+
+impl FromStr for Metric {
+    type Err = ParseMetricError;
+
+    /// Parses a metric from its name, ignoring case.
+    ///
+    /// Along with the snake case name of each metric the common aliases `l1`,
+    /// `l2`, `euclidean`, `linf` and `ip` are accepted. `l2` and `euclidean`
+    /// parse to [Metric::SquaredEuclidean], which ranks neighbors identically.
+    ///
+    /// [Metric::Minkowski] and [Metric::WeightedSquaredEuclidean] need parameters
+    /// so they cannot be parsed and must be constructed directly.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let metric = match s.to_ascii_lowercase().as_str() {
+            "dot" => Self::Dot,
+            "squared_euclidean" | "sqeuclidean" | "euclidean" | "l2" => {
+                Self::SquaredEuclidean
+            },
+            "cosine" => Self::Cosine,
+            "manhattan" | "l1" => Self::Manhattan,
+            "chebyshev" | "linf" => Self::Chebyshev,
+            "inner_product" | "ip" => Self::InnerProduct,
+            "hamming" => Self::Hamming,
+            "jaccard" => Self::Jaccard,
+            "correlation" => Self::Correlation,
+            "hellinger" => Self::Hellinger,
+            "jensen_shannon" => Self::JensenShannon,
+            "haversine" => Self::Haversine,
+            _ => {
+                return Err(ParseMetricError {
+                    name: s.to_string(),
+                })
+            },
+        };
+
+        Ok(metric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eonn_accel::{Fallback, Vector, XAny};
+
+    use super::*;
+
+    fn vector(data: &[f32]) -> Vector<XAny, Fallback> {
+        Vector::try_from_vec(data.to_vec()).unwrap()
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() <= 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_true_distance() {
+        let x = vector(&[1.0, 2.0, 3.0]);
+        let y = vector(&[4.0, 6.0, 3.0]);
+
+        let dist = Metric::SquaredEuclidean.distance(&x, &y);
+        assert_eq!(dist, 25.0);
+        assert_eq!(Metric::SquaredEuclidean.true_distance(dist), 5.0);
+        assert_eq!(
+            Distance::<Vector<XAny, Fallback>>::true_distance(
+                &Metric::SquaredEuclidean,
+                dist
+            ),
+            5.0
+        );
+        assert_eq!(Metric::SquaredEuclidean.true_distance(-1e-7), 0.0);
+
+        let dist = Metric::Manhattan.distance(&x, &y);
+        assert_eq!(Metric::Manhattan.true_distance(dist), 7.0);
+    }
+
+    #[test]
+    fn test_to_similarity() {
+        let x = vector(&[0.6, 0.8]);
+        let y = vector(&[0.8, 0.6]);
+        let opposite = vector(&[-0.6, -0.8]);
+
+        let similarity = Metric::Dot.to_similarity(Metric::Dot.distance(&x, &y));
+        assert_close(similarity, x.dot(&y));
+        // Negative products are clamped by the distance, so they all score `0.0`.
+        let dist = Metric::Dot.distance(&x, &opposite);
+        assert_eq!(dist, 1.0);
+        assert_eq!(Metric::Dot.to_similarity(dist), 0.0);
+
+        let similarity = Metric::Cosine.to_similarity(Metric::Cosine.distance(&x, &x));
+        assert_close(similarity, 1.0);
+        let dist = Metric::Cosine.distance(&x, &opposite);
+        assert_close(Metric::Cosine.to_similarity(dist), -1.0);
+
+        let dist = Metric::InnerProduct.distance(&x, &y);
+        assert_close(Metric::InnerProduct.to_similarity(dist), x.dot(&y));
+
+        assert_close(Metric::SquaredEuclidean.to_similarity(4.0), 1.0 / 3.0);
+        assert_close(Metric::Manhattan.to_similarity(0.0), 1.0);
+        assert_close(Metric::Hellinger.to_similarity(0.5), 0.75);
+    }
+
+    #[test]
+    fn test_similarity_roundtrip() {
+        let metrics = [
+            Metric::Dot,
+            Metric::SquaredEuclidean,
+            Metric::Cosine,
+            Metric::Manhattan,
+            Metric::Chebyshev,
+            Metric::InnerProduct,
+            Metric::Hamming,
+            Metric::Jaccard,
+            Metric::Correlation,
+            Metric::Hellinger,
+            Metric::JensenShannon,
+            Metric::Haversine,
+            Metric::Minkowski { p: 3.0 },
+            Metric::WeightedSquaredEuclidean { weights: vec![1.0] },
+        ];
+
+        for metric in metrics {
+            let mut last = f32::INFINITY;
+            for dist in [0.0, 0.1, 0.5, 0.9, 1.0] {
+                let similarity = metric.to_similarity(dist);
+                assert!(similarity < last, "{metric:?} is not decreasing");
+                assert_close(metric.from_similarity(similarity), dist);
+                last = similarity;
+            }
+        }
+
+        // Similarities outside of the range of the metric are clamped.
+        assert_eq!(Metric::Dot.from_similarity(-0.5), 1.0);
+        assert_eq!(Metric::SquaredEuclidean.from_similarity(1.5), 0.0);
+        assert_eq!(Metric::SquaredEuclidean.from_similarity(0.0), f32::INFINITY);
+        assert_eq!(Metric::Hellinger.from_similarity(1.5), 0.0);
     }
 }
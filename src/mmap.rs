@@ -0,0 +1,660 @@
+use std::fs::File;
+use std::hash::Hasher;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::Path;
+use std::{cmp, mem};
+
+use eonn_accel::{Arch, DangerousOps, Dim, Vector, VectorView};
+use fnv::FnvHasher;
+use memmap2::Mmap;
+
+use crate::graph::{GraphView, SearchGraphView};
+use crate::metric::Metric;
+use crate::nndescent::beam_search;
+use crate::persist::{
+    metric_from_tag,
+    padding_for,
+    parse_header,
+    LoadError,
+    HEADER_SIZE,
+};
+
+/// A read-only [NNDescent](crate::NNDescent) index served directly from a memory
+/// mapped file written by [NNDescent::save](crate::NNDescent::save).
+///
+/// Opening the index only reads and validates the header, section layout and tree
+/// structure, the vectors and graphs are never copied out of the mapping, so opening
+/// even a very large index is near instant and pages are only faulted in as queries
+/// touch them. Use [MmapIndex::verify] and [MmapIndex::verify_checksum] to check the
+/// contents of a file which may be corrupted.
+pub struct MmapIndex<D: Dim, A: Arch>
+where
+    (D, A): DangerousOps,
+{
+    mmap: Mmap,
+    metric: Metric,
+    n_vertices: usize,
+    dims: usize,
+    n_neighbors: usize,
+    vectors: Range<usize>,
+    graph_indices: Range<usize>,
+    graph_distances: Range<usize>,
+    search_indptr: Range<usize>,
+    search_indices: Range<usize>,
+    trees: Vec<TreeLayout>,
+    checksum_offset: usize,
+    _ops: PhantomData<(D, A)>,
+}
+
+/// The byte ranges of a single RP tree within the mapping.
+struct TreeLayout {
+    children: Range<usize>,
+    offsets: Range<usize>,
+    hyperplanes: Range<usize>,
+    /// The hyperplane row of each internal node, or `u32::MAX` for leaves.
+    hyperplane_rows: Vec<u32>,
+    leaf_indptr: Range<usize>,
+    leaf_indices: Range<usize>,
+}
+
+impl<D: Dim, A: Arch> MmapIndex<D, A>
+where
+    (D, A): DangerousOps,
+{
+    /// Memory maps the index at `path`.
+    ///
+    /// The header and section layout are validated, but the graph indices and the
+    /// checksum are **not** verified as that would require reading the whole file,
+    /// see [MmapIndex::verify] and [MmapIndex::verify_checksum].
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while the index is open, doing
+    /// so is undefined behaviour.
+    pub unsafe fn open(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let file = File::open(path)?;
+        let mmap = Mmap::map(&file)?;
+        Self::from_mmap(mmap)
+    }
+
+    fn from_mmap(mmap: Mmap) -> Result<Self, LoadError> {
+        if cfg!(target_endian = "big") {
+            return Err(LoadError::UnsupportedPlatform(
+                "memory mapped indexes require a little-endian platform",
+            ));
+        }
+
+        let header: &[u8; HEADER_SIZE] = mmap
+            .get(..HEADER_SIZE)
+            .and_then(|buf| buf.try_into().ok())
+            .ok_or(LoadError::Corrupted("file is smaller than the header"))?;
+        let header = parse_header(header)?;
+
+        if let Some(expected) = D::const_size() {
+            if header.dims != expected {
+                return Err(LoadError::BadDimensions {
+                    expected,
+                    got: header.dims,
+                });
+            }
+        }
+
+        if header.n_vertices >= u32::MAX as usize {
+            return Err(LoadError::Corrupted("too many vertices"));
+        }
+
+        let mut layout = Layout {
+            buf: &mmap,
+            position: HEADER_SIZE,
+        };
+
+        let metric_params = layout.section::<f32>(header.n_metric_params)?;
+        let n_slots = checked_mul(header.n_vertices, header.n_neighbors)?;
+        let vectors =
+            layout.section::<f32>(checked_mul(header.n_vertices, header.dims)?)?;
+        let graph_indices = layout.section::<u32>(n_slots)?;
+        let graph_distances = layout.section::<f32>(n_slots)?;
+        let search_indptr = layout.section::<u64>(header.n_vertices + 1)?;
+        let search_indices = layout.section::<u32>(header.n_edges)?;
+
+        let mut trees = Vec::with_capacity(cmp::min(header.n_trees, 64));
+        for _ in 0..header.n_trees {
+            trees.push(layout.tree(header.dims)?);
+        }
+
+        let checksum_offset = layout.position;
+        if mmap.len() != checksum_offset + mem::size_of::<u64>() {
+            return Err(LoadError::Corrupted(
+                "file length does not match the layout",
+            ));
+        }
+
+        let metric_params = mmap[metric_params]
+            .chunks_exact(mem::size_of::<f32>())
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        let metric = metric_from_tag(header.metric_tag, &metric_params, header.dims)?;
+        if metric.validate(header.dims).is_err() {
+            return Err(LoadError::Corrupted("metric parameters are invalid"));
+        }
+
+        let index = Self {
+            mmap,
+            metric,
+            n_vertices: header.n_vertices,
+            dims: header.dims,
+            n_neighbors: header.n_neighbors,
+            vectors,
+            graph_indices,
+            graph_distances,
+            search_indptr,
+            search_indices,
+            trees,
+            checksum_offset,
+            _ops: PhantomData,
+        };
+
+        Ok(index)
+    }
+
+    /// Verifies every graph and tree index in the file points at a valid vertex.
+    ///
+    /// Like [MmapIndex::verify_checksum] this reads most of the mapping, so it is
+    /// not done when opening the index. Queries against an unverified, corrupted
+    /// index may panic but are never undefined behaviour.
+    pub fn verify(&self) -> Result<(), LoadError> {
+        let n_edges = self.search_indices.len() / mem::size_of::<u32>();
+        let indptr = self.slice::<u64>(&self.search_indptr);
+        if indptr.first() != Some(&0)
+            || indptr.last() != Some(&(n_edges as u64))
+            || indptr.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err(LoadError::Corrupted("CSR offsets do not cover the indices"));
+        }
+
+        for tree in self.trees.iter() {
+            let n_leaf_indices = tree.leaf_indices.len() / mem::size_of::<u32>();
+            let indptr = self.slice::<u64>(&tree.leaf_indptr);
+            if indptr.first() != Some(&0)
+                || indptr.last() != Some(&(n_leaf_indices as u64))
+                || indptr.windows(2).any(|w| w[0] > w[1])
+            {
+                return Err(LoadError::Corrupted(
+                    "CSR offsets do not cover the indices",
+                ));
+            }
+        }
+
+        let n_vertices = self.n_vertices as u64;
+        let out_of_bounds =
+            |indices: &[u32]| indices.iter().any(|&idx| u64::from(idx) >= n_vertices);
+
+        if out_of_bounds(self.slice::<u32>(&self.search_indices)) {
+            return Err(LoadError::Corrupted("CSR index out of bounds"));
+        }
+        for tree in self.trees.iter() {
+            if out_of_bounds(self.slice::<u32>(&tree.leaf_indices)) {
+                return Err(LoadError::Corrupted("CSR index out of bounds"));
+            }
+        }
+        if self
+            .slice::<u32>(&self.graph_indices)
+            .iter()
+            .any(|&idx| idx != u32::MAX && u64::from(idx) >= n_vertices)
+        {
+            return Err(LoadError::Corrupted("graph neighbor out of bounds"));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the checksum of the entire file.
+    ///
+    /// This reads every page of the mapping so should only be done when the integrity
+    /// of the file is in question.
+    pub fn verify_checksum(&self) -> Result<(), LoadError> {
+        let mut hasher = FnvHasher::default();
+        hasher.write(&self.mmap[..self.checksum_offset]);
+        let expected = hasher.finish();
+
+        let got = &self.mmap[self.checksum_offset..];
+        let got = u64::from_le_bytes(got.try_into().unwrap());
+
+        if expected != got {
+            return Err(LoadError::ChecksumMismatch { expected, got });
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    /// The distance metric used by the graph.
+    pub fn metric(&self) -> &Metric {
+        &self.metric
+    }
+
+    #[inline]
+    /// The number of points in the index.
+    pub fn n_vertices(&self) -> usize {
+        self.n_vertices
+    }
+
+    #[inline]
+    /// The number of dimensions of each vector.
+    pub fn dims(&self) -> usize {
+        self.dims
+    }
+
+    #[inline]
+    /// Returns a view of the vector for point `idx`.
+    pub fn vector(&self, idx: usize) -> VectorView<'_, D, A> {
+        let data =
+            &self.slice::<f32>(&self.vectors)[idx * self.dims..(idx + 1) * self.dims];
+        // Safety:
+        //  The dimensions were validated against `D` when the index was opened.
+        unsafe { VectorView::from_slice_unchecked(data) }
+    }
+
+    #[inline]
+    /// The approximate nearest neighbor graph.
+    pub fn graph(&self) -> GraphView<'_> {
+        GraphView::new(
+            self.n_neighbors,
+            self.slice(&self.graph_indices),
+            self.slice(&self.graph_distances),
+        )
+    }
+
+    #[inline]
+    /// The pruned and diversified graph used when searching.
+    pub fn search_graph(&self) -> SearchGraphView<'_> {
+        SearchGraphView::new(
+            self.slice(&self.search_indptr),
+            self.slice(&self.search_indices),
+        )
+    }
+
+    /// Searches the graph for the approximate `k` nearest neighbors of `query`.
+    ///
+    /// This behaves identically to [NNDescent::query](crate::NNDescent::query).
+    ///
+    /// NOTE:
+    /// If the metric requires normalizing, the `query` vector must already be normalized.
+    pub fn query(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let query = query.as_view();
+        let search_graph = self.search_graph();
+
+        let seeds = self
+            .trees
+            .iter()
+            .flat_map(|tree| self.search_leaf(tree, &query).iter())
+            .map(|&idx| idx as usize)
+            .filter(|&idx| idx < self.n_vertices);
+
+        beam_search(
+            self.n_vertices,
+            k,
+            epsilon,
+            seeds,
+            |idx| search_graph.neighbors(idx),
+            |idx| self.metric.distance_view(&query, &self.vector(idx)),
+            |_| true,
+        )
+    }
+
+    /// Searches the graph like [MmapIndex::query], reporting the true distance of
+    /// each neighbor, see [Metric::true_distance].
+    pub fn query_true_distances(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.true_distance(*dist);
+        }
+        results
+    }
+
+    /// Searches the graph like [MmapIndex::query], reporting the similarity score of
+    /// each neighbor, see [Metric::to_similarity].
+    pub fn query_similarities(
+        &self,
+        query: &Vector<D, A>,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.to_similarity(*dist);
+        }
+        results
+    }
+
+    /// Traverses the tree from the root node, returning the point indices
+    /// of the leaf the `query` vector falls in.
+    fn search_leaf(&self, tree: &TreeLayout, query: &VectorView<D, A>) -> &[u32] {
+        let children = self.slice::<u32>(&tree.children);
+        let offsets = self.slice::<f32>(&tree.offsets);
+        let hyperplanes = self.slice::<f32>(&tree.hyperplanes);
+
+        // Nodes are pushed after their children, so the root is always the last node.
+        let mut node = match offsets.len().checked_sub(1) {
+            Some(root) => root,
+            None => return &[],
+        };
+
+        while children[node * 2] != u32::MAX {
+            let row = tree.hyperplane_rows[node] as usize;
+            let hyperplane = &hyperplanes[row * self.dims..(row + 1) * self.dims];
+            let hyperplane =
+                unsafe { VectorView::<D, A>::from_slice_unchecked(hyperplane) };
+            let margin = offsets[node] + hyperplane.dot(query);
+
+            // Points on the hyperplane always go left so queries are reproducible.
+            let is_left = margin > 0.0 || margin.abs() < f32::EPSILON;
+
+            node = children[node * 2 + (!is_left as usize)] as usize;
+        }
+
+        let indptr = self.slice::<u64>(&tree.leaf_indptr);
+        let indices = self.slice::<u32>(&tree.leaf_indices);
+        &indices[indptr[node] as usize..indptr[node + 1] as usize]
+    }
+
+    #[inline]
+    fn slice<T: Pod>(&self, range: &Range<usize>) -> &[T] {
+        // Safety:
+        //  Every section was checked to be in bounds and aligned to `T` when the
+        //  index was opened, and `T` is valid for any bit pattern.
+        unsafe {
+            std::slice::from_raw_parts(
+                self.mmap.as_ptr().add(range.start) as *const T,
+                range.len() / mem::size_of::<T>(),
+            )
+        }
+    }
+}
+
+/// A primitive type which is valid for any bit pattern.
+trait Pod: Copy {}
+impl Pod for u32 {}
+impl Pod for u64 {}
+impl Pod for f32 {}
+
+/// Walks the sections of the mapped file, validating their bounds and alignment.
+struct Layout<'a> {
+    buf: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Layout<'a> {
+    /// Returns the byte range of a section of `len` values of `T` at the current
+    /// position and advances past it, including any padding.
+    fn section<T: Pod>(&mut self, len: usize) -> Result<Range<usize>, LoadError> {
+        let start = self.position;
+        let end = checked_mul(len, mem::size_of::<T>())?
+            .checked_add(start)
+            .ok_or(LoadError::Corrupted("section size overflows usize"))?;
+
+        if !(self.buf.as_ptr() as usize + start).is_multiple_of(mem::align_of::<T>()) {
+            return Err(LoadError::Corrupted("section is not aligned"));
+        }
+
+        self.position = end + padding_for(end as u64);
+        if self.position > self.buf.len() {
+            return Err(LoadError::Corrupted("section exceeds the file length"));
+        }
+
+        Ok(start..end)
+    }
+
+    fn read_u64(&mut self) -> Result<u64, LoadError> {
+        let range = self.section::<u64>(1)?;
+        Ok(u64::from_le_bytes(self.buf[range].try_into().unwrap()))
+    }
+
+    fn read_usize(&mut self) -> Result<usize, LoadError> {
+        usize::try_from(self.read_u64()?)
+            .map_err(|_| LoadError::Corrupted("value exceeds usize"))
+    }
+
+    fn tree(&mut self, dims: usize) -> Result<TreeLayout, LoadError> {
+        let n_nodes = self.read_usize()?;
+        let n_internal = self.read_usize()?;
+        let n_leaf_indices = self.read_usize()?;
+        let _leaf_size = self.read_u64()?;
+        let _n_leaves = self.read_u64()?;
+
+        let children = self.section::<u32>(checked_mul(n_nodes, 2)?)?;
+        let offsets = self.section::<f32>(n_nodes)?;
+        let hyperplanes = self.section::<f32>(checked_mul(n_internal, dims)?)?;
+        let leaf_indptr = self.section::<u64>(n_nodes + 1)?;
+        let leaf_indices = self.section::<u32>(n_leaf_indices)?;
+
+        let mut hyperplane_rows = Vec::with_capacity(n_nodes);
+        let mut n_rows = 0;
+        for (node, pair) in self.buf[children.clone()].chunks_exact(8).enumerate() {
+            let left = u32::from_le_bytes(pair[..4].try_into().unwrap());
+            let right = u32::from_le_bytes(pair[4..].try_into().unwrap());
+
+            if left == u32::MAX && right == u32::MAX {
+                hyperplane_rows.push(u32::MAX);
+                continue;
+            } else if left == u32::MAX || right == u32::MAX {
+                return Err(LoadError::Corrupted("tree node is only partially a leaf"));
+            }
+
+            // Children are always pushed before their parent.
+            if left as usize >= node || right as usize >= node {
+                return Err(LoadError::Corrupted("tree child out of bounds"));
+            }
+
+            hyperplane_rows.push(n_rows);
+            n_rows += 1;
+        }
+
+        if n_rows as usize != n_internal {
+            return Err(LoadError::Corrupted("tree internal node count mismatch"));
+        }
+
+        Ok(TreeLayout {
+            children,
+            offsets,
+            hyperplanes,
+            hyperplane_rows,
+            leaf_indptr,
+            leaf_indices,
+        })
+    }
+}
+
+#[inline]
+fn checked_mul(a: usize, b: usize) -> Result<usize, LoadError> {
+    a.checked_mul(b)
+        .ok_or(LoadError::Corrupted("section size overflows usize"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use eonn_accel::{Auto, X1024, X512};
+
+    use super::*;
+    use crate::{NNDescent, NNDescentBuilder};
+
+    fn build_index() -> NNDescent<Vector<X512, Auto>> {
+        build_index_with(Metric::SquaredEuclidean)
+    }
+
+    fn build_index_with(metric: Metric) -> NNDescent<Vector<X512, Auto>> {
+        let data = (0..200)
+            .map(|_| {
+                let v = std::iter::repeat_with(fastrand::f32).take(512).collect();
+                Vector::try_from_vec(v).expect("Load vec")
+            })
+            .collect();
+
+        NNDescentBuilder::new()
+            .with_data(data)
+            .with_metric(metric)
+            .with_n_neighbors(10)
+            .build()
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("eonn-mmap-{name}-{}.idx", std::process::id()))
+    }
+
+    #[test]
+    fn test_mmap_matches_index() {
+        let index = build_index();
+        let path = temp_path("matches");
+        index.save(&path).expect("Save index");
+
+        let mapped =
+            unsafe { MmapIndex::<X512, Auto>::open(&path) }.expect("Open index");
+        mapped.verify().expect("Verify index");
+        mapped.verify_checksum().expect("Verify checksum");
+
+        assert_eq!(mapped.metric(), index.metric());
+        assert_eq!(mapped.n_vertices(), index.data().len());
+        assert_eq!(mapped.dims(), 512);
+
+        for (p, vector) in index.data().iter().enumerate() {
+            assert_eq!(mapped.vector(p).as_ref(), vector.as_ref());
+
+            let expected = index
+                .graph()
+                .point(p)
+                .iter_neighbors()
+                .filter(|n| n.idx() != u32::MAX)
+                .map(|n| (n.idx(), n.dist()))
+                .collect::<Vec<_>>();
+            let got = mapped
+                .graph()
+                .iter_neighbors(p)
+                .map(|n| (n.idx(), n.dist()))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, got);
+
+            assert_eq!(
+                mapped.search_graph().neighbors(p),
+                index.search_graph().neighbors(p)
+            );
+        }
+
+        for (p, vector) in index.data().iter().enumerate().take(20) {
+            let results = mapped.query(vector, 5, 0.1);
+            assert_eq!(results.len(), 5);
+            assert_eq!(results[0], (p, 0.0));
+        }
+
+        drop(mapped);
+        std::fs::remove_file(&path).expect("Remove file");
+    }
+
+    #[test]
+    fn test_mmap_metric_params() {
+        let weights = (0..512).map(|i| (i % 4 + 1) as f32).collect();
+        let index = build_index_with(Metric::WeightedSquaredEuclidean { weights });
+        let path = temp_path("metric-params");
+        index.save(&path).expect("Save index");
+
+        let mapped =
+            unsafe { MmapIndex::<X512, Auto>::open(&path) }.expect("Open index");
+        mapped.verify_checksum().expect("Verify checksum");
+        assert_eq!(mapped.metric(), index.metric());
+
+        for vector in index.data().iter().take(20) {
+            assert_eq!(mapped.query(vector, 5, 0.1), index.query(vector, 5, 0.1));
+            assert_eq!(
+                mapped.query_true_distances(vector, 5, 0.1),
+                index.query_true_distances(vector, 5, 0.1)
+            );
+            assert_eq!(
+                mapped.query_similarities(vector, 5, 0.1),
+                index.query_similarities(vector, 5, 0.1)
+            );
+        }
+
+        drop(mapped);
+        std::fs::remove_file(&path).expect("Remove file");
+    }
+
+    #[test]
+    fn test_mmap_validation() {
+        let index = build_index();
+        let path = temp_path("validation");
+        index.save(&path).expect("Save index");
+
+        let err = unsafe { MmapIndex::<X1024, Auto>::open(&path) }
+            .err()
+            .expect("Open should fail");
+        assert!(matches!(err, LoadError::BadDimensions { .. }), "{err}");
+
+        let mut buf = std::fs::read(&path).expect("Read index");
+        buf[HEADER_SIZE + 5] ^= 0xFF;
+        std::fs::write(&path, &buf).expect("Write index");
+
+        let mapped =
+            unsafe { MmapIndex::<X512, Auto>::open(&path) }.expect("Open index");
+        let err = mapped.verify_checksum().expect_err("Checksum should fail");
+        assert!(matches!(err, LoadError::ChecksumMismatch { .. }), "{err}");
+        drop(mapped);
+
+        std::fs::write(&path, &buf[..buf.len() - 16]).expect("Write index");
+        let err = unsafe { MmapIndex::<X512, Auto>::open(&path) }
+            .err()
+            .expect("Open should fail");
+        assert!(matches!(err, LoadError::Corrupted(_)), "{err}");
+
+        std::fs::remove_file(&path).expect("Remove file");
+    }
+
+    #[test]
+    fn test_mmap_rejects_out_of_bounds() {
+        let index = build_index();
+        let path = temp_path("out-of-bounds");
+        index.save(&path).expect("Save index");
+
+        let mapped =
+            unsafe { MmapIndex::<X512, Auto>::open(&path) }.expect("Open index");
+        let sections = [
+            mapped.search_indices.start,
+            mapped.graph_indices.start,
+            mapped.trees[0].leaf_indices.start,
+        ];
+        drop(mapped);
+
+        let original = std::fs::read(&path).expect("Read index");
+        for offset in sections {
+            let mut buf = original.clone();
+            buf[offset..offset + 4].copy_from_slice(&200u32.to_le_bytes());
+            std::fs::write(&path, &buf).expect("Write index");
+
+            let mapped =
+                unsafe { MmapIndex::<X512, Auto>::open(&path) }.expect("Open index");
+            let err = mapped.verify().expect_err("Verify should fail");
+            assert!(matches!(err, LoadError::Corrupted(_)), "{err}");
+        }
+
+        // Haversine is only defined for 2 dimensional vectors.
+        let mut buf = original.clone();
+        buf[12..16].copy_from_slice(&13u32.to_le_bytes());
+        std::fs::write(&path, &buf).expect("Write index");
+        let err = unsafe { MmapIndex::<X512, Auto>::open(&path) }
+            .err()
+            .expect("Open should fail");
+        assert!(matches!(err, LoadError::Corrupted(_)), "{err}");
+
+        std::fs::remove_file(&path).expect("Remove file");
+    }
+}
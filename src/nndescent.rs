@@ -1,11 +1,37 @@
-use std::cmp;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 use std::time::Instant;
-
-use eonn_accel::{Auto, SpacialOps, Vector, X512};
+use std::{cmp, io};
+
+use bitvec::bitvec;
+use bitvec::prelude::Lsb0;
+use bitvec::vec::BitVec;
+use eonn_accel::{
+    Arch,
+    Auto,
+    DangerousOps,
+    Dim,
+    PqCodeSize,
+    SpacialOps,
+    SparseVector,
+    SparseVectorCreateError,
+    Vector,
+    VectorCreateError,
+    X512,
+};
+use fastrand::Rng;
+use fnv::FnvHashSet;
 use tracing::info;
 
-use crate::graph::{DynamicGraph, SortedNeighbors};
-use crate::metric::Metric;
+use crate::compressed::{CompressError, CompressedIndex};
+use crate::graph::{DynamicGraph, Point, SearchGraph, SortedNeighbors};
+use crate::metric::{Distance, Metric};
+use crate::persist;
+use crate::persist::LoadError;
 use crate::rp_trees::Tree;
 
 /// Approximate nearest neighbour graph construction and search using NNDescent.
@@ -15,14 +41,731 @@ use crate::rp_trees::Tree;
 /// the NNDescent paper and Random Projection forests to quickly bootstrap graph
 /// building and searching.
 ///
-/// Unlike the Python version which supports sparse and dense vectors, this implementation
-/// only supports _dense_ vectors and a limited set of distance measures, in particular,
-/// `dot`, `cosine` and `squared_euclidean`. Which is enough for most cases.
-pub struct NNDescent<V: SpacialOps> {
-    _data: Vec<V>,
-    _metric: Metric,
+/// Like the Python version this supports both _dense_ and _sparse_ vectors, with
+/// sparse data loaded from CSR arrays via [NNDescentBuilder::with_sparse_data], along
+/// with bit-packed binary vectors. The supported distance measures are listed in
+/// [Metric].
+pub struct NNDescent<V: SpacialOps, M = Metric> {
+    data: Vec<V>,
+    metric: M,
+    graph: DynamicGraph,
+    search_graph: SearchGraph,
+    search_forest: Vec<Tree<V>>,
+    removed: BitVec,
+    skip_normalization: bool,
+}
+
+impl<V: SpacialOps, M: Distance<V>> NNDescent<V, M> {
+    #[inline]
+    /// The data points the graph was built from.
+    pub fn data(&self) -> &[V] {
+        &self.data
+    }
+
+    #[inline]
+    /// The distance metric used by the graph.
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    #[inline]
+    /// The approximate nearest neighbor graph.
+    pub fn graph(&self) -> &DynamicGraph {
+        &self.graph
+    }
+
+    #[inline]
+    /// The pruned and diversified graph used when searching.
+    pub fn search_graph(&self) -> &SearchGraph {
+        &self.search_graph
+    }
+
+    #[inline]
+    /// Returns if point `idx` has been removed from the index.
+    pub fn is_removed(&self, idx: usize) -> bool {
+        self.removed[idx]
+    }
+
+    #[inline]
+    /// The number of points which have been removed but not yet compacted.
+    pub fn n_removed(&self) -> usize {
+        self.removed.count_ones()
+    }
+
+    /// Searches the graph for the approximate `k` nearest neighbors of `query`.
+    ///
+    /// The search is seeded from the RP-tree leaf the query falls into, then
+    /// performs a best-first beam search over the search graph. `epsilon` controls how
+    /// far beyond the current `k`th nearest neighbor the search is allowed to
+    /// explore, larger values give more accurate results at the cost of speed,
+    /// a value of `0.1` is generally a good starting point.
+    ///
+    /// Returns `(idx, dist)` pairs sorted by ascending distance.
+    ///
+    /// NOTE:
+    /// If the metric requires normalizing, the `query` vector must already be normalized.
+    pub fn query(&self, query: &V, k: usize, epsilon: f32) -> Vec<(usize, f32)> {
+        let seeds = self
+            .search_forest
+            .iter()
+            .flat_map(|tree| tree.search_leaf(query).iter().copied());
+
+        beam_search(
+            self.data.len(),
+            k,
+            epsilon,
+            seeds,
+            |idx| self.search_graph.neighbors(idx),
+            |idx| self.metric.distance(query, &self.data[idx]),
+            |idx| !self.is_removed(idx),
+        )
+    }
+
+    /// Searches the graph like [NNDescent::query], reporting the true distance of
+    /// each neighbor rather than the distance used for ranking, i.e. the Euclidean
+    /// distance instead of the squared Euclidean distance.
+    ///
+    /// See [Distance::true_distance].
+    pub fn query_true_distances(
+        &self,
+        query: &V,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.true_distance(*dist);
+        }
+        results
+    }
+
+    /// Inserts a new point into the graph, returning its index.
+    ///
+    /// The nearest neighbors of the new point are found by searching the current
+    /// graph, and the point is then linked to them bidirectionally so it can be
+    /// found by later queries without rebuilding the graph.
+    ///
+    /// The vector is normalized if the metric requires it, unless the index was built
+    /// with [NNDescentBuilder::with_skip_normalization]. Indexes loaded from disk
+    /// always normalize inserted vectors.
+    pub fn insert(&mut self, vector: V) -> usize {
+        self.insert_inner(vector, false)
+    }
+
+    /// Inserts a new point into the graph like [NNDescent::insert], then runs a
+    /// local NNDescent iteration over the new point's neighborhood.
+    ///
+    /// This is slower than a plain insert but improves the quality of the graph
+    /// around the new point, which helps when inserting many close points.
+    pub fn insert_and_refine(&mut self, vector: V) -> usize {
+        self.insert_inner(vector, true)
+    }
+
+    fn insert_inner(&mut self, mut vector: V, refine: bool) -> usize {
+        if self.metric.requires_normalizing() && !self.skip_normalization {
+            vector.normalize();
+        }
+
+        let n_neighbors = self.graph.n_neighbors();
+        let neighbors = self.query(&vector, n_neighbors, INSERT_EPSILON);
+
+        self.data.push(vector);
+        self.removed.push(false);
+        let idx = self.graph.add_point();
+        debug_assert_eq!(idx, self.data.len() - 1);
+
+        for &(neighbor, dist) in neighbors.iter() {
+            self.graph.point_mut(idx).checked_push(dist, neighbor);
+            self.graph.point_mut(neighbor).checked_push(dist, idx);
+        }
+
+        if refine {
+            self.refine_neighborhood(idx);
+        }
+
+        self.link_search_vertex(idx);
+
+        idx
+    }
+
+    /// Runs a local join over the neighborhood of point `p`, comparing `p` with the
+    /// neighbors of its neighbors, and its neighbors with each other.
+    fn refine_neighborhood(&mut self, p: usize) {
+        let neighbors = self
+            .graph
+            .point(p)
+            .iter_neighbors()
+            .map(|n| n.idx() as usize)
+            .filter(|&n| n != p && n < self.data.len())
+            .collect::<Vec<_>>();
+
+        for (i, &a) in neighbors.iter().enumerate() {
+            let neighbors_of_a = self
+                .graph
+                .point(a)
+                .iter_neighbors()
+                .map(|n| n.idx() as usize)
+                .filter(|&b| b != p && b < self.data.len() && !self.removed[b])
+                .collect::<Vec<_>>();
+
+            for b in neighbors_of_a {
+                let dist = self.metric.distance(&self.data[p], &self.data[b]);
+                if dist < self.graph.threshold(p) {
+                    self.graph.point_mut(p).checked_push(dist, b);
+                }
+                if dist < self.graph.threshold(b) {
+                    self.graph.point_mut(b).checked_push(dist, p);
+                }
+            }
+
+            for &b in neighbors[i + 1..].iter() {
+                let dist = self.metric.distance(&self.data[a], &self.data[b]);
+                if dist < self.graph.threshold(a) {
+                    self.graph.point_mut(a).checked_push(dist, b);
+                }
+                if dist < self.graph.threshold(b) {
+                    self.graph.point_mut(b).checked_push(dist, a);
+                }
+            }
+        }
+    }
+
+    /// Adds the new point `p` to the search graph, linking it from its neighbors
+    /// by replacing their furthest edge if `p` is closer.
+    ///
+    /// Edges are only replaced if their target has other edges pointing to it, and if
+    /// `p` is not closer than any existing edge, an edge of its nearest neighbor is
+    /// replaced to ensure `p` is always reachable. If no neighbor has a replaceable
+    /// edge, a new edge is added to the nearest neighbor instead.
+    fn link_search_vertex(&mut self, p: usize) {
+        let neighbors = self
+            .graph
+            .point(p)
+            .iter_neighbors()
+            .filter(|n| n.idx() as usize != p && n.idx() != u32::MAX)
+            .collect::<Vec<_>>();
+
+        let idx = self
+            .search_graph
+            .push_vertex(neighbors.iter().map(|n| n.idx()));
+        debug_assert_eq!(idx, p);
+
+        let nearest = neighbors.first().map(|n| n.idx() as usize);
+        let mut fallback = None;
+        let mut is_linked = false;
+        for neighbor in neighbors {
+            let source = neighbor.idx() as usize;
+
+            let mut furthest = None;
+            for slot in 0..self.search_graph.neighbors(source).len() {
+                let target = self.search_graph.neighbors(source)[slot] as usize;
+                if self.search_graph.in_degree(target) <= 1 {
+                    continue;
+                }
+
+                let dist = self.metric.distance(&self.data[source], &self.data[target]);
+                if furthest.is_none_or(|(_, d)| dist > d) {
+                    furthest = Some((slot, dist));
+                }
+            }
+
+            if let Some((slot, dist)) = furthest {
+                if neighbor.dist() < dist {
+                    self.search_graph.replace_edge(source, slot, p as u32);
+                    is_linked = true;
+                } else if fallback.is_none() {
+                    fallback = Some((source, slot));
+                }
+            }
+        }
+
+        if !is_linked {
+            if let Some((source, slot)) = fallback {
+                self.search_graph.replace_edge(source, slot, p as u32);
+            } else if let Some(source) = nearest {
+                self.search_graph.push_edge(source, p as u32);
+            }
+        }
+    }
+
+    /// Removes point `idx` from the index, returning `false` if it was already removed.
+    ///
+    /// The point is tombstoned rather than removed from the graph, so it will never
+    /// be returned by queries but is still traversed to keep the graph connected.
+    /// Call [NNDescent::compact] to repair the graph and reclaim the space once
+    /// enough points have been removed.
+    ///
+    /// # Panics
+    /// If `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> bool {
+        assert!(idx < self.data.len(), "Point {idx} is out of bounds");
+        !self.removed.replace(idx, true)
+    }
+
+    /// Removes all tombstoned points from the index, repairing the graph around them.
+    ///
+    /// Points which had a removed point as a neighbor are reconnected using the
+    /// neighbors of their neighbors as candidates, then all remaining points are
+    /// renumbered to be contiguous.
+    ///
+    /// Returns the new index of each old point, or `None` if it was removed.
+    pub fn compact(&mut self) -> Vec<Option<usize>> {
+        let n_vertices = self.data.len();
+
+        let mut mapping = vec![None; n_vertices];
+        let mut n_live = 0;
+        for (old, new) in mapping.iter_mut().enumerate() {
+            if !self.removed[old] {
+                *new = Some(n_live);
+                n_live += 1;
+            }
+        }
+
+        if n_live == n_vertices {
+            return mapping;
+        }
+
+        let start = Instant::now();
+        let graph = self.repair_graph(&mapping, n_live);
+        let search_graph = self.repair_search_graph(&mapping, &graph);
+
+        for tree in self.search_forest.iter_mut() {
+            for indices in tree.point_indices.iter_mut().flatten() {
+                *indices = indices.iter().filter_map(|&idx| mapping[idx]).collect();
+            }
+        }
+
+        let data = std::mem::take(&mut self.data);
+        self.data = data
+            .into_iter()
+            .zip(mapping.iter())
+            .filter_map(|(vector, new)| new.map(|_| vector))
+            .collect();
+        self.graph = graph;
+        self.search_graph = search_graph;
+        self.removed = bitvec![usize, Lsb0; 0; n_live];
+
+        info!(
+            elapsed = ?start.elapsed(),
+            n_removed = n_vertices - n_live,
+            "Compacted graph",
+        );
+
+        mapping
+    }
+
+    /// Builds a new graph containing only the live points, replacing the removed
+    /// neighbors of each point with candidates from its neighbors of neighbors.
+    fn repair_graph(&self, mapping: &[Option<usize>], n_live: usize) -> DynamicGraph {
+        let n_neighbors = self.graph.n_neighbors();
+        let mut graph = DynamicGraph::new(n_live, n_neighbors);
+        let mut seen = FnvHashSet::default();
+
+        for (old, new) in mapping.iter().enumerate() {
+            let Some(new) = *new else {
+                continue;
+            };
+
+            let point = graph.point_mut(new);
+            let mut has_removed = false;
+            for neighbor in self.graph.point(old).iter_neighbors() {
+                match mapping[neighbor.idx() as usize] {
+                    Some(idx) => {
+                        point.checked_push(neighbor.dist(), idx);
+                    },
+                    None => has_removed = true,
+                }
+            }
+
+            if !has_removed {
+                continue;
+            }
+
+            seen.clear();
+            seen.extend(self.graph.point(old).iter_neighbors().map(|n| n.idx()));
+            for neighbor in self.graph.point(old).iter_neighbors() {
+                for candidate in
+                    self.graph.point(neighbor.idx() as usize).iter_neighbors()
+                {
+                    let Some(idx) = mapping[candidate.idx() as usize] else {
+                        continue;
+                    };
+
+                    if !seen.insert(candidate.idx()) {
+                        continue;
+                    }
+
+                    let dist = self
+                        .metric
+                        .distance(&self.data[old], &self.data[candidate.idx() as usize]);
+                    if dist < point.furthest().dist() {
+                        point.checked_push(dist, idx);
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Builds a new search graph containing only the live points, replacing the
+    /// removed edges of each vertex with the nearest neighbors from the repaired graph.
+    fn repair_search_graph(
+        &self,
+        mapping: &[Option<usize>],
+        graph: &DynamicGraph,
+    ) -> SearchGraph {
+        let mut adjacency_lists = Vec::with_capacity(graph.n_vertices());
+
+        for (old, new) in mapping.iter().enumerate() {
+            let Some(new) = *new else {
+                continue;
+            };
+
+            let edges = self.search_graph.neighbors(old);
+            let mut neighbors = edges
+                .iter()
+                .filter_map(|&idx| mapping[idx as usize])
+                .map(|idx| idx as u32)
+                .collect::<Vec<_>>();
+
+            let candidates = graph
+                .point(new)
+                .iter_neighbors()
+                .map(|n| n.idx())
+                .filter(|&idx| idx as usize != new);
+            for candidate in candidates {
+                if neighbors.len() >= edges.len() {
+                    break;
+                }
+
+                if !neighbors.contains(&candidate) {
+                    neighbors.push(candidate);
+                }
+            }
+
+            adjacency_lists.push(neighbors);
+        }
+
+        SearchGraph::from_adjacency_lists(adjacency_lists)
+    }
+}
+
+/// The search `epsilon` used when finding the neighbors of inserted points.
+const INSERT_EPSILON: f32 = 0.1;
+
+/// Performs a best-first beam search for the approximate `k` nearest neighbors
+/// over a graph with `n_vertices`, starting from the given `seeds`.
+///
+/// `neighbors` returns the edges of a vertex in the search graph and `distance`
+/// computes the distance between the query and a vertex. Vertices where `is_live`
+/// returns `false` are still traversed, but never returned.
+pub(crate) fn beam_search<'a>(
+    n_vertices: usize,
+    k: usize,
+    epsilon: f32,
+    seeds: impl IntoIterator<Item = usize>,
+    neighbors: impl Fn(usize) -> &'a [u32],
+    distance: impl Fn(usize) -> f32,
+    is_live: impl Fn(usize) -> bool,
+) -> Vec<(usize, f32)> {
+    if k == 0 || n_vertices == 0 {
+        return Vec::new();
+    }
+
+    let mut visited: BitVec = bitvec![usize, Lsb0; 0; n_vertices];
+    let mut results = SortedNeighbors::new(k);
+    let mut candidates = BinaryHeap::new();
+
+    let mut n_seeds = 0;
+    let mut last_seed = None;
+    for idx in seeds {
+        last_seed = Some(idx);
+        if !visited.replace(idx, true) {
+            let dist = distance(idx);
+            candidates.push(Reverse(Point::new(idx, dist)));
+            if is_live(idx) {
+                results.checked_push(dist, idx);
+                n_seeds += 1;
+            }
+        }
+    }
+
+    // Top up the seeds with the points following the last seed if the leaves were
+    // too small, keeping the results deterministic for a given query.
+    let start = last_seed.map_or(0, |idx| (idx + 1) % n_vertices);
+    for idx in (start..n_vertices).chain(0..start) {
+        if n_seeds >= k {
+            break;
+        }
+
+        if !visited.replace(idx, true) {
+            let dist = distance(idx);
+            candidates.push(Reverse(Point::new(idx, dist)));
+            if is_live(idx) {
+                results.checked_push(dist, idx);
+                n_seeds += 1;
+            }
+        }
+    }
+
+    // Distances may be negative, i.e. for `Metric::InnerProduct`, so the bound
+    // is relaxed by the magnitude of the furthest distance rather than scaled.
+    let bound = |furthest: f32| furthest + epsilon * furthest.abs();
+    let mut distance_bound = bound(results.furthest().dist());
+
+    while let Some(Reverse(candidate)) = candidates.pop() {
+        if candidate.dist() >= distance_bound {
+            break;
+        }
+
+        for &idx in neighbors(candidate.idx() as usize) {
+            let idx = idx as usize;
+            if visited.replace(idx, true) {
+                continue;
+            }
+
+            let dist = distance(idx);
+            if dist < distance_bound {
+                candidates.push(Reverse(Point::new(idx, dist)));
+                if is_live(idx) {
+                    results.checked_push(dist, idx);
+                    distance_bound = bound(results.furthest().dist());
+                }
+            }
+        }
+    }
+
+    results
+        .iter_neighbors()
+        .map(|p| (p.idx() as usize, p.dist()))
+        .collect()
+}
+
+impl<V: SpacialOps> NNDescent<V, Metric> {
+    /// Searches the graph like [NNDescent::query], reporting the similarity score of
+    /// each neighbor rather than its distance, see [Metric::to_similarity].
+    ///
+    /// Returns `(idx, similarity)` pairs sorted by descending similarity.
+    pub fn query_similarities(
+        &self,
+        query: &V,
+        k: usize,
+        epsilon: f32,
+    ) -> Vec<(usize, f32)> {
+        let mut results = self.query(query, k, epsilon);
+        for (_, dist) in results.iter_mut() {
+            *dist = self.metric.to_similarity(*dist);
+        }
+        results
+    }
+}
+
+impl<D: Dim, A: Arch> NNDescent<Vector<D, A>>
+where
+    (D, A): DangerousOps,
+{
+    /// Saves the index to the file at `path`, overwriting it if it already exists.
+    ///
+    /// The file is written in a versioned, little-endian binary format with a trailing
+    /// checksum, see the [persist](crate::persist) module for the exact layout.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.save_to(BufWriter::new(file))
+    }
+
+    /// Writes the index to the given writer.
+    ///
+    /// Indexes with removed points must be compacted with [NNDescent::compact]
+    /// before they can be saved.
+    pub fn save_to(&self, writer: impl Write) -> io::Result<()> {
+        if self.n_removed() > 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "index contains removed points which must be compacted before saving",
+            ));
+        }
+
+        persist::write_index(
+            writer,
+            &self.metric,
+            &self.data,
+            &self.graph,
+            &self.search_graph,
+            &self.search_forest,
+        )
+    }
+
+    /// Compresses the index by encoding the vectors with a product quantizer of
+    /// `n_subspaces` subspaces, consuming the full precision vectors.
+    ///
+    /// The quantizer is trained on a sample of the live points, removed points are
+    /// kept but never returned. Only [Metric::Dot], [Metric::Cosine],
+    /// [Metric::InnerProduct] and [Metric::SquaredEuclidean] are supported.
+    pub fn compress(
+        self,
+        n_subspaces: usize,
+        code_size: PqCodeSize,
+    ) -> Result<CompressedIndex<D, A>, CompressError> {
+        CompressedIndex::new(
+            &self.data,
+            self.metric,
+            self.search_graph,
+            self.search_forest,
+            self.removed,
+            n_subspaces,
+            code_size,
+        )
+    }
+
+    /// Loads an index previously written with [NNDescent::save].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let file = File::open(path)?;
+        Self::load_from(BufReader::new(file))
+    }
+
+    /// Reads an index previously written with [NNDescent::save_to].
+    ///
+    /// The checksum, dimensions and graph structure are all validated before
+    /// the index is returned.
+    pub fn load_from(reader: impl Read) -> Result<Self, LoadError> {
+        let parts = persist::read_index(reader)?;
+
+        if let Some(expected) = D::const_size() {
+            if parts.dims != expected {
+                return Err(LoadError::BadDimensions {
+                    expected,
+                    got: parts.dims,
+                });
+            }
+        }
+
+        let to_vector = |values: Vec<f32>| {
+            Vector::try_from_vec(values).map_err(|e| match e {
+                VectorCreateError::BadDimensions { expected, got } => {
+                    LoadError::BadDimensions { expected, got }
+                },
+                VectorCreateError::NonFinite => {
+                    LoadError::Corrupted("vector contains non-finite values")
+                },
+            })
+        };
+
+        let data = parts
+            .vectors
+            .into_iter()
+            .map(to_vector)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut search_forest = Vec::with_capacity(parts.trees.len());
+        for tree in parts.trees {
+            let hyperplanes = tree
+                .hyperplanes
+                .into_iter()
+                .map(|hyperplane| hyperplane.map(to_vector).transpose())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            search_forest.push(Tree {
+                hyperplanes,
+                offsets: tree.offsets,
+                children: tree.children,
+                point_indices: tree.point_indices,
+                leaf_size: tree.leaf_size,
+                n_leaves: tree.n_leaves,
+            });
+        }
+
+        let removed = bitvec![usize, Lsb0; 0; data.len()];
+
+        Ok(Self {
+            data,
+            metric: parts.metric,
+            graph: parts.graph,
+            search_graph: parts.search_graph,
+            search_forest,
+            removed,
+            skip_normalization: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An error that occurs when the [NNDescentBuilder] is given an invalid configuration.
+pub enum BuildError {
+    /// No data points were provided to build the graph from.
+    EmptyData,
+    /// The number of neighbors per point must be at least `1`.
+    ZeroNeighbors,
+    /// The number of neighbors per point is larger than the number of points.
+    TooManyNeighbors { n_neighbors: usize, n_points: usize },
+    /// A vector has a different number of dimensions to the first vector.
+    MismatchedDimensions {
+        idx: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The RP tree leaf size is smaller than the number of neighbors per point.
+    LeafSizeTooSmall {
+        leaf_size: usize,
+        n_neighbors: usize,
+    },
+    /// The number of metric weights does not match the dimensions of the data.
+    MismatchedWeights { expected: usize, got: usize },
+    /// A builder parameter is outside its valid range.
+    InvalidParameter {
+        name: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyData => write!(f, "Empty Data: at least one point is required"),
+            Self::ZeroNeighbors => {
+                write!(f, "Zero Neighbors: n_neighbors must be at least 1")
+            },
+            Self::TooManyNeighbors {
+                n_neighbors,
+                n_points,
+            } => {
+                write!(
+                    f,
+                    "Too Many Neighbors: n_neighbors is {n_neighbors} but only \
+                     {n_points} points were provided"
+                )
+            },
+            Self::MismatchedDimensions { idx, expected, got } => {
+                write!(
+                    f,
+                    "Mismatched Dimensions: expected {expected} but vector {idx} has {got}"
+                )
+            },
+            Self::LeafSizeTooSmall {
+                leaf_size,
+                n_neighbors,
+            } => {
+                write!(
+                    f,
+                    "Leaf Size Too Small: leaf_size {leaf_size} is smaller than \
+                     n_neighbors {n_neighbors}"
+                )
+            },
+            Self::MismatchedWeights { expected, got } => {
+                write!(
+                    f,
+                    "Mismatched Weights: expected {expected} weights but got {got}"
+                )
+            },
+            Self::InvalidParameter { name, reason } => {
+                write!(f, "Invalid Parameter: {name} {reason}")
+            },
+        }
+    }
 }
 
+impl std::error::Error for BuildError {}
+
 /// The builder for configuring the NNDescent process.
 ///
 /// Approximate nearest neighbour graph construction and search using NNDescent.
@@ -32,12 +775,13 @@ pub struct NNDescent<V: SpacialOps> {
 /// the NNDescent paper and Random Projection forests to quickly bootstrap graph
 /// building and searching.
 ///
-/// Unlike the Python version which supports sparse and dense vectors, this implementation
-/// only supports _dense_ vectors and a limited set of distance measures, in particular,
-/// `dot`, `cosine` and `squared_euclidean`. Which is enough for most cases.
-pub struct NNDescentBuilder<V: SpacialOps = Vector<X512, Auto>> {
+/// Like the Python version this supports both _dense_ and _sparse_ vectors, with
+/// sparse data loaded from CSR arrays via [NNDescentBuilder::with_sparse_data], along
+/// with bit-packed binary vectors. The supported distance measures are listed in
+/// [Metric].
+pub struct NNDescentBuilder<V: SpacialOps = Vector<X512, Auto>, M = Metric> {
     data: Vec<V>,
-    metric: Metric,
+    metric: M,
     n_neighbors: usize,
     n_trees: Option<usize>,
     leaf_size: Option<usize>,
@@ -48,6 +792,7 @@ pub struct NNDescentBuilder<V: SpacialOps = Vector<X512, Auto>> {
     delta: f32,
     skip_normalization: bool,
     max_candidates: Option<usize>,
+    seed: Option<u64>,
     #[cfg(feature = "rayon")]
     thread_pool: Option<rayon::ThreadPool>,
 }
@@ -67,6 +812,7 @@ impl<V: SpacialOps> Default for NNDescentBuilder<V> {
             delta: 0.001,
             skip_normalization: false,
             max_candidates: None,
+            seed: None,
             #[cfg(feature = "rayon")]
             thread_pool: None,
         }
@@ -78,9 +824,9 @@ impl NNDescentBuilder {
     }
 }
 
-impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
+impl<V: SpacialOps, M> NNDescentBuilder<V, M> {
     /// Sets the initial graph data points.
-    pub fn with_data<V2: SpacialOps>(self, data: Vec<V2>) -> NNDescentBuilder<V2> {
+    pub fn with_data<V2: SpacialOps>(self, data: Vec<V2>) -> NNDescentBuilder<V2, M> {
         NNDescentBuilder {
             data,
             metric: self.metric,
@@ -94,17 +840,53 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
             delta: self.delta,
             skip_normalization: self.skip_normalization,
             max_candidates: self.max_candidates,
+            seed: self.seed,
             #[cfg(feature = "rayon")]
             thread_pool: self.thread_pool,
         }
     }
 
+    /// Sets the initial graph data points from a sparse matrix with `dims` columns
+    /// in compressed sparse row (CSR) format, one [SparseVector] per row.
+    ///
+    /// Row `i` is made up of the `indices` and `values` within
+    /// `indptr[i]..indptr[i + 1]`, the indices of each row must be strictly
+    /// ascending.
+    pub fn with_sparse_data(
+        self,
+        dims: usize,
+        indptr: &[usize],
+        indices: &[u32],
+        values: &[f32],
+    ) -> Result<NNDescentBuilder<SparseVector, M>, SparseVectorCreateError> {
+        let data = SparseVector::try_from_csr(dims, indptr, indices, values)?;
+        Ok(self.with_data(data))
+    }
+
     /// Set the distance metric used when constructing the graph.
     ///
+    /// This can be any of the built-in [Metric]s or a custom implementation
+    /// of [Distance].
+    ///
     /// By default, this uses [Metric::SquaredEuclidean].
-    pub fn with_metric(mut self, metric: Metric) -> Self {
-        self.metric = metric;
-        self
+    pub fn with_metric<M2>(self, metric: M2) -> NNDescentBuilder<V, M2> {
+        NNDescentBuilder {
+            data: self.data,
+            metric,
+            n_neighbors: self.n_neighbors,
+            n_trees: self.n_trees,
+            leaf_size: self.leaf_size,
+            pruning_degree_multiplier: self.pruning_degree_multiplier,
+            diversify_prob: self.diversify_prob,
+            max_rptree_depth: self.max_rptree_depth,
+            n_iters: self.n_iters,
+            delta: self.delta,
+            skip_normalization: self.skip_normalization,
+            max_candidates: self.max_candidates,
+            seed: self.seed,
+            #[cfg(feature = "rayon")]
+            thread_pool: self.thread_pool,
+        }
     }
 
     /// Set the number of neighbors per node on the graph.
@@ -214,6 +996,17 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
         self
     }
 
+    /// Sets the seed of the random number generator used when building the graph.
+    ///
+    /// Builds with the same seed, data and configuration will always produce the
+    /// same graph, including when building in parallel.
+    ///
+    /// If not set the generator is seeded randomly.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     #[cfg(feature = "rayon")]
     /// Sets the number of threads to use in parallel when constructing the graph.
     ///
@@ -246,21 +1039,121 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
         self.thread_pool = Some(pool);
         self
     }
+}
 
+impl<V, M> NNDescentBuilder<V, M>
+where
+    V: SpacialOps + Send + Sync + 'static,
+    M: Distance<V> + Send + Sync,
+{
     /// Constructs the approximate nearest neighbour using the current configuration.
-    pub fn build(mut self) -> DynamicGraph {
+    ///
+    /// # Panics
+    /// If the configuration is invalid, see [NNDescentBuilder::try_build] for a
+    /// non-panicking version.
+    pub fn build(self) -> NNDescent<V, M> {
+        self.try_build().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Constructs the approximate nearest neighbour using the current configuration,
+    /// returning an error if the data or any of the builder parameters are invalid.
+    pub fn try_build(mut self) -> Result<NNDescent<V, M>, BuildError> {
+        self.validate()?;
+
         if self.metric.requires_normalizing() && !self.skip_normalization {
             for vector in self.data.iter_mut() {
                 vector.normalize();
             }
         }
 
+        let mut rng = self.seed.map(Rng::with_seed).unwrap_or_default();
+
         let start = Instant::now();
-        let rp_forest = self.create_rp_forest();
+        let mut rp_forest = self.create_rp_forest(&mut rng);
         let leaf_array = crate::rp_trees::rp_tree_leaf_array(&rp_forest);
         info!(elapsed = ?start.elapsed(), "Finished creating RP forest");
 
-        self.nn_descent(&leaf_array)
+        let graph = self.nn_descent(&leaf_array, &mut rng);
+
+        let start = Instant::now();
+        let search_graph = self.prepare_search_graph(&graph, &mut rng);
+        info!(
+            elapsed = ?start.elapsed(),
+            n_edges = search_graph.n_edges(),
+            "Finished preparing search graph",
+        );
+
+        // Only a single tree is needed to seed searches.
+        rp_forest.truncate(1);
+
+        let removed = bitvec![usize, Lsb0; 0; self.data.len()];
+
+        Ok(NNDescent {
+            data: self.data,
+            metric: self.metric,
+            graph,
+            search_graph,
+            search_forest: rp_forest,
+            removed,
+            skip_normalization: self.skip_normalization,
+        })
+    }
+
+    fn validate(&self) -> Result<(), BuildError> {
+        let n_points = self.data.len();
+        let dims = self.data.first().ok_or(BuildError::EmptyData)?.len();
+
+        if let Some((idx, v)) =
+            self.data.iter().enumerate().find(|(_, v)| v.len() != dims)
+        {
+            return Err(BuildError::MismatchedDimensions {
+                idx,
+                expected: dims,
+                got: v.len(),
+            });
+        }
+
+        if self.n_neighbors == 0 {
+            return Err(BuildError::ZeroNeighbors);
+        }
+        if self.n_neighbors > n_points {
+            return Err(BuildError::TooManyNeighbors {
+                n_neighbors: self.n_neighbors,
+                n_points,
+            });
+        }
+        if let Some(leaf_size) = self.leaf_size {
+            if leaf_size < self.n_neighbors {
+                return Err(BuildError::LeafSizeTooSmall {
+                    leaf_size,
+                    n_neighbors: self.n_neighbors,
+                });
+            }
+        }
+
+        let invalid = |name, reason| Err(BuildError::InvalidParameter { name, reason });
+        if self.n_trees == Some(0) {
+            return invalid("n_trees", "must be at least 1");
+        }
+        if self.max_candidates == Some(0) {
+            return invalid("max_candidates", "must be at least 1");
+        }
+        if self.max_rptree_depth == 0 {
+            return invalid("max_rptree_depth", "must be at least 1");
+        }
+        if !(self.pruning_degree_multiplier.is_finite()
+            && self.pruning_degree_multiplier > 0.0)
+        {
+            return invalid("pruning_degree_multiplier", "must be finite and positive");
+        }
+        if !(0.0..=1.0).contains(&self.diversify_prob) {
+            return invalid("diversify_prob", "must be between 0.0 and 1.0");
+        }
+        if !(self.delta.is_finite() && self.delta >= 0.0) {
+            return invalid("delta", "must be finite and not negative");
+        }
+
+        self.metric.validate(dims)
     }
 
     #[inline]
@@ -290,16 +1183,16 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
     }
 
     #[cfg(not(feature = "rayon"))]
-    fn create_rp_forest(&self) -> Vec<Tree<V>> {
+    fn create_rp_forest(&self, rng: &mut Rng) -> Vec<Tree<V>> {
         let n_trees = self.n_trees();
-        let angular = self.metric.requires_angular_trees();
+        let split = self.metric.split_strategy();
         let leaf_size = self
             .leaf_size
             .unwrap_or_else(|| cmp::max(10, self.n_neighbors));
 
         info!(
             n_trees = n_trees,
-            angular = angular,
+            split = ?split,
             leaf_size = leaf_size,
             parallel = false,
             "Creating RP forest",
@@ -309,15 +1202,16 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
             &self.data,
             n_trees,
             leaf_size,
-            angular,
+            split,
             self.max_rptree_depth,
+            rng,
         )
     }
 
     #[cfg(feature = "rayon")]
-    fn create_rp_forest(&self) -> Vec<Tree<V>> {
+    fn create_rp_forest(&self, rng: &mut Rng) -> Vec<Tree<V>> {
         let n_trees = self.n_trees();
-        let angular = self.metric.requires_angular_trees();
+        let split = self.metric.split_strategy();
         let leaf_size = self
             .leaf_size
             .unwrap_or_else(|| cmp::max(10, self.n_neighbors));
@@ -325,7 +1219,7 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
 
         info!(
             n_trees = n_trees,
-            angular = angular,
+            split = ?split,
             leaf_size = leaf_size,
             parallel = parallel,
             "Creating RP forest",
@@ -336,8 +1230,9 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
                 &self.data,
                 self.n_trees(),
                 leaf_size,
-                self.metric.requires_angular_trees(),
+                split,
                 self.max_rptree_depth,
+                rng,
                 pool,
             )
         } else {
@@ -345,16 +1240,17 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
                 &self.data,
                 self.n_trees(),
                 leaf_size,
-                self.metric.requires_angular_trees(),
+                split,
                 self.max_rptree_depth,
+                rng,
             )
         }
     }
 
-    fn nn_descent(&self, leaf_array: &[Vec<usize>]) -> DynamicGraph {
+    fn nn_descent(&self, leaf_array: &[Vec<usize>], rng: &mut Rng) -> DynamicGraph {
         let mut graph = DynamicGraph::new(self.data.len(), self.n_neighbors);
         self.init_graph_with_rp_forest(&mut graph, leaf_array);
-        self.init_graph_with_rng(&mut graph);
+        self.init_graph_with_rng(&mut graph, rng);
 
         self.nn_descent_low_memory(&mut graph);
 
@@ -370,34 +1266,31 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
 
         let n_leaves = leaf_array.len();
         let n_blocks = n_leaves / BLOCK_SIZE;
-        let mut updates = Vec::new();
 
         for i in 0..n_blocks + 1 {
             let block_start = i * BLOCK_SIZE;
             let block_end = cmp::min(n_leaves, (i + 1) * BLOCK_SIZE);
 
             let leaf_block = &leaf_array[block_start..block_end];
-            self.generate_leaf_updates(&mut updates, graph, leaf_block);
+            let updates = self.generate_leaf_updates(graph, leaf_block);
 
             info!(updates = updates.len(), "Generated updates via RP forest");
 
             // Update graph points `p -> q` and `q -> p`.
-            for (p, q, d) in updates.drain(..) {
-                let point = graph.point_mut(p);
-                point.checked_flagged_push(d, q, true);
-                let point = graph.point_mut(q);
-                point.checked_flagged_push(d, p, true);
-            }
+            self.apply_graph_updates(graph, updates);
         }
     }
 
-    fn init_graph_with_rng(&self, graph: &mut DynamicGraph) {
+    fn init_graph_with_rng(&self, graph: &mut DynamicGraph, rng: &mut Rng) {
+        let n_neighbors = graph.n_neighbors();
         for i in 0..self.data.len() {
             let point = graph.point_mut(i);
-            if point.furthest().dist() < 0.0 {
-                let fill_n = point.num_lt(0.0);
+            // Empty slots are always at the end, so only points with too few
+            // neighbors from the forest need topping up.
+            if point.furthest().idx() == u32::MAX {
+                let fill_n = n_neighbors - point.iter_neighbors().count();
                 for _ in 0..fill_n {
-                    let idx = fastrand::usize(0..self.data.len());
+                    let idx = rng.usize(0..self.data.len());
                     let d = self.metric.distance(&self.data[idx], &self.data[i]);
                     point.checked_flagged_push(d, idx, true);
                 }
@@ -407,23 +1300,94 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
 
     fn generate_leaf_updates(
         &self,
-        updates: &mut Vec<(usize, usize, f32)>,
         graph: &DynamicGraph,
         leaf_block: &[Vec<usize>],
-    ) {
-        for block in leaf_block {
-            for i in 0..block.len() {
-                let p = block[i];
+    ) -> Vec<(u32, u32, f32)> {
+        #[cfg(feature = "rayon")]
+        if let Some(pool) = self.thread_pool.as_ref() {
+            use rayon::prelude::*;
+
+            return pool.install(|| {
+                leaf_block
+                    .par_iter()
+                    .fold(Vec::new, |mut updates, leaf| {
+                        generate_leaf_updates(
+                            &mut updates,
+                            graph,
+                            leaf,
+                            &self.data,
+                            &self.metric,
+                        );
+                        updates
+                    })
+                    .flatten_iter()
+                    .collect()
+            });
+        }
 
-                for &q in &block[i + 1..] {
-                    let d = self.metric.distance(&self.data[p], &self.data[q]);
+        let mut updates = Vec::new();
+        for leaf in leaf_block {
+            generate_leaf_updates(&mut updates, graph, leaf, &self.data, &self.metric);
+        }
+        updates
+    }
 
-                    if d < graph.threshold(p) || d < graph.threshold(q) {
-                        updates.push((p, q, d));
-                    }
-                }
-            }
+    fn build_candidates(
+        &self,
+        graph: &mut DynamicGraph,
+    ) -> (Vec<SortedNeighbors>, Vec<SortedNeighbors>) {
+        #[cfg(feature = "rayon")]
+        if let Some(pool) = self.thread_pool.as_ref() {
+            return new_build_candidates_parallel(
+                graph,
+                self.effective_max_candidates(),
+                pool,
+            );
+        }
+
+        new_build_candidates(graph, self.effective_max_candidates())
+    }
+
+    fn generate_graph_updates(
+        &self,
+        new_candidates: &[SortedNeighbors],
+        old_candidates: &[SortedNeighbors],
+        graph: &DynamicGraph,
+    ) -> Vec<(u32, u32, f32)> {
+        #[cfg(feature = "rayon")]
+        if let Some(pool) = self.thread_pool.as_ref() {
+            return generate_graph_updates_parallel(
+                new_candidates,
+                old_candidates,
+                graph,
+                &self.data,
+                &self.metric,
+                self.effective_max_candidates(),
+                pool,
+            );
         }
+
+        generate_graph_updates(
+            new_candidates,
+            old_candidates,
+            graph,
+            &self.data,
+            &self.metric,
+            self.effective_max_candidates(),
+        )
+    }
+
+    fn apply_graph_updates(
+        &self,
+        graph: &mut DynamicGraph,
+        updates: Vec<(u32, u32, f32)>,
+    ) -> usize {
+        #[cfg(feature = "rayon")]
+        if let Some(pool) = self.thread_pool.as_ref() {
+            return apply_graph_updates_parallel(graph, &updates, pool);
+        }
+
+        apply_graph_updates_low_memory(graph, updates)
     }
 
     fn nn_descent_low_memory(&self, graph: &mut DynamicGraph) {
@@ -443,12 +1407,7 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
 
         for n in 0..self.n_iters() {
             let (new_candidate_neighbors, old_candidate_neighbors) =
-                new_build_candidates(
-                    graph,
-                    self.effective_max_candidates(),
-                    &self.data,
-                    self.metric,
-                );
+                self.build_candidates(graph);
 
             let c = self.process_candidates(
                 graph,
@@ -468,6 +1427,64 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
         }
     }
 
+    /// Builds the search graph from the nearest neighbor graph.
+    ///
+    /// The graph is diversified by removing occluded edges, symmetrised by
+    /// adding the (diversified) reverse edges and finally each vertex is pruned
+    /// to at most `pruning_degree_multiplier * n_neighbors` neighbors.
+    fn prepare_search_graph(&self, graph: &DynamicGraph, rng: &mut Rng) -> SearchGraph {
+        let n_vertices = graph.n_vertices();
+        let max_degree = cmp::max(
+            1,
+            (self.pruning_degree_multiplier * self.n_neighbors as f32).round() as usize,
+        );
+
+        let mut forward = Vec::with_capacity(n_vertices);
+        for i in 0..n_vertices {
+            let neighbors = graph
+                .point(i)
+                .iter_neighbors()
+                .filter(|p| p.idx() as usize != i)
+                .collect();
+            forward.push(diversify(
+                neighbors,
+                &self.data,
+                &self.metric,
+                self.diversify_prob,
+                rng,
+            ));
+        }
+
+        let mut reverse = vec![Vec::new(); n_vertices];
+        for (i, neighbors) in forward.iter().enumerate() {
+            for p in neighbors {
+                reverse[p.idx() as usize].push(Point::new(i, p.dist()));
+            }
+        }
+
+        let mut search_neighbors = Vec::with_capacity(n_vertices);
+        for (mut neighbors, mut reverse_neighbors) in forward.into_iter().zip(reverse) {
+            reverse_neighbors.sort_by(|a, b| a.dist().total_cmp(&b.dist()));
+            let reverse_neighbors = diversify(
+                reverse_neighbors,
+                &self.data,
+                &self.metric,
+                self.diversify_prob,
+                rng,
+            );
+
+            neighbors.extend(reverse_neighbors);
+            neighbors.sort_by_key(|p| p.idx());
+            neighbors.dedup_by_key(|p| p.idx());
+            neighbors.sort_by(|a, b| a.dist().total_cmp(&b.dist()));
+            neighbors.truncate(max_degree);
+
+            search_neighbors.push(neighbors);
+        }
+
+        SearchGraph::from_neighbor_lists(search_neighbors)
+    }
+
     fn process_candidates(
         &self,
         graph: &mut DynamicGraph,
@@ -486,16 +1503,13 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
             let new_candidate_block = &new_candidates[block_start..block_end];
             let old_candidate_block = &old_candidates[block_start..block_end];
 
-            let updates = generate_graph_updates(
+            let updates = self.generate_graph_updates(
                 new_candidate_block,
                 old_candidate_block,
                 graph,
-                &self.data,
-                self.metric,
-                self.effective_max_candidates(),
             );
 
-            c += apply_graph_updates_low_memory(graph, updates);
+            c += self.apply_graph_updates(graph, updates);
         }
 
         c
@@ -505,11 +1519,9 @@ impl<V: SpacialOps + Send + Sync + 'static> NNDescentBuilder<V> {
 /// Build a heap of candidate neighbors for nearest neighbor descent. For each vertex the
 /// candidate neighbors are any current neighbors, and any vertices that have the vertex
 /// as one of their nearest neighbors.
-fn new_build_candidates<V: SpacialOps>(
+fn new_build_candidates(
     graph: &mut DynamicGraph,
     max_candidates: usize,
-    data: &[V],
-    metric: Metric,
 ) -> (Vec<SortedNeighbors>, Vec<SortedNeighbors>) {
     let n_vertices = graph.n_vertices();
     let n_neighbors = graph.n_neighbors();
@@ -522,93 +1534,319 @@ fn new_build_candidates<V: SpacialOps>(
         old_candidates.push(SortedNeighbors::new(max_candidates));
     }
 
-    let mut new_inserts = 0;
-    let mut old_inserts = 0;
-    for i in 0..n_vertices {
+    push_candidates(graph, &mut new_candidates, &mut old_candidates);
+
+    for (i, new_candidate) in new_candidates.iter().enumerate() {
+        reset_candidate_flags(
+            graph.point_mut(i),
+            new_candidate,
+            n_neighbors,
+            max_candidates,
+        );
+    }
+
+    (new_candidates, old_candidates)
+}
+
+#[cfg(feature = "rayon")]
+/// Build a heap of candidate neighbors for nearest neighbor descent in parallel
+/// using the given thread pool.
+///
+/// Each thread owns a contiguous range of vertices and only inserts candidates
+/// into the heaps of the vertices it owns, the candidates are first scattered to
+/// the owning thread so each thread only visits its own.
+fn new_build_candidates_parallel(
+    graph: &mut DynamicGraph,
+    max_candidates: usize,
+    pool: &rayon::ThreadPool,
+) -> (Vec<SortedNeighbors>, Vec<SortedNeighbors>) {
+    use rayon::prelude::*;
+
+    let n_vertices = graph.n_vertices();
+    let n_neighbors = graph.n_neighbors();
+    let chunk_size = cmp::max(1, n_vertices.div_ceil(pool.current_num_threads()));
+
+    let mut new_candidates = Vec::with_capacity(n_vertices);
+    let mut old_candidates = Vec::with_capacity(n_vertices);
+
+    for _ in 0..n_vertices {
+        new_candidates.push(SortedNeighbors::new(max_candidates));
+        old_candidates.push(SortedNeighbors::new(max_candidates));
+    }
+
+    pool.install(|| {
+        let scattered = scatter_by_partition(
+            graph.points(),
+            n_vertices,
+            chunk_size,
+            |i, point, push| {
+                for neighbor in point.iter_neighbors() {
+                    let (idx, dist, flag) =
+                        (neighbor.idx(), neighbor.dist(), neighbor.flag());
+                    push(i, (i as u32, idx, dist, flag));
+                    push(idx as usize, (idx, i as u32, dist, flag));
+                }
+            },
+        );
+
+        new_candidates
+            .par_chunks_mut(chunk_size)
+            .zip(old_candidates.par_chunks_mut(chunk_size))
+            .enumerate()
+            .for_each(|(partition, (new_block, old_block))| {
+                let start = partition * chunk_size;
+                for buckets in scattered.iter() {
+                    for &(target, idx, dist, flag) in buckets[partition].iter() {
+                        let candidates = if flag {
+                            &mut *new_block
+                        } else {
+                            &mut *old_block
+                        };
+                        candidates[target as usize - start]
+                            .checked_push(dist, idx as usize);
+                    }
+                }
+            });
+
+        graph
+            .points_mut()
+            .par_iter_mut()
+            .zip(new_candidates.par_iter())
+            .for_each(|(point, new_candidate)| {
+                reset_candidate_flags(point, new_candidate, n_neighbors, max_candidates);
+            });
+    });
+
+    (new_candidates, old_candidates)
+}
+
+#[cfg(feature = "rayon")]
+/// Scatters the values produced from `items` into the partition of the
+/// `n_vertices` vertices, of `chunk_size` each, which they belong to in a single
+/// parallel pass.
+///
+/// `emit` is given the index of each item and pushes `(vertex, value)` pairs. The
+/// buckets are returned per chunk of items, so visiting each chunk's bucket for a
+/// partition in turn preserves the order a sequential pass would have.
+///
+/// Must be called from within the thread pool.
+fn scatter_by_partition<I: Sync, T: Send>(
+    items: &[I],
+    n_vertices: usize,
+    chunk_size: usize,
+    emit: impl Fn(usize, &I, &mut dyn FnMut(usize, T)) + Sync,
+) -> Vec<Vec<Vec<T>>> {
+    use rayon::prelude::*;
+
+    let n_partitions = n_vertices.div_ceil(chunk_size);
+    let item_chunk_size =
+        cmp::max(1, items.len().div_ceil(rayon::current_num_threads()));
+
+    items
+        .par_chunks(item_chunk_size)
+        .enumerate()
+        .map(|(chunk, items)| {
+            let mut buckets = (0..n_partitions).map(|_| Vec::new()).collect::<Vec<_>>();
+            let start = chunk * item_chunk_size;
+            for (offset, item) in items.iter().enumerate() {
+                emit(start + offset, item, &mut |vertex, value| {
+                    buckets[vertex / chunk_size].push(value);
+                });
+            }
+            buckets
+        })
+        .collect()
+}
+
+/// Pushes the neighbors and reverse neighbors of every vertex in the graph into the
+/// candidate heaps.
+fn push_candidates(
+    graph: &DynamicGraph,
+    new_candidates: &mut [SortedNeighbors],
+    old_candidates: &mut [SortedNeighbors],
+) {
+    for i in 0..graph.n_vertices() {
         let point = graph.point(i);
         for neighbor in point.iter_neighbors() {
-            // TODO: Maybe this can be done via fastrand again
-            //  This was changed to be the actual distance metric originally due to the fact
-            //  that the heap implementation relies on the distance to be deterministic one
-            //  insertion in order to detect duplicates.
-            let dist = metric.distance(&data[i], &data[neighbor.idx() as usize]);
-
-            if neighbor.flag() {
-                new_inserts += 1;
-                new_candidates[i].checked_push(dist, neighbor.idx() as usize);
-                new_candidates[neighbor.idx() as usize].checked_push(dist, i);
+            // NOTE: The heap implementation relies on the priority to be deterministic
+            //  on insertion in order to detect duplicates, so we use the distance
+            //  rather than a random priority.
+            let dist = neighbor.dist();
+            let idx = neighbor.idx() as usize;
+
+            let candidates = if neighbor.flag() {
+                &mut *new_candidates
             } else {
-                old_inserts += 1;
-                old_candidates[i].checked_push(dist, neighbor.idx() as usize);
-                old_candidates[neighbor.idx() as usize].checked_push(dist, i);
-            }
+                &mut *old_candidates
+            };
+
+            candidates[i].checked_push(dist, idx);
+            candidates[idx].checked_push(dist, i);
         }
     }
+}
 
-    info!(old_inserts, new_inserts);
-
-    for i in 0..n_vertices {
-        let point = graph.point_mut(i);
-        for j in 0..n_neighbors {
-            let neighbor = point.neighbor_mut(j);
+/// Marks any neighbors of the point which were selected as new candidates as old.
+fn reset_candidate_flags(
+    point: &mut SortedNeighbors,
+    new_candidate: &SortedNeighbors,
+    n_neighbors: usize,
+    max_candidates: usize,
+) {
+    for j in 0..n_neighbors {
+        let neighbor = point.neighbor_mut(j);
 
-            for k in 0..max_candidates {
-                if new_candidates[i].neighbor(k).idx() == neighbor.idx() {
-                    neighbor.set_flag(false);
-                    break;
-                }
+        for k in 0..max_candidates {
+            if new_candidate.neighbor(k).idx() == neighbor.idx() {
+                neighbor.set_flag(false);
+                break;
             }
         }
     }
+}
 
-    (new_candidates, old_candidates)
+/// Removes edges which are occluded by a closer, already retained neighbor.
+///
+/// An edge `p -> q` is occluded if there is a retained neighbor `r` of `p` that is
+/// closer to `q` than `p` is, each occluded edge is removed with probability
+/// `prune_probability`.
+///
+/// The `neighbors` must be sorted by ascending distance.
+fn diversify<V: SpacialOps, M: Distance<V>>(
+    neighbors: Vec<Point>,
+    data: &[V],
+    metric: &M,
+    prune_probability: f32,
+    rng: &mut Rng,
+) -> Vec<Point> {
+    let mut retained: Vec<Point> = Vec::with_capacity(neighbors.len());
+
+    for candidate in neighbors {
+        let vector = &data[candidate.idx() as usize];
+        let is_occluded = retained.iter().any(|r| {
+            let d = metric.distance(vector, &data[r.idx() as usize]);
+            d < candidate.dist() && rng.f32() < prune_probability
+        });
+
+        if !is_occluded {
+            retained.push(candidate);
+        }
+    }
+
+    retained
 }
 
-fn generate_graph_updates<V: SpacialOps>(
+fn generate_graph_updates<V: SpacialOps, M: Distance<V>>(
     new_candidates: &[SortedNeighbors],
     old_candidates: &[SortedNeighbors],
     graph: &DynamicGraph,
     data: &[V],
-    metric: Metric,
+    metric: &M,
     max_candidates: usize,
 ) -> Vec<(u32, u32, f32)> {
-    let block_size = new_candidates.len();
     let mut updates = Vec::new();
 
-    let mut num_steps = 0;
-    for i in 0..block_size {
-        let point = &new_candidates[i];
-        let old_point = &old_candidates[i];
-        for (offset, p) in point.iter_neighbors().take(max_candidates).enumerate() {
-            let p_threshold = graph.point(p.idx() as usize).furthest();
+    for (new_point, old_point) in new_candidates.iter().zip(old_candidates) {
+        generate_vertex_updates(
+            &mut updates,
+            new_point,
+            old_point,
+            graph,
+            data,
+            metric,
+            max_candidates,
+        );
+    }
 
-            for q in point.iter_neighbors().skip(offset) {
-                num_steps += 1;
+    updates
+}
 
-                let q_threshold = graph.point(q.idx() as usize).furthest();
+#[cfg(feature = "rayon")]
+fn generate_graph_updates_parallel<V: SpacialOps + Sync, M: Distance<V> + Sync>(
+    new_candidates: &[SortedNeighbors],
+    old_candidates: &[SortedNeighbors],
+    graph: &DynamicGraph,
+    data: &[V],
+    metric: &M,
+    max_candidates: usize,
+    pool: &rayon::ThreadPool,
+) -> Vec<(u32, u32, f32)> {
+    use rayon::prelude::*;
+
+    pool.install(|| {
+        new_candidates
+            .par_iter()
+            .zip(old_candidates.par_iter())
+            .fold(Vec::new, |mut updates, (new_point, old_point)| {
+                generate_vertex_updates(
+                    &mut updates,
+                    new_point,
+                    old_point,
+                    graph,
+                    data,
+                    metric,
+                    max_candidates,
+                );
+                updates
+            })
+            .flatten_iter()
+            .collect()
+    })
+}
 
-                let d =
-                    metric.distance(&data[p.idx() as usize], &data[q.idx() as usize]);
-                if d <= p_threshold.dist() || d <= q_threshold.dist() {
-                    updates.push((p.idx(), q.idx(), d));
-                }
-            }
+/// Generates the graph updates from the local join of a single vertex's
+/// new and old candidates.
+fn generate_vertex_updates<V: SpacialOps, M: Distance<V>>(
+    updates: &mut Vec<(u32, u32, f32)>,
+    new_point: &SortedNeighbors,
+    old_point: &SortedNeighbors,
+    graph: &DynamicGraph,
+    data: &[V],
+    metric: &M,
+    max_candidates: usize,
+) {
+    for (offset, p) in new_point.iter_neighbors().take(max_candidates).enumerate() {
+        let p_threshold = graph.point(p.idx() as usize).furthest();
 
-            for q in old_point.iter_neighbors().take(max_candidates) {
-                num_steps += 1;
+        for q in new_point.iter_neighbors().skip(offset) {
+            let q_threshold = graph.point(q.idx() as usize).furthest();
 
-                let q_threshold = graph.point(q.idx() as usize).furthest();
+            let d = metric.distance(&data[p.idx() as usize], &data[q.idx() as usize]);
+            if d <= p_threshold.dist() || d <= q_threshold.dist() {
+                updates.push((p.idx(), q.idx(), d));
+            }
+        }
 
-                let d =
-                    metric.distance(&data[p.idx() as usize], &data[q.idx() as usize]);
-                if d <= p_threshold.dist() || d <= q_threshold.dist() {
-                    updates.push((p.idx(), q.idx(), d));
-                }
+        for q in old_point.iter_neighbors().take(max_candidates) {
+            let q_threshold = graph.point(q.idx() as usize).furthest();
+
+            let d = metric.distance(&data[p.idx() as usize], &data[q.idx() as usize]);
+            if d <= p_threshold.dist() || d <= q_threshold.dist() {
+                updates.push((p.idx(), q.idx(), d));
             }
         }
     }
+}
 
-    updates
+/// Generates the graph updates from every pair of points within the leaf.
+fn generate_leaf_updates<V: SpacialOps, M: Distance<V>>(
+    updates: &mut Vec<(u32, u32, f32)>,
+    graph: &DynamicGraph,
+    leaf: &[usize],
+    data: &[V],
+    metric: &M,
+) {
+    for i in 0..leaf.len() {
+        let p = leaf[i];
+
+        for &q in &leaf[i + 1..] {
+            let d = metric.distance(&data[p], &data[q]);
+
+            if d < graph.threshold(p) || d < graph.threshold(q) {
+                updates.push((p as u32, q as u32, d));
+            }
+        }
+    }
 }
 
 fn apply_graph_updates_low_memory(
@@ -630,10 +1868,894 @@ fn apply_graph_updates_low_memory(
     n_changes
 }
 
+#[cfg(feature = "rayon")]
+/// Applies the graph updates in parallel using the given thread pool.
+///
+/// Each thread owns a contiguous range of vertices, the updates are first
+/// scattered to the owning thread of both of their points so each thread only
+/// applies the updates which affect the vertices it owns.
+fn apply_graph_updates_parallel(
+    graph: &mut DynamicGraph,
+    updates: &[(u32, u32, f32)],
+    pool: &rayon::ThreadPool,
+) -> usize {
+    use rayon::prelude::*;
+
+    let n_vertices = graph.n_vertices();
+    let chunk_size = cmp::max(1, n_vertices.div_ceil(pool.current_num_threads()));
+
+    pool.install(|| {
+        let scattered = scatter_by_partition(
+            updates,
+            n_vertices,
+            chunk_size,
+            |_, &(p, q, dist), push| {
+                push(p as usize, (p, q, dist));
+                push(q as usize, (q, p, dist));
+            },
+        );
+
+        graph
+            .points_mut()
+            .par_chunks_mut(chunk_size)
+            .enumerate()
+            .map(|(partition, points)| {
+                let start = partition * chunk_size;
+                let mut n_changes = 0;
+
+                for buckets in scattered.iter() {
+                    for &(p, q, dist) in buckets[partition].iter() {
+                        let point = &mut points[p as usize - start];
+                        let added = point.checked_flagged_push(dist, q as usize, true);
+                        n_changes += added as usize;
+                    }
+                }
+
+                n_changes
+            })
+            .sum()
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use eonn_accel::{BinaryVector, XAny, X2};
+
     use super::*;
 
+    fn test_data(n: usize) -> Vec<Vector<X512, Auto>> {
+        let mut data = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            let v =
+                Vec::from_iter(std::iter::from_fn(|| Some(fastrand::f32())).take(512));
+            let v = Vector::try_from_vec(v).expect("Load vec");
+            data.push(v);
+        }
+
+        data
+    }
+
+    fn clone_data(data: &[Vector<X512, Auto>]) -> Vec<Vector<X512, Auto>> {
+        data.iter().map(|v| v.as_view().to_vector()).collect()
+    }
+
+    fn brute_force_knn<V: SpacialOps>(
+        data: &[V],
+        query: &V,
+        metric: impl Distance<V>,
+        k: usize,
+    ) -> Vec<usize> {
+        let mut dists = data
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| (idx, metric.distance(query, v)))
+            .collect::<Vec<_>>();
+        dists.sort_by(|a, b| a.1.total_cmp(&b.1));
+        dists.into_iter().take(k).map(|(idx, _)| idx).collect()
+    }
+
+    #[test]
+    fn test_query_returns_self() {
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .build();
+
+        for idx in [0, 17, 150, 299] {
+            let results = index.query(&index.data()[idx], 5, 0.1);
+            assert_eq!(results.len(), 5);
+            assert_eq!(results[0].0, idx);
+            assert_eq!(results[0].1, 0.0);
+            assert!(results.windows(2).all(|w| w[0].1 <= w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_query_recall() {
+        let data = test_data(500);
+        let queries = test_data(20);
+        let index = NNDescentBuilder::new()
+            .with_data(data)
+            .with_n_neighbors(15)
+            .build();
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[test]
+    fn test_query_recall_lp_metrics() {
+        let weights = (0..512).map(|i| (i % 4 + 1) as f32).collect();
+        for metric in [
+            Metric::Manhattan,
+            Metric::Chebyshev,
+            Metric::Minkowski { p: 3.0 },
+            Metric::WeightedSquaredEuclidean { weights },
+        ] {
+            let data = test_data(500);
+            let queries = test_data(20);
+            let index = NNDescentBuilder::new()
+                .with_data(data)
+                .with_metric(metric.clone())
+                .with_n_neighbors(15)
+                .build();
+
+            let k = 10;
+            let mut hits = 0;
+            for query in queries.iter() {
+                let expected = brute_force_knn(index.data(), query, &metric, k);
+                let results = index.query(query, k, 0.2);
+                hits += results
+                    .iter()
+                    .filter(|(idx, _)| expected.contains(idx))
+                    .count();
+            }
+
+            let recall = hits as f32 / (k * queries.len()) as f32;
+            assert!(recall >= 0.8, "Recall too low for {metric:?}: {recall}");
+        }
+    }
+
+    #[test]
+    fn test_query_recall_distribution_metrics() {
+        for metric in [
+            Metric::Correlation,
+            Metric::Hellinger,
+            Metric::JensenShannon,
+        ] {
+            let data = test_data(500);
+            let queries = test_data(20);
+            let index = NNDescentBuilder::new()
+                .with_data(data)
+                .with_metric(metric.clone())
+                .with_n_neighbors(15)
+                .build();
+
+            let k = 10;
+            let mut hits = 0;
+            for query in queries.iter() {
+                let expected = brute_force_knn(index.data(), query, &metric, k);
+                let results = index.query(query, k, 0.2);
+                hits += results
+                    .iter()
+                    .filter(|(idx, _)| expected.contains(idx))
+                    .count();
+            }
+
+            let recall = hits as f32 / (k * queries.len()) as f32;
+            assert!(recall >= 0.8, "Recall too low for {metric:?}: {recall}");
+        }
+    }
+
+    /// A squared Euclidean distance with a weight per dimension.
+    struct WeightedDistance {
+        weights: Vec<f32>,
+    }
+
+    impl Distance<Vector<X512, Auto>> for WeightedDistance {
+        fn distance(&self, x: &Vector<X512, Auto>, y: &Vector<X512, Auto>) -> f32 {
+            x.iter()
+                .zip(y.iter())
+                .zip(&self.weights)
+                .map(|((x, y), w)| w * (x - y) * (x - y))
+                .sum()
+        }
+    }
+
+    #[test]
+    fn test_query_recall_custom_distance() {
+        // Only the first few dimensions carry any weight.
+        let weights = (0..512).map(|i| if i < 16 { 1.0 } else { 0.01 }).collect();
+        let metric = WeightedDistance { weights };
+
+        let data = test_data(500);
+        let queries = test_data(20);
+        let index = NNDescentBuilder::new()
+            .with_metric(metric)
+            .with_data(data)
+            .with_n_neighbors(15)
+            .build();
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[test]
+    fn test_query_recall_binary() {
+        let binary_data = |n: usize| {
+            (0..n)
+                .map(|_| {
+                    let words = std::iter::repeat_with(|| fastrand::u64(..))
+                        .take(8)
+                        .collect();
+                    BinaryVector::<X512>::try_from_words(words).expect("Load vec")
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for metric in [Metric::Hamming, Metric::Jaccard] {
+            let data = binary_data(500);
+            let queries = binary_data(20);
+            let index = NNDescentBuilder::new()
+                .with_data(data)
+                .with_metric(metric.clone())
+                .with_n_neighbors(15)
+                .build();
+
+            let k = 10;
+            let mut hits = 0;
+            for query in queries.iter() {
+                let expected = brute_force_knn(index.data(), query, &metric, k);
+                let results = index.query(query, k, 0.2);
+                hits += results
+                    .iter()
+                    .filter(|(idx, _)| expected.contains(idx))
+                    .count();
+            }
+
+            let recall = hits as f32 / (k * queries.len()) as f32;
+            assert!(recall >= 0.8, "Recall too low for {metric:?}: {recall}");
+        }
+    }
+
+    #[test]
+    fn test_query_recall_haversine() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let random_location = || {
+            let lat = fastrand::f32().mul_add(PI, -FRAC_PI_2);
+            let long = fastrand::f32().mul_add(2.0 * PI, -PI);
+            Vector::<X2, Auto>::try_from_vec(vec![lat, long]).expect("Load vec")
+        };
+
+        let data = std::iter::repeat_with(random_location).take(500).collect();
+        let queries = std::iter::repeat_with(random_location)
+            .take(20)
+            .collect::<Vec<_>>();
+        let index = NNDescentBuilder::new()
+            .with_data(data)
+            .with_metric(Metric::Haversine)
+            .with_n_neighbors(15)
+            .build();
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+
+        let e = NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_metric(Metric::Haversine)
+            .with_n_neighbors(5)
+            .try_build()
+            .err()
+            .unwrap();
+        assert!(matches!(
+            e,
+            BuildError::InvalidParameter { name: "metric", .. }
+        ));
+    }
+
+    #[test]
+    fn test_query_true_distances_and_similarities() {
+        let data = test_data(300);
+        let query = test_data(1).remove(0);
+
+        let index = NNDescentBuilder::new()
+            .with_data(data)
+            .with_n_neighbors(10)
+            .build();
+        let results = index.query(&query, 5, 0.1);
+
+        let true_distances = index.query_true_distances(&query, 5, 0.1);
+        assert_eq!(true_distances.len(), results.len());
+        for ((idx, dist), (true_idx, true_dist)) in results.iter().zip(&true_distances) {
+            assert_eq!(idx, true_idx);
+            assert!((dist.sqrt() - true_dist).abs() < 1e-4);
+        }
+
+        let similarities = index.query_similarities(&query, 5, 0.1);
+        for ((idx, dist), (sim_idx, sim)) in results.iter().zip(&similarities) {
+            assert_eq!(idx, sim_idx);
+            assert!((1.0 / (1.0 + dist.sqrt()) - sim).abs() < 1e-4);
+        }
+        assert!(similarities.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_metric(Metric::Cosine)
+            .with_n_neighbors(10)
+            .build();
+        let results = index.query(&query, 5, 0.1);
+        let similarities = index.query_similarities(&query, 5, 0.1);
+        for ((_, dist), (_, sim)) in results.iter().zip(&similarities) {
+            assert_eq!(1.0 - dist, *sim);
+        }
+        // Cosine distances are already true distances.
+        assert_eq!(index.query_true_distances(&query, 5, 0.1), results);
+    }
+
+    #[test]
+    fn test_query_recall_sparse() {
+        // Clustered bag-of-words style rows, each made up of a handful of
+        // dimensions from the vocabulary of its cluster plus some common words
+        // shared by every cluster.
+        let dims = 100_000;
+        let common = (0..50)
+            .map(|_| fastrand::u32(0..dims as u32))
+            .collect::<Vec<_>>();
+        let vocabularies = (0..4)
+            .map(|_| {
+                (0..80)
+                    .map(|_| fastrand::u32(0..dims as u32))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let random_csr = |n: usize| {
+            let mut indptr = vec![0];
+            let mut indices = Vec::new();
+            let mut values = Vec::new();
+            for _ in 0..n {
+                let vocabulary = &vocabularies[fastrand::usize(0..vocabularies.len())];
+                let mut row = (0..30)
+                    .map(|_| vocabulary[fastrand::usize(0..vocabulary.len())])
+                    .chain((0..10).map(|_| common[fastrand::usize(0..common.len())]))
+                    .collect::<Vec<_>>();
+                row.sort_unstable();
+                row.dedup();
+                values.extend(row.iter().map(|_| fastrand::f32() + 0.1));
+                indices.extend(row);
+                indptr.push(indices.len());
+            }
+            (indptr, indices, values)
+        };
+
+        let (indptr, indices, values) = random_csr(500);
+        let (q_indptr, q_indices, q_values) = random_csr(20);
+        let queries = SparseVector::try_from_csr(dims, &q_indptr, &q_indices, &q_values)
+            .expect("Load queries");
+
+        for metric in [Metric::Cosine, Metric::SquaredEuclidean, Metric::Jaccard] {
+            let index = NNDescentBuilder::new()
+                .with_sparse_data(dims, &indptr, &indices, &values)
+                .expect("Load data")
+                .with_metric(metric.clone())
+                .with_n_neighbors(15)
+                .build();
+
+            let k = 10;
+            let mut hits = 0;
+            for query in queries.iter() {
+                let mut query = query.clone();
+                if metric.requires_normalizing() {
+                    query.normalize();
+                }
+                let expected = brute_force_knn(index.data(), &query, index.metric(), k);
+                let results = index.query(&query, k, 0.2);
+                hits += results
+                    .iter()
+                    .filter(|(idx, _)| expected.contains(idx))
+                    .count();
+            }
+
+            let recall = hits as f32 / (k * queries.len()) as f32;
+            assert!(recall >= 0.8, "Recall too low for {metric:?}: {recall}");
+        }
+
+        let e = NNDescentBuilder::new()
+            .with_sparse_data(dims, &[0, 2], &[5, 1], &[1.0, 1.0])
+            .err()
+            .unwrap();
+        assert!(matches!(e, SparseVectorCreateError::UnsortedIndices));
+    }
+
+    #[test]
+    fn test_query_recall_inner_product() {
+        // Scale the vectors so their norms vary, otherwise the ranking would
+        // be the same as the normalized dot product.
+        let scaled_data = |n: usize| {
+            test_data(n)
+                .into_iter()
+                .map(|v| v * fastrand::f32().mul_add(4.0, 0.5))
+                .collect::<Vec<_>>()
+        };
+
+        let data = scaled_data(500);
+        let queries = scaled_data(20);
+        let index = NNDescentBuilder::new()
+            .with_data(data)
+            .with_metric(Metric::InnerProduct)
+            .with_n_neighbors(15)
+            .build();
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, Metric::InnerProduct, k);
+            let results = index.query(query, k, 0.2);
+            assert!(results.windows(2).all(|w| w[0].1 <= w[1].1));
+            assert!(results.iter().all(|(_, dist)| *dist < 0.0));
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .build();
+
+        let inserted = test_data(50);
+        for (i, vector) in inserted.into_iter().enumerate() {
+            let idx = index.insert(vector);
+            assert_eq!(idx, 300 + i);
+        }
+
+        assert_eq!(index.data().len(), 350);
+        assert_eq!(index.graph().n_vertices(), 350);
+        assert_eq!(index.search_graph().n_vertices(), 350);
+
+        let mut n_found = 0;
+        for idx in 300..350 {
+            let neighbors = index.graph().point(idx).iter_neighbors();
+            assert!(neighbors.filter(|n| n.idx() != u32::MAX).count() > 0);
+            let is_linked = (0..index.data().len())
+                .any(|v| index.search_graph().neighbors(v).contains(&(idx as u32)));
+            assert!(is_linked, "Inserted point {idx} is unreachable");
+
+            let results = index.query(&index.data()[idx], 5, 0.1);
+            n_found += (results[0].0 == idx) as usize;
+        }
+        assert!(n_found >= 45, "Only found {n_found} inserted points");
+    }
+
+    #[test]
+    fn test_insert_saturated_search_graph() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .build();
+
+        // Without any edges there is nothing to replace, so an edge must be added.
+        let n_vertices = index.data().len();
+        index.search_graph =
+            SearchGraph::from_adjacency_lists(vec![Vec::new(); n_vertices]);
+
+        let idx = index.insert(clone_data(&index.data()[..1]).remove(0));
+        let is_linked = (0..n_vertices)
+            .any(|v| index.search_graph().neighbors(v).contains(&(idx as u32)));
+        assert!(is_linked, "Inserted point {idx} is unreachable");
+
+        let results = index.query(&index.data()[idx], 2, 0.1);
+        assert!(results.iter().any(|&(p, _)| p == idx), "{results:?}");
+    }
+
+    #[test]
+    fn test_insert_and_refine_recall() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(15)
+            .build();
+
+        for vector in test_data(200) {
+            index.insert_and_refine(vector);
+        }
+
+        let k = 10;
+        let queries = test_data(20);
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[test]
+    fn test_insert_into_empty() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(1))
+            .with_n_neighbors(1)
+            .build();
+
+        let idx = index.insert(test_data(1).remove(0));
+        assert_eq!(idx, 1);
+        assert_eq!(index.graph().point(1).neighbor(0).idx(), 0);
+        assert_eq!(index.search_graph().neighbors(1), [0]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .build();
+
+        for idx in (0..300).step_by(3) {
+            assert!(index.remove(idx));
+        }
+        assert!(!index.remove(0));
+        assert_eq!(index.n_removed(), 100);
+
+        for query in test_data(10).iter().chain(index.data()[..10].iter()) {
+            let results = index.query(query, 10, 0.1);
+            assert_eq!(results.len(), 10);
+            assert!(results.iter().all(|(idx, _)| !index.is_removed(*idx)));
+        }
+
+        let mut buf = Vec::new();
+        assert!(index.save_to(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut index = NNDescentBuilder::new()
+            .with_data(test_data(400))
+            .with_n_neighbors(15)
+            .build();
+
+        let removed = (0..400)
+            .filter(|_| fastrand::f32() < 0.3)
+            .collect::<Vec<_>>();
+        for &idx in removed.iter() {
+            index.remove(idx);
+        }
+        let expected_data = (0..400)
+            .filter(|idx| !removed.contains(idx))
+            .map(|idx| index.data()[idx].to_vec())
+            .collect::<Vec<_>>();
+
+        let mapping = index.compact();
+        let n_live = 400 - removed.len();
+        assert_eq!(mapping.len(), 400);
+        assert_eq!(mapping.iter().flatten().count(), n_live);
+        for &idx in removed.iter() {
+            assert_eq!(mapping[idx], None);
+        }
+
+        assert_eq!(index.n_removed(), 0);
+        assert_eq!(index.data().len(), n_live);
+        assert_eq!(index.graph().n_vertices(), n_live);
+        assert_eq!(index.search_graph().n_vertices(), n_live);
+        for (vector, expected) in index.data().iter().zip(expected_data) {
+            assert_eq!(vector.as_ref(), expected.as_slice());
+        }
+
+        for p in 0..n_live {
+            let neighbors = index.graph().point(p).iter_neighbors().collect::<Vec<_>>();
+            assert!(!neighbors.is_empty());
+            assert!(neighbors.iter().all(|n| (n.idx() as usize) < n_live));
+            assert!(index
+                .search_graph()
+                .neighbors(p)
+                .iter()
+                .all(|&n| (n as usize) < n_live));
+        }
+
+        let k = 10;
+        let queries = test_data(20);
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[test]
+    fn test_search_graph_pruned() {
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .with_pruning_degree_multiplier(1.5)
+            .build();
+
+        let search_graph = index.search_graph();
+        assert_eq!(search_graph.n_vertices(), 300);
+        for i in 0..search_graph.n_vertices() {
+            let neighbors = search_graph.neighbors(i);
+            assert!(!neighbors.is_empty());
+            assert!(neighbors.len() <= 15);
+            assert!(!neighbors.contains(&(i as u32)));
+        }
+    }
+
+    #[test]
+    fn test_search_graph_no_diversify() {
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(300))
+            .with_n_neighbors(10)
+            .with_diversify_prob(0.0)
+            .with_pruning_degree_multiplier(100.0)
+            .build();
+
+        // Without diversifying or pruning, every edge and its reverse is kept.
+        let graph = index.graph();
+        let search_graph = index.search_graph();
+        for i in 0..graph.n_vertices() {
+            for p in graph.point(i).iter_neighbors() {
+                if p.idx() as usize == i {
+                    continue;
+                }
+
+                assert!(search_graph.neighbors(i).contains(&p.idx()));
+                assert!(search_graph
+                    .neighbors(p.idx() as usize)
+                    .contains(&(i as u32)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_diversify() {
+        let data = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [0.0, 1.5]]
+            .into_iter()
+            .map(|[x, y]| {
+                let mut v = vec![0.0; 512];
+                v[0] = x;
+                v[1] = y;
+                Vector::<X512, Auto>::try_from_vec(v).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let neighbors =
+            vec![Point::new(1, 1.0), Point::new(3, 2.25), Point::new(2, 4.0)];
+
+        // Point `2` is closer to `1` than it is to `0` so it gets removed.
+        let mut rng = Rng::with_seed(0);
+        let retained = diversify(
+            neighbors.clone(),
+            &data,
+            &Metric::SquaredEuclidean,
+            1.0,
+            &mut rng,
+        );
+        let retained = retained.iter().map(|p| p.idx()).collect::<Vec<_>>();
+        assert_eq!(retained, [1, 3]);
+
+        let retained =
+            diversify(neighbors, &data, &Metric::SquaredEuclidean, 0.0, &mut rng);
+        assert_eq!(retained.len(), 3);
+    }
+
+    fn assert_same_graphs<V: SpacialOps>(a: &NNDescent<V>, b: &NNDescent<V>) {
+        let (a_graph, b_graph) = (a.graph(), b.graph());
+        for idx in 0..a_graph.n_vertices() {
+            let a_neighbors = a_graph
+                .point(idx)
+                .iter_neighbors()
+                .map(|p| (p.idx(), p.dist()));
+            let b_neighbors = b_graph
+                .point(idx)
+                .iter_neighbors()
+                .map(|p| (p.idx(), p.dist()));
+            assert!(a_neighbors.eq(b_neighbors), "neighbors of {idx} differ");
+        }
+        for idx in 0..a.search_graph().n_vertices() {
+            assert_eq!(
+                a.search_graph().neighbors(idx),
+                b.search_graph().neighbors(idx)
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_seeded() {
+        let data = test_data(300);
+        let build = |seed| {
+            NNDescentBuilder::new()
+                .with_data(clone_data(&data))
+                .with_n_neighbors(10)
+                .with_seed(seed)
+                .build()
+        };
+
+        let a = build(42);
+        let b = build(42);
+        assert_same_graphs(&a, &b);
+
+        // Asking for more neighbors than fit in the leaves exercises the top-up.
+        for query in data.iter().take(20) {
+            assert_eq!(a.query(query, 50, 0.1), a.query(query, 50, 0.1));
+            assert_eq!(a.query(query, 50, 0.1), b.query(query, 50, 0.1));
+        }
+    }
+
+    #[test]
+    fn test_try_build_errors() {
+        let err = |builder: NNDescentBuilder| builder.try_build().err().unwrap();
+
+        let empty = NNDescentBuilder::new().with_data(Vec::<Vector<X512, Auto>>::new());
+        assert_eq!(empty.try_build().err().unwrap(), BuildError::EmptyData);
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(0));
+        assert_eq!(e, BuildError::ZeroNeighbors);
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(11));
+        assert_eq!(
+            e,
+            BuildError::TooManyNeighbors {
+                n_neighbors: 11,
+                n_points: 10
+            }
+        );
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(5)
+            .with_leaf_size(4));
+        assert_eq!(
+            e,
+            BuildError::LeafSizeTooSmall {
+                leaf_size: 4,
+                n_neighbors: 5
+            }
+        );
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(5)
+            .with_diversify_prob(1.5));
+        assert!(matches!(
+            e,
+            BuildError::InvalidParameter {
+                name: "diversify_prob",
+                ..
+            }
+        ));
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(5)
+            .with_delta(f32::NAN));
+        assert!(matches!(
+            e,
+            BuildError::InvalidParameter { name: "delta", .. }
+        ));
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(5)
+            .with_metric(Metric::Minkowski { p: 0.0 }));
+        assert!(matches!(e, BuildError::InvalidParameter { name: "p", .. }));
+
+        let e = err(NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(5)
+            .with_metric(Metric::WeightedSquaredEuclidean {
+                weights: vec![1.0; 256],
+            }));
+        assert_eq!(
+            e,
+            BuildError::MismatchedWeights {
+                expected: 512,
+                got: 256
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "Mismatched Weights: expected 512 weights but got 256"
+        );
+
+        let mixed = [vec![0.5; 8], vec![0.5; 8], vec![0.5; 16]]
+            .into_iter()
+            .map(|v| Vector::<XAny, Auto>::try_from_vec(v).unwrap())
+            .collect::<Vec<_>>();
+        let e = NNDescentBuilder::new()
+            .with_data(mixed)
+            .with_n_neighbors(2)
+            .try_build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            e,
+            BuildError::MismatchedDimensions {
+                idx: 2,
+                expected: 8,
+                got: 16
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "Mismatched Dimensions: expected 8 but vector 2 has 16"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Zero Neighbors")]
+    fn test_build_panics_on_invalid_config() {
+        NNDescentBuilder::new()
+            .with_data(test_data(10))
+            .with_n_neighbors(0)
+            .build();
+    }
+
+    #[test]
+    fn test_query_k_larger_than_data() {
+        let index = NNDescentBuilder::new()
+            .with_data(test_data(12))
+            .with_n_neighbors(5)
+            .build();
+
+        let results = index.query(&index.data()[3], 20, 0.1);
+        assert_eq!(results.len(), 12);
+        assert!(index.query(&index.data()[3], 0, 0.1).is_empty());
+    }
+
     #[test]
     fn test_apply_graph_updates_low_memory_empty() {
         let mut graph = DynamicGraph::new(10, 3);
@@ -668,32 +2790,147 @@ mod tests {
         assert_eq!(point_5.neighbor(0).idx(), 2);
     }
 
-    // #[test]
-    // fn test_new_build_candidates_empty() {
-    //     let mut graph = DynamicGraph::new(10, 3);
-    //     let (new, old) = new_build_candidates(&mut graph, 10);
-    //     assert!(new.iter().all(|n| n.iter_neighbors().count() == 0));
-    //     assert!(old.iter().all(|n| n.iter_neighbors().count() == 0));
-    // }
-    //
-    // #[test]
-    // fn test_new_build_candidates() {
-    //     let updates = vec![
-    //         (0, 1, 1.0),    // Dist point 0 <-> point 1 1.0
-    //         (2, 3, 0.5),    // Dist point 2 <-> point 3, 0.5
-    //         (2, 5, 1.2),    // Dist point 2 <-> point 5, 1.2
-    //         (1, 2, 0.5),    // Dist point 1 <-> point 2, 0.5
-    //     ];
-    //
-    //     let mut graph = DynamicGraph::new(10, 3);
-    //     let changes = apply_graph_updates_low_memory(&mut graph, updates);
-    //     assert_eq!(changes, 8);
-    //
-    //     let (new, old) = new_build_candidates(&mut graph, 10);
-    //     for (p, neighbors) in new.into_iter().enumerate() {
-    //         println!("{p} - {neighbors:?}");
-    //     }
-    //
-    //     println!("{old:?}");
-    // }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_apply_graph_updates_parallel() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+        let updates = (0..200)
+            .map(|_| {
+                let p = fastrand::u32(0..50);
+                let q = fastrand::u32(0..50);
+                (p, q, fastrand::f32())
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = DynamicGraph::new(50, 5);
+        let expected_changes =
+            apply_graph_updates_low_memory(&mut expected, updates.clone());
+
+        let mut graph = DynamicGraph::new(50, 5);
+        let changes = apply_graph_updates_parallel(&mut graph, &updates, &pool);
+        assert_eq!(changes, expected_changes);
+
+        for i in 0..50 {
+            let expected = expected.point(i).iter_neighbors().collect::<Vec<_>>();
+            let actual = graph.point(i).iter_neighbors().collect::<Vec<_>>();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_query_recall_parallel() {
+        let data = test_data(500);
+        let queries = test_data(20);
+        let index = NNDescentBuilder::new()
+            .with_data(data)
+            .with_n_neighbors(15)
+            .with_n_threads(3)
+            .build();
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let expected = brute_force_knn(index.data(), query, index.metric(), k);
+            let results = index.query(query, k, 0.2);
+            hits += results
+                .iter()
+                .filter(|(idx, _)| expected.contains(idx))
+                .count();
+        }
+
+        let recall = hits as f32 / (k * queries.len()) as f32;
+        assert!(recall >= 0.8, "Recall too low: {recall}");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_build_seeded_parallel() {
+        let data = test_data(300);
+        let build = || {
+            NNDescentBuilder::new()
+                .with_data(clone_data(&data))
+                .with_n_neighbors(10)
+                .with_n_threads(3)
+                .with_seed(7)
+                .build()
+        };
+
+        let a = build();
+        let b = build();
+        assert_same_graphs(&a, &b);
+    }
+
+    #[test]
+    fn test_new_build_candidates_empty() {
+        let mut graph = DynamicGraph::new(10, 3);
+        let (new, old) = new_build_candidates(&mut graph, 10);
+        assert!(new.iter().all(|n| n.iter_neighbors().count() == 0));
+        assert!(old.iter().all(|n| n.iter_neighbors().count() == 0));
+    }
+
+    #[test]
+    fn test_new_build_candidates() {
+        let updates = vec![
+            (0, 1, 1.0), // Dist point 0 <-> point 1 1.0
+            (2, 3, 0.5), // Dist point 2 <-> point 3, 0.5
+            (2, 5, 1.2), // Dist point 2 <-> point 5, 1.2
+            (1, 2, 0.5), // Dist point 1 <-> point 2, 0.5
+        ];
+
+        let mut graph = DynamicGraph::new(10, 3);
+        let changes = apply_graph_updates_low_memory(&mut graph, updates);
+        assert_eq!(changes, 8);
+
+        let (new, old) = new_build_candidates(&mut graph, 10);
+        let point_2 = new[2].iter_neighbors().map(|p| p.idx()).collect::<Vec<_>>();
+        assert_eq!(point_2, [3, 1, 5]);
+        assert!(old.iter().all(|n| n.iter_neighbors().count() == 0));
+
+        // All neighbors were selected as new candidates, so are now marked as old.
+        for i in 0..graph.n_vertices() {
+            assert!(graph.point(i).iter_neighbors().all(|p| !p.flag()));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_new_build_candidates_parallel() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+        let updates = (0..200)
+            .map(|_| {
+                let p = fastrand::u32(0..50);
+                let q = fastrand::u32(0..50);
+                (p, q, fastrand::f32())
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected_graph = DynamicGraph::new(50, 5);
+        apply_graph_updates_low_memory(&mut expected_graph, updates.clone());
+        let (expected_new, expected_old) = new_build_candidates(&mut expected_graph, 4);
+
+        let mut graph = DynamicGraph::new(50, 5);
+        apply_graph_updates_low_memory(&mut graph, updates);
+        let (new, old) = new_build_candidates_parallel(&mut graph, 4, &pool);
+
+        for i in 0..50 {
+            let collect = |n: &SortedNeighbors| n.iter_neighbors().collect::<Vec<_>>();
+            assert_eq!(collect(&new[i]), collect(&expected_new[i]));
+            assert_eq!(collect(&old[i]), collect(&expected_old[i]));
+
+            let flags = |g: &DynamicGraph| {
+                g.point(i)
+                    .iter_neighbors()
+                    .map(|p| p.flag())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(flags(&graph), flags(&expected_graph));
+        }
+    }
 }
@@ -0,0 +1,947 @@
+//! The on-disk format of a [NNDescent](crate::NNDescent) index.
+//!
+//! All values are stored little-endian regardless of the host platform, and every
+//! section begins on an 8 byte boundary (padded with zeroes) so the file can be
+//! mapped into memory and read in place.
+//!
+//! ```text
+//! Header (64 bytes):
+//!     magic           [u8; 8]     b"EONNIDX\0"
+//!     version         u32         FORMAT_VERSION
+//!     metric          u32         see `metric_to_tag`
+//!     n_vertices      u64
+//!     dims            u64
+//!     n_neighbors     u64
+//!     n_edges         u64         number of edges in the search graph
+//!     n_trees         u64
+//!     n_metric_params u64         number of metric parameters, see `metric_params`
+//!
+//! Metric parameters:
+//!     params          [f32; n_metric_params]
+//!
+//! Vectors:
+//!     values          [f32; n_vertices * dims]
+//!
+//! Nearest neighbor graph (sorted by ascending distance, empty slots are `u32::MAX`):
+//!     indices         [u32; n_vertices * n_neighbors]
+//!     distances       [f32; n_vertices * n_neighbors]
+//!
+//! Search graph (CSR):
+//!     indptr          [u64; n_vertices + 1]
+//!     indices         [u32; n_edges]
+//!
+//! RP trees (repeated `n_trees` times):
+//!     n_nodes         u64
+//!     n_internal      u64
+//!     n_leaf_indices  u64
+//!     leaf_size       u64
+//!     n_leaves        u64
+//!     children        [u32; n_nodes * 2]      `u32::MAX` for leaf nodes
+//!     offsets         [f32; n_nodes]
+//!     hyperplanes     [f32; n_internal * dims]    internal nodes only, in node order
+//!     leaf_indptr     [u64; n_nodes + 1]
+//!     leaf_indices    [u32; n_leaf_indices]
+//!
+//! Footer:
+//!     checksum        u64         FNV-1a of every preceding byte
+//! ```
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+use std::io;
+use std::io::{Read, Write};
+
+use fnv::FnvHasher;
+
+use crate::graph::{DynamicGraph, Point, SearchGraph};
+use crate::metric::Metric;
+use crate::rp_trees::Tree;
+
+/// The magic bytes every index file begins with.
+pub const MAGIC: [u8; 8] = *b"EONNIDX\0";
+/// The current version of the on-disk format.
+pub const FORMAT_VERSION: u32 = 1;
+/// The size of the fixed header in bytes.
+pub const HEADER_SIZE: usize = 64;
+/// The largest number of elements preallocated from an untrusted count, larger
+/// collections grow as their contents are read.
+const MAX_PREALLOC: usize = 1 << 16;
+
+#[derive(Debug)]
+/// An error that occurs while attempting to load a persisted index.
+pub enum LoadError {
+    /// An IO error occurred while reading the index.
+    Io(io::Error),
+    /// The file does not begin with the expected magic bytes.
+    BadMagic,
+    /// The file was written with a version of the format this build cannot read.
+    UnsupportedVersion { version: u32 },
+    /// The checksum of the file does not match the stored checksum.
+    ChecksumMismatch { expected: u64, got: u64 },
+    /// The index was saved with a different number of dimensions than the vector type.
+    BadDimensions { expected: usize, got: usize },
+    /// The file contains an unknown distance metric.
+    UnknownMetric { tag: u32 },
+    /// The file contents are malformed.
+    Corrupted(&'static str),
+    /// The index cannot be loaded on the current platform.
+    UnsupportedPlatform(&'static str),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "IO Error: {e}"),
+            Self::BadMagic => write!(f, "Bad Magic: file is not an EONN index"),
+            Self::UnsupportedVersion { version } => {
+                write!(
+                    f,
+                    "Unsupported Version: expected {FORMAT_VERSION} but got {version}"
+                )
+            },
+            Self::ChecksumMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Checksum Mismatch: expected {expected:#018x} but got {got:#018x}"
+                )
+            },
+            Self::BadDimensions { expected, got } => {
+                write!(f, "Bad Dimensions: expected {expected} but got {got}")
+            },
+            Self::UnknownMetric { tag } => write!(f, "Unknown Metric: tag {tag}"),
+            Self::Corrupted(reason) => write!(f, "Corrupted Index: {reason}"),
+            Self::UnsupportedPlatform(reason) => {
+                write!(f, "Unsupported Platform: {reason}")
+            },
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// The decoded contents of an index file.
+pub(crate) struct IndexParts {
+    pub(crate) metric: Metric,
+    pub(crate) dims: usize,
+    pub(crate) vectors: Vec<Vec<f32>>,
+    pub(crate) graph: DynamicGraph,
+    pub(crate) search_graph: SearchGraph,
+    pub(crate) trees: Vec<Tree<Vec<f32>>>,
+}
+
+/// The fixed size header of an index file.
+pub(crate) struct Header {
+    pub(crate) metric_tag: u32,
+    pub(crate) n_metric_params: usize,
+    pub(crate) n_vertices: usize,
+    pub(crate) dims: usize,
+    pub(crate) n_neighbors: usize,
+    pub(crate) n_edges: usize,
+    pub(crate) n_trees: usize,
+}
+
+pub(crate) fn metric_to_tag(metric: &Metric) -> u32 {
+    match metric {
+        Metric::Dot => 0,
+        Metric::SquaredEuclidean => 1,
+        Metric::Cosine => 2,
+        Metric::Manhattan => 3,
+        Metric::Chebyshev => 4,
+        Metric::InnerProduct => 5,
+        Metric::Hamming => 6,
+        Metric::Jaccard => 7,
+        Metric::Correlation => 8,
+        Metric::Hellinger => 9,
+        Metric::JensenShannon => 10,
+        Metric::Minkowski { .. } => 11,
+        Metric::WeightedSquaredEuclidean { .. } => 12,
+        Metric::Haversine => 13,
+    }
+}
+
+/// Returns the parameters of the metric which are stored after the header.
+pub(crate) fn metric_params(metric: &Metric) -> &[f32] {
+    match metric {
+        Metric::Minkowski { p } => std::slice::from_ref(p),
+        Metric::WeightedSquaredEuclidean { weights } => weights,
+        _ => &[],
+    }
+}
+
+pub(crate) fn metric_from_tag(
+    tag: u32,
+    params: &[f32],
+    dims: usize,
+) -> Result<Metric, LoadError> {
+    let metric = match (tag, params) {
+        (0, []) => Metric::Dot,
+        (1, []) => Metric::SquaredEuclidean,
+        (2, []) => Metric::Cosine,
+        (3, []) => Metric::Manhattan,
+        (4, []) => Metric::Chebyshev,
+        (5, []) => Metric::InnerProduct,
+        (6, []) => Metric::Hamming,
+        (7, []) => Metric::Jaccard,
+        (8, []) => Metric::Correlation,
+        (9, []) => Metric::Hellinger,
+        (10, []) => Metric::JensenShannon,
+        (11, &[p]) => Metric::Minkowski { p },
+        (12, weights) if weights.len() == dims => Metric::WeightedSquaredEuclidean {
+            weights: weights.to_vec(),
+        },
+        (13, []) => Metric::Haversine,
+        (0..=13, _) => {
+            return Err(LoadError::Corrupted("metric parameters are malformed"))
+        },
+        (tag, _) => return Err(LoadError::UnknownMetric { tag }),
+    };
+
+    Ok(metric)
+}
+
+/// Writes the index to the given writer.
+pub(crate) fn write_index<V: AsRef<[f32]>, W: Write>(
+    writer: W,
+    metric: &Metric,
+    data: &[V],
+    graph: &DynamicGraph,
+    search_graph: &SearchGraph,
+    trees: &[Tree<V>],
+) -> io::Result<()> {
+    let dims = data.first().map(|v| v.as_ref().len()).unwrap_or(0);
+    let n_vertices = data.len();
+    let n_neighbors = graph.n_neighbors();
+
+    let mut writer = ChecksumWriter::new(writer);
+
+    writer.write_all(&MAGIC)?;
+    writer.write_u32(FORMAT_VERSION)?;
+    writer.write_u32(metric_to_tag(metric))?;
+    writer.write_u64(n_vertices as u64)?;
+    writer.write_u64(dims as u64)?;
+    writer.write_u64(n_neighbors as u64)?;
+    writer.write_u64(search_graph.n_edges() as u64)?;
+    writer.write_u64(trees.len() as u64)?;
+    writer.write_u64(metric_params(metric).len() as u64)?;
+    writer.write_f32_slice(metric_params(metric))?;
+    writer.pad()?;
+
+    for vector in data {
+        let vector = vector.as_ref();
+        if vector.len() != dims {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "all vectors must have the same dimensions to be saved",
+            ));
+        }
+        writer.write_f32_slice(vector)?;
+    }
+    writer.pad()?;
+
+    for i in 0..n_vertices {
+        let point = graph.point(i);
+        for j in 0..n_neighbors {
+            writer.write_u32(point.neighbor(j).idx())?;
+        }
+    }
+    writer.pad()?;
+    for i in 0..n_vertices {
+        let point = graph.point(i);
+        for j in 0..n_neighbors {
+            writer.write_f32(point.neighbor(j).dist())?;
+        }
+    }
+    writer.pad()?;
+
+    for &offset in search_graph.indptr() {
+        writer.write_u64(offset as u64)?;
+    }
+    writer.write_u32_slice(search_graph.indices())?;
+    writer.pad()?;
+
+    for tree in trees {
+        write_tree(&mut writer, tree)?;
+    }
+
+    let checksum = writer.hasher.finish();
+    writer.inner.write_all(&checksum.to_le_bytes())?;
+    writer.inner.flush()
+}
+
+fn write_tree<V: AsRef<[f32]>, W: Write>(
+    writer: &mut ChecksumWriter<W>,
+    tree: &Tree<V>,
+) -> io::Result<()> {
+    let n_nodes = tree.children.len();
+    let n_internal = tree.children.iter().filter(|c| c.is_some()).count();
+    let n_leaf_indices = tree
+        .point_indices
+        .iter()
+        .filter_map(|v| v.as_ref())
+        .map(|v| v.len())
+        .sum::<usize>();
+
+    writer.write_u64(n_nodes as u64)?;
+    writer.write_u64(n_internal as u64)?;
+    writer.write_u64(n_leaf_indices as u64)?;
+    writer.write_u64(tree.leaf_size as u64)?;
+    writer.write_u64(tree.n_leaves as u64)?;
+
+    for children in tree.children.iter() {
+        let (left, right) = children
+            .map(|(l, r)| (l as u32, r as u32))
+            .unwrap_or((u32::MAX, u32::MAX));
+        writer.write_u32(left)?;
+        writer.write_u32(right)?;
+    }
+    writer.pad()?;
+
+    writer.write_f32_slice(&tree.offsets)?;
+    writer.pad()?;
+
+    for (hyperplane, children) in tree.hyperplanes.iter().zip(tree.children.iter()) {
+        if children.is_some() {
+            let hyperplane = hyperplane.as_ref().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "internal tree node is missing its hyperplane",
+                )
+            })?;
+            writer.write_f32_slice(hyperplane.as_ref())?;
+        }
+    }
+    writer.pad()?;
+
+    let mut offset = 0;
+    writer.write_u64(0)?;
+    for indices in tree.point_indices.iter() {
+        offset += indices.as_ref().map(|v| v.len()).unwrap_or(0);
+        writer.write_u64(offset as u64)?;
+    }
+    for indices in tree.point_indices.iter().flatten() {
+        for &idx in indices {
+            writer.write_u32(idx as u32)?;
+        }
+    }
+    writer.pad()
+}
+
+/// Parses and validates the fixed size index header.
+pub(crate) fn parse_header(buf: &[u8; HEADER_SIZE]) -> Result<Header, LoadError> {
+    let u32_at = |pos: usize| u32::from_le_bytes(buf[pos..pos + 4].try_into().unwrap());
+    let u64_at = |pos: usize| u64::from_le_bytes(buf[pos..pos + 8].try_into().unwrap());
+
+    if buf[..8] != MAGIC {
+        return Err(LoadError::BadMagic);
+    }
+
+    let version = u32_at(8);
+    if version != FORMAT_VERSION {
+        return Err(LoadError::UnsupportedVersion { version });
+    }
+
+    let dims = to_usize(u64_at(24))?;
+    let n_metric_params = to_usize(u64_at(56))?;
+    if n_metric_params > dims.max(1) {
+        return Err(LoadError::Corrupted("too many metric parameters"));
+    }
+
+    Ok(Header {
+        metric_tag: u32_at(12),
+        n_metric_params,
+        n_vertices: to_usize(u64_at(16))?,
+        dims,
+        n_neighbors: to_usize(u64_at(32))?,
+        n_edges: to_usize(u64_at(40))?,
+        n_trees: to_usize(u64_at(48))?,
+    })
+}
+
+/// Reads the index from the given reader, validating its contents and checksum.
+pub(crate) fn read_index<R: Read>(reader: R) -> Result<IndexParts, LoadError> {
+    let mut reader = ChecksumReader::new(reader);
+
+    let mut buf = [0; HEADER_SIZE];
+    reader.read_exact(&mut buf)?;
+    let header = parse_header(&buf)?;
+    let Header {
+        metric_tag,
+        n_metric_params,
+        n_vertices,
+        dims,
+        n_neighbors,
+        n_edges,
+        n_trees,
+    } = header;
+
+    let params = reader.read_f32_vec(n_metric_params)?;
+    reader.skip_padding()?;
+    let metric = metric_from_tag(metric_tag, &params, dims)?;
+
+    if n_vertices >= u32::MAX as usize {
+        return Err(LoadError::Corrupted("too many vertices"));
+    }
+
+    let mut vectors = Vec::with_capacity(n_vertices.min(MAX_PREALLOC));
+    for _ in 0..n_vertices {
+        vectors.push(reader.read_f32_vec(dims)?);
+    }
+    reader.skip_padding()?;
+
+    let n_slots = checked_mul(n_vertices, n_neighbors)?;
+    let indices = reader.read_u32_vec(n_slots)?;
+    reader.skip_padding()?;
+    let distances = reader.read_f32_vec(n_slots)?;
+    reader.skip_padding()?;
+
+    let mut graph = DynamicGraph::new(n_vertices, n_neighbors);
+    for i in 0..n_vertices {
+        let point = graph.point_mut(i);
+        for j in 0..n_neighbors {
+            let idx = indices[i * n_neighbors + j];
+            let dist = distances[i * n_neighbors + j];
+            if idx != u32::MAX && idx as usize >= n_vertices {
+                return Err(LoadError::Corrupted("graph neighbor out of bounds"));
+            }
+            *point.neighbor_mut(j) = Point {
+                idx,
+                dist,
+                flag: false,
+            };
+        }
+    }
+
+    let mut indptr = Vec::with_capacity(n_vertices.min(MAX_PREALLOC) + 1);
+    for _ in 0..n_vertices + 1 {
+        indptr.push(to_usize(reader.read_u64()?)?);
+    }
+    let search_indices = reader.read_u32_vec(n_edges)?;
+    reader.skip_padding()?;
+    validate_csr(&indptr, &search_indices, n_vertices)?;
+    let search_graph = SearchGraph::from_raw_parts(indptr, search_indices);
+
+    let mut trees = Vec::with_capacity(n_trees.min(MAX_PREALLOC));
+    for _ in 0..n_trees {
+        trees.push(read_tree(&mut reader, dims, n_vertices)?);
+    }
+
+    let expected = reader.hasher.finish();
+    let mut buf = [0; 8];
+    reader.inner.read_exact(&mut buf)?;
+    let got = u64::from_le_bytes(buf);
+    if expected != got {
+        return Err(LoadError::ChecksumMismatch { expected, got });
+    }
+
+    Ok(IndexParts {
+        metric,
+        dims,
+        vectors,
+        graph,
+        search_graph,
+        trees,
+    })
+}
+
+fn read_tree<R: Read>(
+    reader: &mut ChecksumReader<R>,
+    dims: usize,
+    n_vertices: usize,
+) -> Result<Tree<Vec<f32>>, LoadError> {
+    let n_nodes = to_usize(reader.read_u64()?)?;
+    let n_internal = to_usize(reader.read_u64()?)?;
+    let n_leaf_indices = to_usize(reader.read_u64()?)?;
+    let leaf_size = to_usize(reader.read_u64()?)?;
+    let n_leaves = to_usize(reader.read_u64()?)?;
+
+    if n_internal > n_nodes {
+        return Err(LoadError::Corrupted("more internal nodes than tree nodes"));
+    }
+
+    let raw_children = reader.read_u32_vec(checked_mul(n_nodes, 2)?)?;
+    reader.skip_padding()?;
+    let offsets = reader.read_f32_vec(n_nodes)?;
+    reader.skip_padding()?;
+
+    let mut children = Vec::with_capacity(n_nodes.min(MAX_PREALLOC));
+    for (node, pair) in raw_children.chunks_exact(2).enumerate() {
+        let (left, right) = (pair[0], pair[1]);
+        if left == u32::MAX && right == u32::MAX {
+            children.push(None);
+        } else if left == u32::MAX || right == u32::MAX {
+            return Err(LoadError::Corrupted("tree node is only partially a leaf"));
+        } else if (left as usize) < node && (right as usize) < node {
+            // Children are always pushed before their parent, which also rules out cycles.
+            children.push(Some((left as usize, right as usize)));
+        } else {
+            return Err(LoadError::Corrupted("tree child out of bounds"));
+        }
+    }
+
+    if children.iter().filter(|c| c.is_some()).count() != n_internal {
+        return Err(LoadError::Corrupted("tree internal node count mismatch"));
+    }
+
+    let mut hyperplanes = Vec::with_capacity(n_nodes.min(MAX_PREALLOC));
+    for children in children.iter() {
+        if children.is_some() {
+            hyperplanes.push(Some(reader.read_f32_vec(dims)?));
+        } else {
+            hyperplanes.push(None);
+        }
+    }
+    reader.skip_padding()?;
+
+    let mut leaf_indptr = Vec::with_capacity(n_nodes.min(MAX_PREALLOC) + 1);
+    for _ in 0..n_nodes + 1 {
+        leaf_indptr.push(to_usize(reader.read_u64()?)?);
+    }
+    let leaf_indices = reader.read_u32_vec(n_leaf_indices)?;
+    reader.skip_padding()?;
+    validate_csr(&leaf_indptr, &leaf_indices, n_vertices)?;
+
+    let point_indices = children
+        .iter()
+        .enumerate()
+        .map(|(node, children)| {
+            if children.is_some() {
+                return None;
+            }
+
+            let indices = &leaf_indices[leaf_indptr[node]..leaf_indptr[node + 1]];
+            Some(indices.iter().map(|&idx| idx as usize).collect())
+        })
+        .collect();
+
+    Ok(Tree {
+        hyperplanes,
+        offsets,
+        children,
+        point_indices,
+        leaf_size,
+        n_leaves,
+    })
+}
+
+/// Validates a CSR structure, ensuring all offsets and indices are in bounds.
+pub(crate) fn validate_csr<T: Copy + Into<u64>>(
+    indptr: &[usize],
+    indices: &[T],
+    n_vertices: usize,
+) -> Result<(), LoadError> {
+    if indptr.first() != Some(&0) || indptr.last() != Some(&indices.len()) {
+        return Err(LoadError::Corrupted("CSR offsets do not cover the indices"));
+    }
+
+    if indptr.windows(2).any(|w| w[0] > w[1]) {
+        return Err(LoadError::Corrupted("CSR offsets are not sorted"));
+    }
+
+    if indices.iter().any(|&idx| idx.into() >= n_vertices as u64) {
+        return Err(LoadError::Corrupted("CSR index out of bounds"));
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn to_usize(value: u64) -> Result<usize, LoadError> {
+    usize::try_from(value).map_err(|_| LoadError::Corrupted("value exceeds usize"))
+}
+
+#[inline]
+fn checked_mul(a: usize, b: usize) -> Result<usize, LoadError> {
+    a.checked_mul(b)
+        .ok_or(LoadError::Corrupted("section size overflows usize"))
+}
+
+#[inline]
+/// The number of padding bytes required to align `position` to 8 bytes.
+pub(crate) fn padding_for(position: u64) -> usize {
+    ((8 - (position % 8)) % 8) as usize
+}
+
+/// A writer which tracks the FNV-1a checksum and position of everything written.
+struct ChecksumWriter<W> {
+    inner: W,
+    hasher: FnvHasher,
+    position: u64,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: FnvHasher::default(),
+            position: 0,
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.hasher.write(buf);
+        self.position += buf.len() as u64;
+        self.inner.write_all(buf)
+    }
+
+    fn write_u32(&mut self, value: u32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, value: u64) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_f32(&mut self, value: f32) -> io::Result<()> {
+        self.write_all(&value.to_le_bytes())
+    }
+
+    fn write_u32_slice(&mut self, values: &[u32]) -> io::Result<()> {
+        let mut buf = Vec::with_capacity(values.len() * 4);
+        for value in values {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        self.write_all(&buf)
+    }
+
+    fn write_f32_slice(&mut self, values: &[f32]) -> io::Result<()> {
+        let mut buf = Vec::with_capacity(values.len() * 4);
+        for value in values {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        self.write_all(&buf)
+    }
+
+    /// Pads the output with zeroes to the next 8 byte boundary.
+    fn pad(&mut self) -> io::Result<()> {
+        let padding = padding_for(self.position);
+        self.write_all(&[0; 8][..padding])
+    }
+}
+
+/// A reader which tracks the FNV-1a checksum and position of everything read.
+struct ChecksumReader<R> {
+    inner: R,
+    hasher: FnvHasher,
+    position: u64,
+}
+
+impl<R: Read> ChecksumReader<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: FnvHasher::default(),
+            position: 0,
+        }
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.inner.read_exact(buf)?;
+        self.hasher.write(buf);
+        self.position += buf.len() as u64;
+        Ok(())
+    }
+
+    fn read_u64(&mut self) -> io::Result<u64> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, LoadError> {
+        // Read in bounded chunks so a corrupted length cannot trigger a huge allocation
+        // before the reader runs out of data.
+        const CHUNK_SIZE: usize = 1 << 20;
+
+        let mut buf = Vec::with_capacity(len.min(CHUNK_SIZE));
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = remaining.min(CHUNK_SIZE);
+            let start = buf.len();
+            buf.resize(start + n, 0);
+            self.read_exact(&mut buf[start..])?;
+            remaining -= n;
+        }
+
+        Ok(buf)
+    }
+
+    fn read_u32_vec(&mut self, len: usize) -> Result<Vec<u32>, LoadError> {
+        let buf = self.read_bytes(checked_mul(len, 4)?)?;
+        Ok(buf
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+            .collect())
+    }
+
+    fn read_f32_vec(&mut self, len: usize) -> Result<Vec<f32>, LoadError> {
+        let buf = self.read_bytes(checked_mul(len, 4)?)?;
+        Ok(buf
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect())
+    }
+
+    /// Skips the zero padding up to the next 8 byte boundary.
+    fn skip_padding(&mut self) -> Result<(), LoadError> {
+        let mut buf = [0; 8];
+        let padding = padding_for(self.position);
+        self.read_exact(&mut buf[..padding])?;
+
+        if buf.iter().any(|&b| b != 0) {
+            return Err(LoadError::Corrupted("non-zero padding"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eonn_accel::{Auto, Vector, X1024, X512};
+
+    use super::*;
+    use crate::{NNDescent, NNDescentBuilder};
+
+    fn sample_data() -> Vec<Vector<X512, Auto>> {
+        (0..200)
+            .map(|_| {
+                let v = std::iter::repeat_with(fastrand::f32).take(512).collect();
+                Vector::try_from_vec(v).expect("Load vec")
+            })
+            .collect()
+    }
+
+    fn build_index() -> NNDescent<Vector<X512, Auto>> {
+        NNDescentBuilder::new()
+            .with_data(sample_data())
+            .with_metric(Metric::SquaredEuclidean)
+            .with_n_neighbors(10)
+            .with_n_trees(2)
+            .build()
+    }
+
+    fn save_to_vec(index: &NNDescent<Vector<X512, Auto>>) -> Vec<u8> {
+        let mut buf = Vec::new();
+        index.save_to(&mut buf).expect("Save index");
+        buf
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let index = build_index();
+        let buf = save_to_vec(&index);
+        assert_eq!(buf.len() % 8, 0);
+
+        let loaded = NNDescent::<Vector<X512, Auto>>::load_from(buf.as_slice())
+            .expect("Load index");
+
+        assert_eq!(loaded.metric(), index.metric());
+        assert_eq!(loaded.data().len(), index.data().len());
+        for (a, b) in loaded.data().iter().zip(index.data()) {
+            assert_eq!(a.as_ref(), b.as_ref());
+        }
+
+        for p in 0..index.data().len() {
+            let expected = index.graph().point(p).iter_neighbors().collect::<Vec<_>>();
+            let got = loaded.graph().point(p).iter_neighbors().collect::<Vec<_>>();
+            assert_eq!(
+                expected
+                    .iter()
+                    .map(|p| (p.idx(), p.dist()))
+                    .collect::<Vec<_>>(),
+                got.iter().map(|p| (p.idx(), p.dist())).collect::<Vec<_>>(),
+            );
+            assert_eq!(
+                index.search_graph().neighbors(p),
+                loaded.search_graph().neighbors(p)
+            );
+        }
+
+        // Saving the loaded index again must produce an identical file.
+        assert_eq!(save_to_vec(&loaded), buf);
+
+        for query in index.data().iter().take(10) {
+            let expected = index.query(query, 5, 0.1);
+            let got = loaded.query(query, 5, 0.1);
+            assert_eq!(expected[0], got[0]);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_metric_params() {
+        let weights = (0..512).map(|i| i as f32 / 512.0).collect();
+
+        for metric in [
+            Metric::Minkowski { p: 3.0 },
+            Metric::WeightedSquaredEuclidean { weights },
+        ] {
+            let index = NNDescentBuilder::new()
+                .with_data(sample_data())
+                .with_metric(metric)
+                .with_n_neighbors(10)
+                .with_n_trees(2)
+                .build();
+            let buf = save_to_vec(&index);
+            assert_eq!(buf.len() % 8, 0);
+
+            let loaded = NNDescent::<Vector<X512, Auto>>::load_from(buf.as_slice())
+                .expect("Load index");
+            assert_eq!(loaded.metric(), index.metric());
+            assert_eq!(save_to_vec(&loaded), buf);
+        }
+    }
+
+    #[test]
+    fn test_save_load_file() {
+        let index = build_index();
+        let path = std::env::temp_dir()
+            .join(format!("eonn-persist-test-{}.idx", std::process::id()));
+
+        index.save(&path).expect("Save index");
+        let loaded = NNDescent::<Vector<X512, Auto>>::load(&path);
+        std::fs::remove_file(&path).expect("Remove file");
+
+        let loaded = loaded.expect("Load index");
+        assert_eq!(loaded.data().len(), index.data().len());
+    }
+
+    #[test]
+    fn test_checksum_mismatch() {
+        let index = build_index();
+        let mut buf = save_to_vec(&index);
+        buf[HEADER_SIZE + 17] ^= 0xFF;
+
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(buf.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(matches!(err, LoadError::ChecksumMismatch { .. }), "{err}");
+    }
+
+    #[test]
+    fn test_bad_header() {
+        let index = build_index();
+        let buf = save_to_vec(&index);
+
+        let mut bad_magic = buf.clone();
+        bad_magic[0] = b'X';
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(bad_magic.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(matches!(err, LoadError::BadMagic), "{err}");
+
+        let mut bad_version = buf.clone();
+        bad_version[8..12].copy_from_slice(&99u32.to_le_bytes());
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(bad_version.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(
+            matches!(err, LoadError::UnsupportedVersion { version: 99 }),
+            "{err}"
+        );
+
+        let mut bad_metric = buf.clone();
+        bad_metric[12..16].copy_from_slice(&42u32.to_le_bytes());
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(bad_metric.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(matches!(err, LoadError::UnknownMetric { tag: 42 }), "{err}");
+
+        let err = NNDescent::<Vector<X1024, Auto>>::load_from(buf.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(
+            matches!(
+                err,
+                LoadError::BadDimensions {
+                    expected: 1024,
+                    got: 512
+                }
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_cyclic_tree() {
+        let index = build_index();
+        let buf = save_to_vec(&index);
+
+        let u64_at =
+            |pos: usize| u64::from_le_bytes(buf[pos..pos + 8].try_into().unwrap());
+        let n_vertices = u64_at(16) as usize;
+        let dims = u64_at(24) as usize;
+        let n_neighbors = u64_at(32) as usize;
+        let n_edges = u64_at(40) as usize;
+
+        // Skip the vectors, graph and search graph to reach the first tree.
+        let mut pos = HEADER_SIZE + n_vertices * dims * 4;
+        pos += 2 * n_vertices * n_neighbors * 4;
+        pos += (n_vertices + 1) * 8 + n_edges * 4;
+        pos += padding_for(pos as u64);
+        let n_nodes = u64_at(pos) as usize;
+        let root = pos + 40 + (n_nodes - 1) * 8;
+
+        let mut self_loop = buf.clone();
+        let node = (n_nodes as u32 - 1).to_le_bytes();
+        self_loop[root..root + 4].copy_from_slice(&node);
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(self_loop.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(
+            matches!(err, LoadError::Corrupted("tree child out of bounds")),
+            "{err}"
+        );
+
+        let mut half_leaf = buf.clone();
+        half_leaf[root..root + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(half_leaf.as_slice())
+            .err()
+            .expect("Load should fail");
+        assert!(
+            matches!(
+                err,
+                LoadError::Corrupted("tree node is only partially a leaf")
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_truncated() {
+        let index = build_index();
+        let buf = save_to_vec(&index);
+
+        let err = NNDescent::<Vector<X512, Auto>>::load_from(&buf[..buf.len() / 2])
+            .err()
+            .expect("Load should fail");
+        assert!(matches!(err, LoadError::Io(_)), "{err}");
+    }
+
+    #[test]
+    fn test_corrupted_counts() {
+        let index = build_index();
+        let buf = save_to_vec(&index);
+
+        // Vertex and tree counts far beyond the data must fail without preallocating.
+        for (pos, count) in [(16, u32::MAX as u64 - 1), (48, 1 << 40)] {
+            let mut bad_count = buf.clone();
+            bad_count[pos..pos + 8].copy_from_slice(&count.to_le_bytes());
+            let err = NNDescent::<Vector<X512, Auto>>::load_from(bad_count.as_slice())
+                .err()
+                .expect("Load should fail");
+            assert!(matches!(err, LoadError::Io(_)), "{err}");
+        }
+    }
+}
@@ -6,8 +6,31 @@ use bitvec::bitvec;
 use bitvec::prelude::Lsb0;
 use bitvec::vec::BitVec;
 use eonn_accel::SpacialOps;
+use fastrand::Rng;
 use tracing::info;
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// The strategy used to pick the splitting hyperplane of each tree node.
+pub enum SplitStrategy {
+    /// Splits on the hyperplane between the directions of two points,
+    /// always passing through the origin.
+    Angular,
+    /// Splits on the hyperplane equidistant from two points.
+    Euclidean,
+    /// Splits on the hyperplane equidistant from two points after applying the
+    /// MIPS to Euclidean transform.
+    ///
+    /// Each data point `x` is augmented with an extra dimension of
+    /// `sqrt(φ - ‖x‖²)` where `φ` is the largest squared norm in the data, and
+    /// queries are augmented with `0`. The augmented data points all share the
+    /// same norm, so their Euclidean nearest neighbors to an augmented query are
+    /// exactly the points with the largest inner product.
+    ///
+    /// Queries have no augmented component, so the stored hyperplanes only need
+    /// the original dimensions and their offset is always zero.
+    InnerProduct,
+}
+
 #[derive(Debug)]
 pub struct Tree<V> {
     pub hyperplanes: Vec<Option<V>>,
@@ -18,20 +41,53 @@ pub struct Tree<V> {
     pub n_leaves: usize,
 }
 
+impl<V: SpacialOps> Tree<V> {
+    /// Traverses the tree from the root node, returning the point indices
+    /// of the leaf the `query` vector falls in.
+    pub fn search_leaf(&self, query: &V) -> &[usize] {
+        // Nodes are pushed after their children, so the root is always the last node.
+        let mut node = match self.children.len().checked_sub(1) {
+            Some(root) => root,
+            None => return &[],
+        };
+
+        while let Some((left, right)) = self.children[node] {
+            let hyperplane = self.hyperplanes[node]
+                .as_ref()
+                .expect("Internal node must have a hyperplane");
+            let margin = self.offsets[node] + hyperplane.dot(query);
+
+            // Points on the hyperplane always go left so queries are reproducible.
+            let is_left = margin > 0.0 || margin.abs() < f32::EPSILON;
+
+            node = if is_left { left } else { right };
+        }
+
+        self.point_indices[node].as_deref().unwrap_or(&[])
+    }
+}
+
 /// Builds a random project forest with`n_trees`.
+///
+/// Each tree is built with its own generator seeded from `rng`, so the forest
+/// is reproducible for a given `rng` state.
 pub fn make_forest<V: SpacialOps>(
     data: &[V],
     n_trees: usize,
     leaf_size: usize,
-    angular: bool,
+    split: SplitStrategy,
     max_depth: usize,
+    rng: &mut Rng,
 ) -> Vec<Tree<V>> {
     let mut trees = Vec::with_capacity(n_trees);
+    let augmented = augmented_dimension(data, split);
 
     let total = Instant::now();
     for i in 0..n_trees {
         let start = Instant::now();
-        let tree = make_dense_tree(data, leaf_size, angular, max_depth);
+        let mut tree_rng = rng.fork();
+        let tree =
+            make_tree(data, &augmented, leaf_size, split, max_depth, &mut tree_rng);
         trees.push(tree);
         info!(elapsed = ?start.elapsed(), idx = i, "Built tree");
     }
@@ -43,12 +99,16 @@ pub fn make_forest<V: SpacialOps>(
 #[cfg(feature = "rayon")]
 /// Builds a random project forest with `n_trees` in parallel using the
 /// given thread pool.
+///
+/// Each tree is built with its own generator seeded from `rng`, so the forest
+/// is reproducible for a given `rng` state regardless of the number of threads.
 pub fn make_forest_parallel<V: SpacialOps + Send + Sync + 'static>(
     data: &[V],
     n_trees: usize,
     leaf_size: usize,
-    angular: bool,
+    split: SplitStrategy,
     max_depth: usize,
+    rng: &mut Rng,
     pool: &rayon::ThreadPool,
 ) -> Vec<Tree<V>> {
     use rayon::prelude::*;
@@ -60,12 +120,23 @@ pub fn make_forest_parallel<V: SpacialOps + Send + Sync + 'static>(
 
     let total = Instant::now();
 
+    let augmented = augmented_dimension(data, split);
+    let tree_rngs = (0..n_trees).map(|_| rng.fork()).collect::<Vec<_>>();
+
     let trees = pool.install(|| {
-        (0..n_trees)
+        tree_rngs
             .into_par_iter()
-            .map(|idx| {
+            .enumerate()
+            .map(|(idx, mut tree_rng)| {
                 let start = Instant::now();
-                let tree = make_dense_tree(data, leaf_size, angular, max_depth);
+                let tree = make_tree(
+                    data,
+                    &augmented,
+                    leaf_size,
+                    split,
+                    max_depth,
+                    &mut tree_rng,
+                );
                 info!(elapsed = ?start.elapsed(), idx = idx, "Built tree");
                 tree
             })
@@ -104,11 +175,35 @@ fn get_leaves_from_tree<V: SpacialOps>(tree: &Tree<V>) -> Vec<Vec<usize>> {
     leaves
 }
 
-fn make_dense_tree<V: SpacialOps>(
+/// Computes the extra dimension of each data point used by the
+/// [SplitStrategy::InnerProduct] transform, this is empty for any other strategy.
+fn augmented_dimension<V: SpacialOps>(data: &[V], split: SplitStrategy) -> Vec<f32> {
+    if split != SplitStrategy::InnerProduct {
+        return Vec::new();
+    }
+
+    let squared_norms = data.iter().map(|v| v.squared_norm()).collect::<Vec<_>>();
+    let max_squared_norm = squared_norms.iter().copied().fold(0.0, f32::max);
+
+    squared_norms
+        .into_iter()
+        .map(|norm| (max_squared_norm - norm).max(0.0).sqrt())
+        .collect()
+}
+
+/// Builds a single random projection tree over `data`.
+///
+/// The hyperplanes are produced by the vectors themselves, so they share the
+/// representation of the data. Sparse vectors produce hyperplanes which only
+/// cover the non-zero dimensions of the two points they split, keeping both
+/// the build and each search step proportional to the number of non-zero values.
+fn make_tree<V: SpacialOps>(
     data: &[V],
+    augmented: &[f32],
     leaf_size: usize,
-    angular: bool,
+    split: SplitStrategy,
     max_depth: usize,
+    rng: &mut Rng,
 ) -> Tree<V> {
     let indices = (0..data.len()).collect::<Vec<usize>>();
     let mut tree = Tree {
@@ -120,10 +215,16 @@ fn make_dense_tree<V: SpacialOps>(
         n_leaves: 0,
     };
 
-    if angular {
-        make_angular_tree(&mut tree, data, indices, leaf_size, max_depth)
-    } else {
-        make_euclidean_tree(&mut tree, data, indices, leaf_size, max_depth)
+    match split {
+        SplitStrategy::Angular => {
+            make_angular_tree(&mut tree, data, indices, leaf_size, max_depth, rng)
+        },
+        SplitStrategy::Euclidean => {
+            make_euclidean_tree(&mut tree, data, indices, leaf_size, max_depth, rng)
+        },
+        SplitStrategy::InnerProduct => make_inner_product_tree(
+            &mut tree, data, augmented, indices, leaf_size, max_depth, rng,
+        ),
     }
 
     tree.leaf_size = cmp::max(
@@ -145,16 +246,17 @@ fn make_angular_tree<V: SpacialOps>(
     indices: Vec<usize>,
     leaf_size: usize,
     max_depth: usize,
+    rng: &mut Rng,
 ) {
     if indices.len() > leaf_size && max_depth > 0 {
         let (left_indices, right_indices, hyperplane) =
-            angular_random_project_split(data, indices);
+            angular_random_project_split(data, indices, rng);
 
-        make_angular_tree(tree, data, left_indices, leaf_size, max_depth - 1);
+        make_angular_tree(tree, data, left_indices, leaf_size, max_depth - 1, rng);
 
         let left_node_num = tree.point_indices.len() - 1;
 
-        make_angular_tree(tree, data, right_indices, leaf_size, max_depth - 1);
+        make_angular_tree(tree, data, right_indices, leaf_size, max_depth - 1, rng);
 
         let right_node_num = tree.point_indices.len() - 1;
 
@@ -177,16 +279,17 @@ fn make_euclidean_tree<V: SpacialOps>(
     indices: Vec<usize>,
     leaf_size: usize,
     max_depth: usize,
+    rng: &mut Rng,
 ) {
     if indices.len() > leaf_size && max_depth > 0 {
         let (left_indices, right_indices, hyperplane, offset) =
-            euclidean_random_projection_split(data, indices);
+            euclidean_random_projection_split(data, indices, rng);
 
-        make_euclidean_tree(tree, data, left_indices, leaf_size, max_depth - 1);
+        make_euclidean_tree(tree, data, left_indices, leaf_size, max_depth - 1, rng);
 
         let left_node_num = tree.point_indices.len() - 1;
 
-        make_euclidean_tree(tree, data, right_indices, leaf_size, max_depth - 1);
+        make_euclidean_tree(tree, data, right_indices, leaf_size, max_depth - 1, rng);
 
         let right_node_num = tree.point_indices.len() - 1;
 
@@ -203,6 +306,56 @@ fn make_euclidean_tree<V: SpacialOps>(
     }
 }
 
+fn make_inner_product_tree<V: SpacialOps>(
+    tree: &mut Tree<V>,
+    data: &[V],
+    augmented: &[f32],
+    indices: Vec<usize>,
+    leaf_size: usize,
+    max_depth: usize,
+    rng: &mut Rng,
+) {
+    if indices.len() > leaf_size && max_depth > 0 {
+        let (left_indices, right_indices, hyperplane) =
+            inner_product_random_projection_split(data, augmented, indices, rng);
+
+        make_inner_product_tree(
+            tree,
+            data,
+            augmented,
+            left_indices,
+            leaf_size,
+            max_depth - 1,
+            rng,
+        );
+
+        let left_node_num = tree.point_indices.len() - 1;
+
+        make_inner_product_tree(
+            tree,
+            data,
+            augmented,
+            right_indices,
+            leaf_size,
+            max_depth - 1,
+            rng,
+        );
+
+        let right_node_num = tree.point_indices.len() - 1;
+
+        tree.hyperplanes.push(Some(hyperplane));
+        tree.offsets.push(0.0);
+        tree.children.push(Some((left_node_num, right_node_num)));
+        tree.point_indices.push(None);
+    } else {
+        tree.hyperplanes.push(None);
+        tree.offsets.push(f32::NEG_INFINITY);
+        tree.children.push(None);
+        tree.point_indices.push(Some(indices));
+        tree.n_leaves += 1;
+    }
+}
+
 /// Given a set of `graph_indices` for graph_data points from `data`, create
 /// a random hyperplane to split the graph_data, returning two arrays graph_indices
 /// that fall on either side of the hyperplane. This is the basis for a
@@ -210,12 +363,14 @@ fn make_euclidean_tree<V: SpacialOps>(
 fn angular_random_project_split<V: SpacialOps>(
     data: &[V],
     indices: Vec<usize>,
+    rng: &mut Rng,
 ) -> (Vec<usize>, Vec<usize>, V) {
-    let (left, right) = select_left_right(data, &indices);
+    let (left, right) = select_left_right(data, &indices, rng);
 
     let hyperplane = left.angular_hyperplane(right);
 
-    let (left_indices, right_indices) = select_sides(data, indices, &hyperplane, 0.0);
+    let (left_indices, right_indices) =
+        select_sides(data, indices, &hyperplane, 0.0, rng);
 
     (left_indices, right_indices, hyperplane)
 }
@@ -223,31 +378,67 @@ fn angular_random_project_split<V: SpacialOps>(
 fn euclidean_random_projection_split<V: SpacialOps>(
     data: &[V],
     indices: Vec<usize>,
+    rng: &mut Rng,
 ) -> (Vec<usize>, Vec<usize>, V, f32) {
-    let (left, right) = select_left_right(data, &indices);
+    let (left, right) = select_left_right(data, &indices, rng);
 
     let (hyperplane, offset) = left.euclidean_hyperplane(right);
 
-    let (left_indices, right_indices) = select_sides(data, indices, &hyperplane, offset);
+    let (left_indices, right_indices) =
+        select_sides(data, indices, &hyperplane, offset, rng);
 
     (left_indices, right_indices, hyperplane, offset)
 }
 
+/// Splits the points on the Euclidean hyperplane between two augmented points,
+/// see [SplitStrategy::InnerProduct].
+///
+/// All augmented points share the same norm, so the hyperplane always passes
+/// through the origin and only the augmented component of the normal differs
+/// between data points and queries.
+fn inner_product_random_projection_split<V: SpacialOps>(
+    data: &[V],
+    augmented: &[f32],
+    indices: Vec<usize>,
+    rng: &mut Rng,
+) -> (Vec<usize>, Vec<usize>, V) {
+    let (left, right) = select_left_right_indices(&indices, rng);
+
+    let (hyperplane, _) = data[left].euclidean_hyperplane(&data[right]);
+    let augmented_normal = augmented[left] - augmented[right];
+
+    let (left_indices, right_indices) = select_sides_by(indices, rng, |idx| {
+        hyperplane.dot(&data[idx]) + augmented_normal * augmented[idx]
+    });
+
+    (left_indices, right_indices, hyperplane)
+}
+
 fn select_sides<V: SpacialOps>(
     data: &[V],
     indices: Vec<usize>,
     hyperplane: &V,
     offset: f32,
+    rng: &mut Rng,
+) -> (Vec<usize>, Vec<usize>) {
+    select_sides_by(indices, rng, |idx| offset + hyperplane.dot(&data[idx]))
+}
+
+/// Splits the points by the sign of their `margin` to a hyperplane.
+fn select_sides_by(
+    indices: Vec<usize>,
+    rng: &mut Rng,
+    margin: impl Fn(usize) -> f32,
 ) -> (Vec<usize>, Vec<usize>) {
     let mut num_left = 0;
     let mut num_right = 0;
     let mut side: BitVec = bitvec![usize, Lsb0; 0; indices.len()];
 
-    for i in 0..indices.len() {
-        let margin = offset + hyperplane.dot(&data[indices[i]]);
+    for (i, &idx) in indices.iter().enumerate() {
+        let margin = margin(idx);
 
         if margin.abs() < f32::EPSILON {
-            let v = fastrand::bool();
+            let v = rng.bool();
             side.set(i, v);
 
             if v {
@@ -271,7 +462,7 @@ fn select_sides<V: SpacialOps>(
         num_right = 0;
 
         for i in 0..indices.len() {
-            let v = fastrand::bool();
+            let v = rng.bool();
             side.set(i, v);
 
             if v {
@@ -287,9 +478,9 @@ fn select_sides<V: SpacialOps>(
 
     for i in 0..indices.len() {
         if *side.index(i) {
-            indices_left.push(i);
+            indices_left.push(indices[i]);
         } else {
-            indices_right.push(i);
+            indices_right.push(indices[i]);
         }
     }
 
@@ -300,21 +491,25 @@ fn select_sides<V: SpacialOps>(
 fn select_left_right<'a, V: SpacialOps>(
     data: &'a [V],
     indices: &[usize],
+    rng: &mut Rng,
 ) -> (&'a V, &'a V) {
-    let mut left_index = fastrand::usize(0..indices.len());
-    let mut right_index = fastrand::usize(0..indices.len());
+    let (left_index, right_index) = select_left_right_indices(indices, rng);
+    (&data[left_index], &data[right_index])
+}
+
+#[inline]
+fn select_left_right_indices(indices: &[usize], rng: &mut Rng) -> (usize, usize) {
+    let left_index = rng.usize(0..indices.len());
+    let mut right_index = rng.usize(0..indices.len());
     right_index += (left_index == right_index) as usize;
     right_index %= indices.len();
 
-    left_index = indices[left_index];
-    right_index = indices[right_index];
-
-    (&data[left_index], &data[right_index])
+    (indices[left_index], indices[right_index])
 }
 
 #[cfg(test)]
 mod tests {
-    use eonn_accel::{Auto, Vector, X512};
+    use eonn_accel::{Auto, SparseVector, Vector, X512};
 
     use super::*;
 
@@ -334,7 +529,8 @@ mod tests {
     #[test]
     fn test_build_forest() {
         let data = test_data();
-        let forest = make_forest(&data, 4, 3, true, 200);
+        let forest =
+            make_forest(&data, 4, 3, SplitStrategy::Angular, 200, &mut Rng::new());
         dbg!(forest);
     }
 
@@ -346,7 +542,152 @@ mod tests {
             .num_threads(1)
             .build()
             .unwrap();
-        let forest = make_forest_parallel(&data, 4, 3, true, 200, &pool);
+        let forest = make_forest_parallel(
+            &data,
+            4,
+            3,
+            SplitStrategy::Angular,
+            200,
+            &mut Rng::new(),
+            &pool,
+        );
         dbg!(forest);
     }
+
+    #[test]
+    fn test_forest_leaves_cover_data() {
+        let data = test_data();
+        let forest =
+            make_forest(&data, 4, 3, SplitStrategy::Euclidean, 200, &mut Rng::new());
+
+        for tree in forest.iter() {
+            let mut indices = get_leaves_from_tree(tree)
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            indices.sort_unstable();
+            assert_eq!(indices, (0..data.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_augmented_dimension() {
+        let data = test_data()
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| v * (i + 1) as f32)
+            .collect::<Vec<_>>();
+
+        assert!(augmented_dimension(&data, SplitStrategy::Euclidean).is_empty());
+
+        // All augmented points share the largest squared norm.
+        let augmented = augmented_dimension(&data, SplitStrategy::InnerProduct);
+        let max_squared_norm = data.iter().map(|v| v.squared_norm()).fold(0.0, f32::max);
+        for (v, extra) in data.iter().zip(augmented) {
+            let squared_norm = v.squared_norm() + extra * extra;
+            assert!((squared_norm - max_squared_norm).abs() <= max_squared_norm * 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_forest_seeded() {
+        let data = test_data();
+
+        for split in [
+            SplitStrategy::Angular,
+            SplitStrategy::Euclidean,
+            SplitStrategy::InnerProduct,
+        ] {
+            let first = make_forest(&data, 4, 3, split, 200, &mut Rng::with_seed(42));
+            let second = make_forest(&data, 4, 3, split, 200, &mut Rng::with_seed(42));
+
+            for (a, b) in first.iter().zip(second.iter()) {
+                assert_eq!(a.children, b.children);
+                assert_eq!(a.point_indices, b.point_indices);
+                assert_eq!(a.offsets, b.offsets);
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_forest_parallel_seeded() {
+        let data = test_data();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        let expected = make_forest(
+            &data,
+            4,
+            3,
+            SplitStrategy::Angular,
+            200,
+            &mut Rng::with_seed(7),
+        );
+        let forest = make_forest_parallel(
+            &data,
+            4,
+            3,
+            SplitStrategy::Angular,
+            200,
+            &mut Rng::with_seed(7),
+            &pool,
+        );
+
+        for (a, b) in expected.iter().zip(forest.iter()) {
+            assert_eq!(a.children, b.children);
+            assert_eq!(a.point_indices, b.point_indices);
+        }
+    }
+
+    #[test]
+    fn test_build_sparse_forest() {
+        let dims = 10_000;
+        let data = (0..50)
+            .map(|_| {
+                let mut indices = (0..20)
+                    .map(|_| fastrand::u32(0..dims as u32))
+                    .collect::<Vec<_>>();
+                indices.sort_unstable();
+                indices.dedup();
+                let values = indices.iter().map(|_| fastrand::f32() + 0.1).collect();
+                SparseVector::try_from_parts(dims, indices, values).expect("Load vec")
+            })
+            .collect::<Vec<_>>();
+
+        for split in [SplitStrategy::Angular, SplitStrategy::Euclidean] {
+            let forest = make_forest(&data, 4, 5, split, 200, &mut Rng::new());
+
+            for tree in forest.iter() {
+                for hyperplane in tree.hyperplanes.iter().flatten() {
+                    assert_eq!(hyperplane.len(), dims);
+                    assert!(hyperplane.nnz() <= 40);
+                }
+
+                let mut covered = rp_tree_leaf_array(std::slice::from_ref(tree))
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                covered.sort_unstable();
+                assert_eq!(covered, (0..data.len()).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_leaf() {
+        let data = test_data();
+        let forest =
+            make_forest(&data, 4, 3, SplitStrategy::Angular, 200, &mut Rng::new());
+
+        for tree in forest.iter() {
+            for vector in data.iter() {
+                let leaf = tree.search_leaf(vector);
+                assert!(!leaf.is_empty());
+                assert!(leaf.iter().all(|&i| i < data.len()));
+            }
+        }
+    }
 }